@@ -1,13 +1,30 @@
 use ark_bn254::{Fr, G1Projective};
 use ark_ec::CurveGroup;
-use ark_std::{test_rng, UniformRand};
+use ark_std::{rand::RngCore, test_rng, UniformRand};
 use iai_callgrind::{library_benchmark, library_benchmark_group, main};
 use jolt_core::{
+    jolt::instruction::xor::XORInstruction,
+    lasso::surge::{SurgePreprocessing, SurgeProof},
     msm::VariableBaseMSM,
-    poly::{dense_mlpoly::DensePolynomial, field::JoltField},
+    poly::{
+        commitment::{
+            commitment_scheme::{BatchType, CommitShape, CommitmentScheme},
+            hyrax::{HyraxCommitment, HyraxLayout, HyraxScheme},
+            pedersen::PedersenGenerators,
+        },
+        dense_mlpoly::DensePolynomial,
+        field::JoltField,
+    },
+    utils::{errors::ProofVerifyError, transcript::ProofTranscript},
+    DotProductProof,
 };
 use std::hint::black_box;
 
+const LASSO_C: usize = 4;
+const LASSO_M: usize = 1 << 16;
+type LassoProof = SurgeProof<Fr, HyraxScheme<G1Projective>, XORInstruction, LASSO_C, LASSO_M>;
+type HyraxDotProductProof = DotProductProof<Fr, HyraxScheme<G1Projective>>;
+
 fn msm_setup<G: CurveGroup>(num_points: usize) -> (Vec<G>, Vec<G::ScalarField>) {
     let mut rng = test_rng();
 
@@ -35,6 +52,60 @@ fn eval_poly_setup<F: JoltField>(size: usize) -> (DensePolynomial<F>, Vec<F>) {
     (poly, points)
 }
 
+fn hyrax_commit_setup<G: CurveGroup>(
+    size: usize,
+    layout: HyraxLayout,
+) -> (Vec<G::ScalarField>, PedersenGenerators<G>, HyraxLayout) {
+    let mut rng = test_rng();
+
+    (
+        vec![G::ScalarField::rand(&mut rng); size],
+        PedersenGenerators::new(1 << 8, b"iai"),
+        layout,
+    )
+}
+
+fn lasso_verify_setup(
+    num_lookups: usize,
+) -> (
+    SurgePreprocessing<Fr, XORInstruction, LASSO_C, LASSO_M>,
+    PedersenGenerators<G1Projective>,
+    LassoProof,
+) {
+    let mut rng = test_rng();
+
+    let ops: Vec<XORInstruction> = (0..num_lookups)
+        .map(|_| XORInstruction(rng.next_u32() as u64, rng.next_u32() as u64))
+        .collect();
+    let preprocessing = SurgePreprocessing::preprocess();
+    let generators = PedersenGenerators::new(LassoProof::num_generators(num_lookups), b"iai");
+    let mut transcript = ProofTranscript::new(b"iai");
+    let proof = LassoProof::prove(&preprocessing, &generators, ops, &mut transcript);
+    (preprocessing, generators, proof)
+}
+
+fn dot_product_verify_setup(
+    num_vars: usize,
+) -> (
+    PedersenGenerators<G1Projective>,
+    Vec<HyraxCommitment<G1Projective>>,
+    Fr,
+    HyraxDotProductProof,
+) {
+    let mut rng = test_rng();
+
+    let a = DensePolynomial::new((0..1 << num_vars).map(|_| Fr::random(&mut rng)).collect());
+    let b = DensePolynomial::new((0..1 << num_vars).map(|_| Fr::random(&mut rng)).collect());
+    let generators =
+        HyraxScheme::<G1Projective>::setup(&[CommitShape::new(1 << num_vars, BatchType::Big)]);
+    let commitments =
+        HyraxScheme::<G1Projective>::batch_commit_polys_ref(&[&a, &b], &generators, BatchType::Big);
+    let mut transcript = ProofTranscript::new(b"iai");
+    let (proof, c) =
+        HyraxDotProductProof::prove(&commitments[0], &commitments[1], &a, &b, &mut transcript);
+    (generators, commitments, c, proof)
+}
+
 #[library_benchmark]
 #[bench::long(msm_setup::<G1Projective>(4096))]
 fn bench_msm<G: CurveGroup>(input: (Vec<G>, Vec<G::ScalarField>)) -> G {
@@ -55,9 +126,71 @@ fn bench_polynomial_evaluate<F: JoltField>(input: (DensePolynomial<F>, Vec<F>))
     black_box(poly.evaluate(&points))
 }
 
+#[library_benchmark]
+#[bench::row_major(hyrax_commit_setup::<G1Projective>(4096, HyraxLayout::RowMajor))]
+#[bench::column_major(hyrax_commit_setup::<G1Projective>(4096, HyraxLayout::ColumnMajor))]
+fn bench_hyrax_commit<F: JoltField, G: CurveGroup<ScalarField = F>>(
+    input: (Vec<F>, PedersenGenerators<G>, HyraxLayout),
+) -> HyraxCommitment<G> {
+    let (evals, generators, layout) = input;
+    black_box(HyraxCommitment::commit_slice_with_layout(
+        &evals,
+        &generators,
+        layout,
+    ))
+}
+
+#[library_benchmark]
+#[bench::long(lasso_verify_setup(1 << 10))]
+fn bench_lasso_verify(
+    input: (
+        SurgePreprocessing<Fr, XORInstruction, LASSO_C, LASSO_M>,
+        PedersenGenerators<G1Projective>,
+        LassoProof,
+    ),
+) -> Result<(), ProofVerifyError> {
+    let (preprocessing, generators, proof) = input;
+    let mut transcript = ProofTranscript::new(b"iai");
+    black_box(LassoProof::verify(
+        &preprocessing,
+        &generators,
+        proof,
+        &mut transcript,
+    ))
+}
+
+#[library_benchmark]
+#[bench::long(dot_product_verify_setup(12))]
+fn bench_dot_product_verify(
+    input: (
+        PedersenGenerators<G1Projective>,
+        Vec<HyraxCommitment<G1Projective>>,
+        Fr,
+        HyraxDotProductProof,
+    ),
+) -> Result<(), ProofVerifyError> {
+    let (generators, commitments, c, proof) = input;
+    let mut transcript = ProofTranscript::new(b"iai");
+    black_box(HyraxDotProductProof::verify(
+        &generators,
+        &commitments[0],
+        &commitments[1],
+        12,
+        c,
+        &proof,
+        &mut transcript,
+    ))
+}
+
 library_benchmark_group!(
     name = jolt_core_ops;
-    benchmarks = bench_msm, bench_polynomial_binding, bench_polynomial_evaluate
+    benchmarks =
+        bench_msm,
+        bench_polynomial_binding,
+        bench_polynomial_evaluate,
+        bench_hyrax_commit,
+        bench_lasso_verify,
+        bench_dot_product_verify
 );
 
 main!(library_benchmark_groups = jolt_core_ops);
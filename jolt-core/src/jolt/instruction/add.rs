@@ -75,7 +75,11 @@ mod test {
     use rand_chacha::rand_core::RngCore;
 
     use super::ADDInstruction;
-    use crate::{jolt::instruction::JoltInstruction, jolt_instruction_test};
+    use crate::{
+        flat_chunked_consistency_test,
+        jolt::instruction::{test::assert_mle_identity, JoltInstruction},
+        jolt_instruction_test,
+    };
 
     #[test]
     fn add_instruction_32_e2e() {
@@ -117,4 +121,56 @@ mod test {
             jolt_instruction_test!(instruction);
         }
     }
+
+    #[test]
+    fn add_subtable_indices() {
+        use crate::jolt::subtable::{
+            identity::IdentitySubtable, truncate_overflow::TruncateOverflowSubtable, LassoSubtable,
+        };
+        use crate::poly::field::JoltField;
+        use std::any::TypeId;
+
+        const C: usize = 4;
+        const M: usize = 1 << 16;
+        let instruction = ADDInstruction::<32>(u32::MAX as u64, 12345);
+
+        // The two most significant chunks hold the overflow, which is truncated
+        let subtable_indices = instruction.subtable_indices::<Fr>(C, M);
+        assert_eq!(
+            subtable_indices,
+            vec![
+                (TypeId::of::<TruncateOverflowSubtable<Fr, 32>>(), vec![0, 1]),
+                (TypeId::of::<IdentitySubtable<Fr>>(), vec![2, 3]),
+            ]
+        );
+
+        // Looking up each listed subtable at its positions gives the entries, in order, that
+        // `combine_lookups` collates into the instruction's output
+        let indices = instruction.to_indices(C, ark_std::log2(M) as usize);
+        let subtables = instruction.subtables::<Fr>(C, M);
+        let mut vals = vec![];
+        for ((id, positions), (subtable, _)) in subtable_indices.iter().zip(subtables.iter()) {
+            assert_eq!(*id, subtable.subtable_id());
+            let materialized = subtable.materialize(M);
+            vals.extend(positions.iter().map(|&i| materialized[indices[i]]));
+        }
+        let slices = instruction.slice_values(&vals, C, M);
+        for (slice, (_, positions)) in slices.iter().zip(subtable_indices.iter()) {
+            assert_eq!(slice.len(), positions.len());
+        }
+        assert_eq!(
+            instruction.combine_lookups(&vals, C, M),
+            Fr::from_u64(instruction.lookup_entry()).unwrap()
+        );
+    }
+
+    flat_chunked_consistency_test!(add_flat_chunked_consistency, ADDInstruction::<32>, Fr);
+
+    #[test]
+    fn add_mle_identity() {
+        const C: usize = 4;
+        const M: usize = 1 << 16;
+        // The lookup index of ADD is the sum itself; 20 bits span the two identity chunks
+        assert_mle_identity::<C, M, _>(|index| ADDInstruction::<32>(index, 0), 20, 8);
+    }
 }
@@ -63,7 +63,9 @@ mod test {
     use ark_std::test_rng;
     use rand_chacha::rand_core::RngCore;
 
-    use crate::{jolt::instruction::JoltInstruction, jolt_instruction_test};
+    use crate::{
+        flat_chunked_consistency_test, jolt::instruction::JoltInstruction, jolt_instruction_test,
+    };
 
     use super::BGEInstruction;
 
@@ -105,4 +107,6 @@ mod test {
             jolt_instruction_test!(instruction);
         }
     }
+
+    flat_chunked_consistency_test!(bge_flat_chunked_consistency, BGEInstruction, Fr);
 }
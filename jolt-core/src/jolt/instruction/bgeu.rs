@@ -56,7 +56,11 @@ mod test {
     use ark_std::test_rng;
     use rand_chacha::rand_core::RngCore;
 
-    use crate::{jolt::instruction::JoltInstruction, jolt_instruction_test};
+    use crate::{
+        flat_chunked_consistency_test,
+        jolt::instruction::{test::assert_mle_identity, JoltInstruction},
+        jolt_instruction_test,
+    };
 
     use super::BGEUInstruction;
 
@@ -114,4 +118,19 @@ mod test {
             jolt_instruction_test!(instruction);
         }
     }
+
+    flat_chunked_consistency_test!(bgeu_flat_chunked_consistency, BGEUInstruction, Fr);
+
+    #[test]
+    fn bgeu_mle_identity() {
+        const C: usize = 4;
+        const M: usize = 1 << 16;
+        // The low 20 bits of the lookup index are x's low byte, then y's low 12 bits, which
+        // span the two lowest chunks
+        assert_mle_identity::<C, M, _>(
+            |index| BGEUInstruction((index >> 8) & 0xff, ((index >> 16) << 8) | (index & 0xff)),
+            20,
+            8,
+        );
+    }
 }
@@ -55,7 +55,9 @@ mod test {
     use ark_std::test_rng;
     use rand_chacha::rand_core::RngCore;
 
-    use crate::{jolt::instruction::JoltInstruction, jolt_instruction_test};
+    use crate::{
+        flat_chunked_consistency_test, jolt::instruction::JoltInstruction, jolt_instruction_test,
+    };
 
     use super::BNEInstruction;
 
@@ -109,4 +111,6 @@ mod test {
             jolt_instruction_test!(instruction);
         }
     }
+
+    flat_chunked_consistency_test!(bne_flat_chunked_consistency, BNEInstruction, Fr);
 }
@@ -0,0 +1,153 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use super::SubtableIndices;
+use crate::jolt::subtable::LassoSubtable;
+use crate::poly::field::JoltField;
+
+type ToIndicesFn = dyn Fn((u64, u64), usize, usize) -> Vec<usize> + Send + Sync;
+type SubtablesFn<F> =
+    dyn Fn(usize, usize) -> Vec<(Box<dyn LassoSubtable<F>>, SubtableIndices)> + Send + Sync;
+type CollationFn<F> = dyn Fn(&[F], usize, usize) -> F + Send + Sync;
+
+/// An instruction defined at runtime by closures, for defining new instructions without
+/// implementing `JoltInstruction`. The closures play the roles of the `JoltInstruction`
+/// methods of the same names; see their documentation for the expected conventions.
+///
+/// Unlike `JoltInstruction::combine_lookups`, the collation closure is specific to the
+/// field `F`, so a `DynInstruction` cannot itself implement `JoltInstruction`.
+#[derive(Clone)]
+pub struct DynInstruction<F: JoltField> {
+    operands: (u64, u64),
+    to_indices: Arc<ToIndicesFn>,
+    subtables: Arc<SubtablesFn<F>>,
+    combine_lookups: Arc<CollationFn<F>>,
+    g_poly_degree: usize,
+}
+
+impl<F: JoltField> DynInstruction<F> {
+    /// - `to_indices(operands, C, log_M)` decomposes the operands into `C` subtable indices.
+    /// - `subtables(C, M)` lists the unique subtables used and the dimensions read from each.
+    /// - `combine_lookups(vals, C, M)` collates the subtable values, of total degree
+    ///   `g_poly_degree`.
+    pub fn new(
+        to_indices: impl Fn((u64, u64), usize, usize) -> Vec<usize> + Send + Sync + 'static,
+        subtables: impl Fn(usize, usize) -> Vec<(Box<dyn LassoSubtable<F>>, SubtableIndices)>
+            + Send
+            + Sync
+            + 'static,
+        combine_lookups: impl Fn(&[F], usize, usize) -> F + Send + Sync + 'static,
+        g_poly_degree: usize,
+    ) -> Self {
+        Self {
+            operands: (0, 0),
+            to_indices: Arc::new(to_indices),
+            subtables: Arc::new(subtables),
+            combine_lookups: Arc::new(combine_lookups),
+            g_poly_degree,
+        }
+    }
+
+    /// Returns this instruction applied to operands `x` and `y`.
+    pub fn with_operands(&self, x: u64, y: u64) -> Self {
+        Self {
+            operands: (x, y),
+            ..self.clone()
+        }
+    }
+
+    pub fn operands(&self) -> (u64, u64) {
+        self.operands
+    }
+
+    pub fn to_indices(&self, C: usize, log_M: usize) -> Vec<usize> {
+        (self.to_indices)(self.operands, C, log_M)
+    }
+
+    pub fn subtables(
+        &self,
+        C: usize,
+        M: usize,
+    ) -> Vec<(Box<dyn LassoSubtable<F>>, SubtableIndices)> {
+        (self.subtables)(C, M)
+    }
+
+    pub fn combine_lookups(&self, vals: &[F], C: usize, M: usize) -> F {
+        (self.combine_lookups)(vals, C, M)
+    }
+
+    pub fn g_poly_degree(&self, _: usize) -> usize {
+        self.g_poly_degree
+    }
+}
+
+impl<F: JoltField> Debug for DynInstruction<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DynInstruction")
+            .field("operands", &self.operands)
+            .field("g_poly_degree", &self.g_poly_degree)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ark_bn254::Fr;
+    use ark_std::{log2, test_rng};
+    use rand_chacha::rand_core::RngCore;
+
+    use super::DynInstruction;
+    use crate::{
+        jolt::{
+            instruction::SubtableIndices,
+            subtable::{and::AndSubtable, LassoSubtable},
+        },
+        poly::field::JoltField,
+        utils::instruction_utils::{chunk_and_concatenate_operands, concatenate_lookups},
+    };
+
+    #[test]
+    fn and_then_shift_native_equivalence() {
+        const C: usize = 4;
+        const M: usize = 1 << 16;
+        const SHIFT: u64 = 3;
+
+        // (x & y) << SHIFT
+        let instruction = DynInstruction::<Fr>::new(
+            |(x, y), C, log_M| chunk_and_concatenate_operands(x, y, C, log_M),
+            |C, _| {
+                vec![(
+                    Box::new(AndSubtable::new()) as Box<dyn LassoSubtable<Fr>>,
+                    SubtableIndices::from(0..C),
+                )]
+            },
+            |vals, C, M| {
+                concatenate_lookups(vals, C, log2(M) as usize / 2)
+                    * Fr::from_u64(1 << SHIFT).unwrap()
+            },
+            1,
+        );
+
+        let mut rng = test_rng();
+        for _ in 0..256 {
+            let (x, y) = (rng.next_u32() as u64, rng.next_u32() as u64);
+            let instruction = instruction.with_operands(x, y);
+
+            let indices = instruction.to_indices(C, log2(M) as usize);
+            let mut subtable_values: Vec<Fr> = vec![];
+            for (subtable, dimension_indices) in instruction.subtables(C, M) {
+                let materialized_subtable = subtable.materialize(M);
+                for i in dimension_indices.iter() {
+                    subtable_values.push(materialized_subtable[indices[i]]);
+                }
+            }
+
+            assert_eq!(
+                instruction.combine_lookups(&subtable_values, C, M),
+                Fr::from_u64((x & y) << SHIFT).unwrap(),
+                "{:?}",
+                instruction
+            );
+        }
+    }
+}
@@ -6,9 +6,13 @@ use std::marker::Sync;
 use std::ops::Range;
 use strum::{EnumCount, IntoEnumIterator};
 
-use crate::jolt::subtable::LassoSubtable;
+use rayon::prelude::*;
+
+use crate::jolt::subtable::{LassoSubtable, SubtableId};
+use crate::poly::dense_mlpoly::DensePolynomial;
 use crate::poly::field::JoltField;
 use crate::utils::instruction_utils::chunk_operand;
+use crate::utils::math::Math;
 use common::rv_trace::ELFInstruction;
 use std::fmt::Debug;
 
@@ -67,6 +71,16 @@ pub trait JoltInstruction: Clone + Debug + Send + Sync + Serialize {
         assert_eq!(offset, vals.len());
         slices
     }
+
+    /// Lists the subtables this instruction looks up into, identified by `SubtableId`, each with
+    /// the chunk positions (in `[0, C)`) it is applied to, in the order in which
+    /// `combine_lookups` consumes their entries.
+    fn subtable_indices<F: JoltField>(&self, C: usize, M: usize) -> Vec<(SubtableId, Vec<usize>)> {
+        self.subtables::<F>(C, M)
+            .into_iter()
+            .map(|(subtable, indices)| (subtable.subtable_id(), indices.iter().collect()))
+            .collect()
+    }
 }
 
 pub trait JoltInstructionSet:
@@ -122,12 +136,66 @@ impl From<Range<usize>> for SubtableIndices {
     }
 }
 
+/// Collates each instruction's subtable entries into its output (see
+/// `JoltInstruction::combine_lookups`), in parallel across instructions. `subtable_evals[i]` are
+/// the entries looked up by `instructions[i]`.
+pub fn batch_collate<F: JoltField, I: JoltInstruction>(
+    instructions: &[I],
+    subtable_evals: &[Vec<F>],
+    C: usize,
+    M: usize,
+) -> Vec<F> {
+    assert_eq!(instructions.len(), subtable_evals.len());
+    instructions
+        .par_iter()
+        .zip(subtable_evals.par_iter())
+        .map(|(instruction, vals)| instruction.combine_lookups(vals, C, M))
+        .collect()
+}
+
+/// Decomposes the operand values of `instruction` into the index it looks up in each subtable,
+/// in the order in which `combine_lookups` expects the looked-up values: for each subtable
+/// returned by `subtables`, one index per chunk it is applied to.
+pub fn build_indices_from_values<F: JoltField, I: JoltInstruction>(
+    instruction: &I,
+    C: usize,
+    M: usize,
+) -> Vec<usize> {
+    let chunk_indices = instruction.to_indices(C, M.log_2());
+    instruction
+        .subtables::<F>(C, M)
+        .iter()
+        .flat_map(|(_, dimension_indices)| dimension_indices.iter().map(|i| chunk_indices[i]))
+        .collect()
+}
+
+/// Builds the truth table of `instruction` over all `2^input_bits` inputs, as a polynomial whose
+/// entry `(x << input_bits / 2) | y` is the `lookup_entry` of `instruction(x, y)`. Only feasible
+/// for small `input_bits`; useful for testing an instruction's collation end-to-end.
+pub fn instruction_truth_table<F: JoltField, I: JoltInstruction>(
+    instruction: impl Fn(u64, u64) -> I + Sync,
+    input_bits: usize,
+) -> DensePolynomial<F> {
+    assert!(
+        input_bits % 2 == 0 && input_bits < 64,
+        "input_bits must be even and less than 64"
+    );
+    let operand_bits = input_bits / 2;
+    let operand_mask = (1u64 << operand_bits) - 1;
+    let entries: Vec<u64> = (0..1u64 << input_bits)
+        .into_par_iter()
+        .map(|i| instruction(i >> operand_bits, i & operand_mask).lookup_entry())
+        .collect();
+    DensePolynomial::from_u64(&entries)
+}
+
 pub mod add;
 pub mod and;
 pub mod beq;
 pub mod bge;
 pub mod bgeu;
 pub mod bne;
+pub mod dyn_instruction;
 pub mod lb;
 pub mod lh;
 pub mod or;
@@ -144,3 +212,101 @@ pub mod xor;
 
 #[cfg(test)]
 pub mod test;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jolt::instruction::{add::ADDInstruction, and::ANDInstruction, xor::XORInstruction};
+    use crate::jolt::vm::rv32i_vm::RV32I;
+    use crate::utils::index_to_field_bitvector;
+    use crate::utils::test_lib::assert_field_vecs_eq;
+    use ark_bn254::Fr;
+    use ark_std::test_rng;
+    use rand_chacha::rand_core::RngCore;
+
+    #[test]
+    fn batch_collate_matches_sequential() {
+        const C: usize = 4;
+        const M: usize = 1 << 16;
+        let log_M = ark_std::log2(M) as usize;
+
+        let mut rng = test_rng();
+        let instructions: Vec<RV32I> = (0..32)
+            .map(|i| {
+                let (x, y) = (rng.next_u32() as u64, rng.next_u32() as u64);
+                if i % 2 == 0 {
+                    RV32I::ADD(ADDInstruction(x, y))
+                } else {
+                    RV32I::XOR(XORInstruction(x, y))
+                }
+            })
+            .collect();
+
+        let subtable_evals: Vec<Vec<Fr>> = instructions
+            .iter()
+            .map(|instruction| {
+                let indices = instruction.to_indices(C, log_M);
+                let mut vals = vec![];
+                for (subtable, dimension_indices) in instruction.subtables::<Fr>(C, M) {
+                    for i in dimension_indices.iter() {
+                        vals.push(
+                            subtable.evaluate_mle(&index_to_field_bitvector(indices[i], log_M)),
+                        );
+                    }
+                }
+                vals
+            })
+            .collect();
+
+        let sequential: Vec<Fr> = instructions
+            .iter()
+            .zip(subtable_evals.iter())
+            .map(|(instruction, vals)| instruction.combine_lookups(vals, C, M))
+            .collect();
+        let batched = batch_collate(&instructions, &subtable_evals, C, M);
+        assert_field_vecs_eq(&batched, &sequential);
+
+        for (instruction, output) in instructions.iter().zip(batched.iter()) {
+            assert_eq!(*output, Fr::from_u64(instruction.lookup_entry()).unwrap());
+        }
+    }
+
+    /// Checks the truth table of `instruction` over pairs of 8-bit operands against the
+    /// collation of its subtable entries, at random inputs.
+    fn truth_table_matches_collation<I: JoltInstruction>(
+        instruction: impl Fn(u64, u64) -> I + Sync,
+    ) {
+        const C: usize = 4;
+        const M: usize = 1 << 4;
+        const INPUT_BITS: usize = 16;
+        let log_M = ark_std::log2(M) as usize;
+
+        let truth_table: DensePolynomial<Fr> = instruction_truth_table(&instruction, INPUT_BITS);
+        let mut rng = test_rng();
+        for _ in 0..32 {
+            let (x, y) = (rng.next_u32() as u64 & 0xff, rng.next_u32() as u64 & 0xff);
+            let op = instruction(x, y);
+
+            let indices = op.to_indices(C, log_M);
+            let mut vals = vec![];
+            for (subtable, dimension_indices) in op.subtables::<Fr>(C, M) {
+                let materialized = subtable.materialize(M);
+                for i in dimension_indices.iter() {
+                    vals.push(materialized[indices[i]]);
+                }
+            }
+
+            let input = ((x << 8) | y) as usize;
+            assert_eq!(
+                truth_table.evaluate(&index_to_field_bitvector(input, INPUT_BITS)),
+                op.combine_lookups(&vals, C, M)
+            );
+        }
+    }
+
+    #[test]
+    fn instruction_truth_table_matches_collation() {
+        truth_table_matches_collation(XORInstruction);
+        truth_table_matches_collation(ANDInstruction);
+    }
+}
@@ -69,7 +69,11 @@ mod test {
     use ark_std::test_rng;
     use rand_chacha::rand_core::RngCore;
 
-    use crate::{jolt::instruction::JoltInstruction, jolt_instruction_test};
+    use crate::{
+        flat_chunked_consistency_test,
+        jolt::instruction::{test::assert_mle_identity, JoltInstruction},
+        jolt_instruction_test,
+    };
 
     use super::SLTUInstruction;
 
@@ -104,4 +108,19 @@ mod test {
             jolt_instruction_test!(instruction);
         }
     }
+
+    flat_chunked_consistency_test!(sltu_flat_chunked_consistency, SLTUInstruction, Fr);
+
+    #[test]
+    fn sltu_mle_identity() {
+        const C: usize = 4;
+        const M: usize = 1 << 16;
+        // The low 20 bits of the lookup index are x's low byte, then y's low 12 bits, which
+        // span the two lowest chunks
+        assert_mle_identity::<C, M, _>(
+            |index| SLTUInstruction((index >> 8) & 0xff, ((index >> 16) << 8) | (index & 0xff)),
+            20,
+            8,
+        );
+    }
 }
@@ -1,3 +1,7 @@
+use super::{build_indices_from_values, JoltInstruction};
+use crate::poly::field::JoltField;
+use crate::utils::index_to_field_bitvector;
+
 #[macro_export]
 /// Tests the consistency of an instruction's `subtables``, `to_indices`, and `combine_lookups`
 /// methods. In detail:
@@ -24,3 +28,124 @@ macro_rules! jolt_instruction_test {
         assert_eq!(actual, expected, "{:?}", $instr);
     };
 }
+
+#[macro_export]
+/// Defines a test checking that an instruction's flat lookup agrees with its chunked
+/// representation over the field `$F` (see `assert_flat_chunked_consistency`). `$instr`
+/// constructs the instruction from its two operands.
+macro_rules! flat_chunked_consistency_test {
+    ($test_name:ident, $instr:expr, $F:ty) => {
+        #[test]
+        fn $test_name() {
+            $crate::jolt::instruction::test::assert_flat_chunked_consistency::<$F, _>($instr);
+        }
+    };
+}
+
+/// Checks that an instruction's flat lookup (its `lookup_entry`) agrees with its chunked
+/// representation, for random 32-bit operands decomposed into both `C = 2` and `C = 4` chunks.
+/// In detail, for each `C`:
+/// 1. Computes the expected value directly, using `lookup_entry`
+/// 2. Decomposes the operands into subtable lookup indices using `build_indices_from_values`
+/// 3. Evaluates the MLE of each subtable in `subtables` at its indices
+/// 4. Checks that collating those evaluations with `combine_lookups` gives the expected value
+///
+/// Unlike `jolt_instruction_test`, subtables are never materialized, so a chunk may span 32
+/// bits. `instr` constructs the instruction from its two operands.
+pub fn assert_flat_chunked_consistency<F: JoltField, I: JoltInstruction>(
+    instr: impl Fn(u64, u64) -> I,
+) {
+    use rand_chacha::rand_core::RngCore;
+
+    let mut rng = ark_std::test_rng();
+    for (num_chunks, memory_size) in [(2, 1usize << 32), (4, 1 << 16)] {
+        let log_M = ark_std::log2(memory_size) as usize;
+        for _ in 0..64 {
+            let instruction = instr(rng.next_u32() as u64, rng.next_u32() as u64);
+            let expected = F::from_u64(instruction.lookup_entry()).unwrap();
+
+            let indices = build_indices_from_values::<F, _>(&instruction, num_chunks, memory_size);
+            let mut indices = indices.into_iter();
+            let mut vals = vec![];
+            for (subtable, dimension_indices) in instruction.subtables::<F>(num_chunks, memory_size)
+            {
+                for _ in dimension_indices.iter() {
+                    let point = index_to_field_bitvector(indices.next().unwrap(), log_M);
+                    vals.push(subtable.evaluate_mle(&point));
+                }
+            }
+
+            assert_eq!(
+                instruction.combine_lookups(&vals, num_chunks, memory_size),
+                expected,
+                "{:?} with C = {}",
+                instruction,
+                num_chunks
+            );
+        }
+    }
+}
+
+/// Schwartz-Zippel test that an instruction's collation agrees with the MLE of its truth table
+/// away from the Boolean hypercube, which catches collation errors that tests at Boolean points
+/// (e.g. `jolt_instruction_test`) miss.
+///
+/// The truth table is taken over the low `input_bits` bits of the lookup index, i.e. the
+/// concatenation of the `C` chunks returned by `to_indices`, with the remaining high bits fixed
+/// to zero. `instr` constructs the instruction whose lookup index is the given value; this is
+/// checked for every entry. In each of `num_trials` trials, both the truth table's MLE and the
+/// collation (`combine_lookups` of each subtable's MLE, evaluated at its chunk of the point) are
+/// evaluated at a random point and asserted equal. This requires the collation to be multilinear
+/// in the randomized bits, which holds whenever it only multiplies together subtable
+/// evaluations on distinct chunks.
+pub fn assert_mle_identity<const C: usize, const M: usize, I: JoltInstruction>(
+    instr: impl Fn(u64) -> I,
+    input_bits: usize,
+    num_trials: usize,
+) {
+    use crate::poly::dense_mlpoly::DensePolynomial;
+    use ark_bn254::Fr;
+
+    let log_M = ark_std::log2(M) as usize;
+    assert!(input_bits <= C * log_M && input_bits < 64);
+
+    let entries: Vec<u64> = (0..1u64 << input_bits)
+        .map(|index| {
+            let instruction = instr(index);
+            let lookup_index = instruction
+                .to_indices(C, log_M)
+                .iter()
+                .fold(0u128, |acc, &chunk| (acc << log_M) | chunk as u128);
+            assert_eq!(
+                lookup_index, index as u128,
+                "{:?} does not have lookup index {}",
+                instruction, index
+            );
+            instruction.lookup_entry()
+        })
+        .collect();
+    let truth_table: DensePolynomial<Fr> = DensePolynomial::from_u64(&entries);
+
+    let mut rng = ark_std::test_rng();
+    for _ in 0..num_trials {
+        let r: Vec<Fr> = (0..input_bits).map(|_| Fr::random(&mut rng)).collect();
+        let mut point = vec![Fr::zero(); C * log_M - input_bits];
+        point.extend(r.iter());
+
+        // Any instruction has the same subtables and collation
+        let instruction = instr(0);
+        let mut vals = vec![];
+        for (subtable, dimension_indices) in instruction.subtables::<Fr>(C, M) {
+            for i in dimension_indices.iter() {
+                vals.push(subtable.evaluate_mle(&point[i * log_M..(i + 1) * log_M]));
+            }
+        }
+
+        assert_eq!(
+            instruction.combine_lookups(&vals, C, M),
+            truth_table.evaluate(&r),
+            "collation of {:?} is not the MLE of its truth table",
+            instruction
+        );
+    }
+}
@@ -3,7 +3,7 @@ use ark_std::log2;
 use std::marker::PhantomData;
 
 use super::LassoSubtable;
-use crate::utils::split_bits;
+use crate::utils::{powers_of_two, split_bits};
 
 #[derive(Default)]
 pub struct AndSubtable<F: JoltField> {
@@ -39,10 +39,10 @@ impl<F: JoltField> LassoSubtable<F> for AndSubtable<F> {
         let (x, y) = point.split_at(b);
 
         let mut result = F::zero();
-        for i in 0..b {
+        for (i, power) in powers_of_two::<F>(b).enumerate() {
             let x = x[b - i - 1];
             let y = y[b - i - 1];
-            result += F::from_u64(1u64 << i).unwrap() * x * y;
+            result += power * x * y;
         }
         result
     }
@@ -53,9 +53,14 @@ mod test {
     use ark_bn254::Fr;
 
     use crate::{
-        jolt::subtable::{and::AndSubtable, LassoSubtable},
+        jolt::subtable::{and::AndSubtable, test::assert_materialize_deterministic, LassoSubtable},
         subtable_materialize_mle_parity_test,
     };
 
     subtable_materialize_mle_parity_test!(and_materialize_mle_parity, AndSubtable<Fr>, Fr, 256);
+
+    #[test]
+    fn and_materialize_deterministic() {
+        assert_materialize_deterministic(&AndSubtable::<Fr>::new(), 256);
+    }
 }
@@ -0,0 +1,93 @@
+use crate::poly::field::JoltField;
+use crate::utils::powers_of_two;
+use ark_std::log2;
+use std::marker::PhantomData;
+
+use super::LassoSubtable;
+
+/// Subtable mapping each `WIDTH`-bit index to the value whose bits are those of the index in
+/// reverse order, as used to permute FFT inputs. The subtable must therefore have size
+/// `M = 2^WIDTH`.
+#[derive(Default)]
+pub struct BitReverseSubtable<F: JoltField, const WIDTH: usize> {
+    _field: PhantomData<F>,
+}
+
+impl<F: JoltField, const WIDTH: usize> BitReverseSubtable<F, WIDTH> {
+    pub fn new() -> Self {
+        Self {
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<F: JoltField, const WIDTH: usize> LassoSubtable<F> for BitReverseSubtable<F, WIDTH> {
+    fn materialize(&self, M: usize) -> Vec<F> {
+        assert_eq!(log2(M) as usize, WIDTH);
+        (0..M)
+            .map(|idx| F::from_u64((idx as u64).reverse_bits() >> (64 - WIDTH)).unwrap())
+            .collect()
+    }
+
+    fn evaluate_mle(&self, point: &[F]) -> F {
+        // `point[i]` is the input bit of weight 2^(WIDTH - 1 - i), which the reversal moves to
+        // weight 2^i.
+        debug_assert!(point.len() == WIDTH);
+        let mut result = F::zero();
+        for (power, bit) in powers_of_two::<F>(WIDTH).zip(point.iter()) {
+            result += power * bit;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ark_bn254::Fr;
+
+    use crate::{
+        jolt::subtable::{bit_reverse::BitReverseSubtable, LassoSubtable},
+        poly::field::JoltField,
+        subtable_materialize_mle_parity_test,
+        utils::index_to_field_bitvector,
+    };
+
+    const WIDTH: usize = 8;
+
+    subtable_materialize_mle_parity_test!(
+        bit_reverse_materialize_mle_parity,
+        BitReverseSubtable<Fr, WIDTH>,
+        Fr,
+        256
+    );
+
+    #[test]
+    fn bit_reverse_native() {
+        let subtable = BitReverseSubtable::<Fr, WIDTH>::new();
+        let materialized = subtable.materialize(1 << WIDTH);
+        for (idx, expected) in [(0b0000_0001, 0b1000_0000), (0b1100_0000, 0b0000_0011)] {
+            let expected = Fr::from_u64(expected).unwrap();
+            assert_eq!(materialized[idx], expected);
+            assert_eq!(
+                subtable.evaluate_mle(&index_to_field_bitvector(idx, WIDTH)),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn bit_reverse_involution() {
+        let materialized = BitReverseSubtable::<Fr, WIDTH>::new().materialize(1 << WIDTH);
+        let reversed: Vec<usize> = (0..1 << WIDTH)
+            .map(|idx: usize| idx.reverse_bits() >> (usize::BITS as usize - WIDTH))
+            .collect();
+        for idx in 0..1 << WIDTH {
+            // Reversing twice is the identity
+            assert_eq!(reversed[reversed[idx]], idx);
+            assert_eq!(
+                materialized[reversed[idx]],
+                Fr::from_u64(idx as u64).unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use crate::poly::field::JoltField;
+use crate::utils::powers_of_two;
+use std::marker::PhantomData;
+
+use super::LassoSubtable;
+
+/// Subtable selecting byte `byte_index` of the index, i.e. `(i >> (8 * byte_index)) & 0xff`.
+/// Byte 0 is the least significant byte.
+pub struct ByteExtractSubtable<F: JoltField> {
+    byte_index: usize,
+    _field: PhantomData<F>,
+}
+
+impl<F: JoltField> ByteExtractSubtable<F> {
+    pub fn new(byte_index: usize) -> Self {
+        Self {
+            byte_index,
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<F: JoltField> LassoSubtable<F> for ByteExtractSubtable<F> {
+    fn materialize(&self, M: usize) -> Vec<F> {
+        let shift = 8 * self.byte_index;
+        (0..M)
+            .map(|i| F::from_u64(i.checked_shr(shift as u32).unwrap_or(0) as u64 & 0xff).unwrap())
+            .collect()
+    }
+
+    fn evaluate_mle(&self, point: &[F]) -> F {
+        let b = point.len();
+        let mut result = F::zero();
+        // Only the 8 bit-variables of the selected byte contribute
+        for (k, power) in powers_of_two::<F>(8).enumerate() {
+            let bit_index = 8 * self.byte_index + k;
+            if bit_index < b {
+                result += power * point[b - 1 - bit_index];
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ark_bn254::Fr;
+    use ark_std::test_rng;
+    use rand_chacha::rand_core::RngCore;
+
+    use crate::{
+        jolt::subtable::{byte_extract::ByteExtractSubtable, LassoSubtable},
+        poly::field::JoltField,
+        utils::index_to_field_bitvector,
+    };
+
+    #[test]
+    fn byte_extract_materialize_mle_parity() {
+        const M: usize = 1 << 16;
+        for byte_index in 0..4 {
+            let subtable = ByteExtractSubtable::<Fr>::new(byte_index);
+            let materialized = subtable.materialize(M);
+            for i in 0..M {
+                assert_eq!(
+                    materialized[i],
+                    subtable.evaluate_mle(&index_to_field_bitvector(i, 16)),
+                    "MLE did not match materialized subtable at index {}",
+                    i
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn byte_extract_native_equivalence() {
+        let mut rng = test_rng();
+        for _ in 0..256 {
+            let word = rng.next_u32();
+            let bytes = word.to_le_bytes();
+            for (byte_index, byte) in bytes.iter().enumerate() {
+                let subtable = ByteExtractSubtable::<Fr>::new(byte_index);
+                assert_eq!(
+                    subtable.evaluate_mle(&index_to_field_bitvector(word as usize, 32)),
+                    Fr::from_u64(*byte as u64).unwrap(),
+                    "byte {} of {:#010x}",
+                    byte_index,
+                    word
+                );
+            }
+        }
+    }
+}
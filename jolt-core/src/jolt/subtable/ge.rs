@@ -0,0 +1,88 @@
+use crate::poly::field::JoltField;
+use ark_std::log2;
+use std::marker::PhantomData;
+
+use super::{ltu::LtuSubtable, LassoSubtable};
+use crate::utils::split_bits;
+
+/// Subtable of the unsigned `x >= y` predicate over the operand halves of the index, i.e. the
+/// complement of `LtuSubtable`.
+#[derive(Default)]
+pub struct GeSubtable<F: JoltField> {
+    _field: PhantomData<F>,
+}
+
+impl<F: JoltField> GeSubtable<F> {
+    pub fn new() -> Self {
+        Self {
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<F: JoltField> LassoSubtable<F> for GeSubtable<F> {
+    fn materialize(&self, M: usize) -> Vec<F> {
+        let mut entries: Vec<F> = Vec::with_capacity(M);
+        let bits_per_operand = (log2(M) / 2) as usize;
+
+        // Materialize table entries in order where (x | y) ranges 0..M
+        for idx in 0..M {
+            let (x, y) = split_bits(idx, bits_per_operand);
+            let row = if x >= y { F::one() } else { F::zero() };
+            entries.push(row);
+        }
+        entries
+    }
+
+    fn evaluate_mle(&self, point: &[F]) -> F {
+        // 1 - LTU(x, y)
+        F::one() - LtuSubtable::<F>::new().evaluate_mle(point)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ark_bn254::Fr;
+
+    use crate::{
+        jolt::subtable::{ge::GeSubtable, LassoSubtable},
+        poly::field::JoltField,
+        subtable_materialize_mle_parity_test,
+        utils::index_to_field_bitvector,
+    };
+
+    subtable_materialize_mle_parity_test!(ge_materialize_mle_parity, GeSubtable<Fr>, Fr, 256);
+
+    #[test]
+    fn ge_native_equivalence() {
+        const OPERAND_BITS: usize = 4;
+        let subtable = GeSubtable::<Fr>::new();
+        let materialized = subtable.materialize(1 << (2 * OPERAND_BITS));
+        let cases = [
+            // Equal operands
+            (0, 0),
+            (7, 7),
+            (15, 15),
+            // Strictly greater
+            (1, 0),
+            (8, 7),
+            (15, 0),
+            // Strictly less
+            (0, 1),
+            (7, 8),
+            (0, 15),
+        ];
+        for (x, y) in cases {
+            let index = (x << OPERAND_BITS) | y;
+            let expected = Fr::from_u64((x >= y) as u64).unwrap();
+            assert_eq!(
+                subtable.evaluate_mle(&index_to_field_bitvector(index, 2 * OPERAND_BITS)),
+                expected,
+                "{} >= {}",
+                x,
+                y
+            );
+            assert_eq!(materialized[index], expected);
+        }
+    }
+}
@@ -1,16 +1,33 @@
 use crate::poly::field::JoltField;
+use crate::utils::powers_of_two;
 use std::marker::PhantomData;
 
 use super::LassoSubtable;
 
+/// Subtable mapping each index to itself. By default the operand spans the full index; a
+/// subtable constructed with `with_width` instead maps each index to its low `width` bits,
+/// for operands narrower than a chunk.
+///
+/// Subtables in a `subtable_enum!` are constructed with `new()`, so a narrowed identity
+/// subtable must be returned directly from `JoltInstruction::subtables`.
 #[derive(Default)]
 pub struct IdentitySubtable<F: JoltField> {
+    width: Option<usize>,
     _field: PhantomData<F>,
 }
 
 impl<F: JoltField> IdentitySubtable<F> {
     pub fn new() -> Self {
         Self {
+            width: None,
+            _field: PhantomData,
+        }
+    }
+
+    /// Identity subtable over the low `width` bits of the index; higher bits are ignored.
+    pub fn with_width(width: usize) -> Self {
+        Self {
+            width: Some(width),
             _field: PhantomData,
         }
     }
@@ -18,13 +35,22 @@ impl<F: JoltField> IdentitySubtable<F> {
 
 impl<F: JoltField> LassoSubtable<F> for IdentitySubtable<F> {
     fn materialize(&self, M: usize) -> Vec<F> {
-        (0..M).map(|i| F::from_u64(i as u64).unwrap()).collect()
+        let mask = match self.width {
+            Some(width) if width < 64 => (1u64 << width) - 1,
+            _ => u64::MAX,
+        };
+        (0..M)
+            .map(|i| F::from_u64(i as u64 & mask).unwrap())
+            .collect()
     }
 
     fn evaluate_mle(&self, point: &[F]) -> F {
+        let b = self
+            .width
+            .map_or(point.len(), |width| width.min(point.len()));
         let mut result = F::zero();
-        for i in 0..point.len() {
-            result += F::from_u64(1u64 << i).unwrap() * point[point.len() - 1 - i];
+        for (i, power) in powers_of_two::<F>(b).enumerate() {
+            result += power * point[point.len() - 1 - i];
         }
         result
     }
@@ -35,8 +61,14 @@ mod test {
     use ark_bn254::Fr;
 
     use crate::{
-        jolt::subtable::{identity::IdentitySubtable, LassoSubtable},
+        jolt::subtable::{
+            identity::IdentitySubtable,
+            test::{assert_materialize_deterministic, check_against_fixture, export_fixture},
+            LassoSubtable,
+        },
+        poly::field::JoltField,
         subtable_materialize_mle_parity_test,
+        utils::index_to_field_bitvector,
     };
 
     subtable_materialize_mle_parity_test!(
@@ -45,4 +77,40 @@ mod test {
         Fr,
         256
     );
+
+    #[test]
+    fn iden_with_width_ignores_high_bits() {
+        const WIDTH: usize = 3;
+        let subtable = IdentitySubtable::<Fr>::with_width(WIDTH);
+        let materialized = subtable.materialize(256);
+        for i in 0..256 {
+            let expected = Fr::from_u64((i & ((1 << WIDTH) - 1)) as u64).unwrap();
+            assert_eq!(materialized[i], expected, "index {}", i);
+            assert_eq!(
+                subtable.evaluate_mle(&index_to_field_bitvector(i, 8)),
+                expected,
+                "MLE at index {}",
+                i
+            );
+        }
+
+        // A width at least the number of index bits is the full identity
+        let wide = IdentitySubtable::<Fr>::with_width(8);
+        assert_eq!(
+            wide.materialize(256),
+            IdentitySubtable::<Fr>::new().materialize(256)
+        );
+    }
+
+    #[test]
+    fn iden_materialize_deterministic() {
+        assert_materialize_deterministic(&IdentitySubtable::<Fr>::new(), 256);
+    }
+
+    #[test]
+    fn iden_fixture_round_trip() {
+        let mut fixture = vec![];
+        export_fixture(&IdentitySubtable::<Fr>::new(), 256, &mut fixture);
+        check_against_fixture(&IdentitySubtable::<Fr>::new(), &fixture[..]);
+    }
 }
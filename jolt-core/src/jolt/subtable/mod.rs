@@ -30,14 +30,21 @@ pub trait JoltSubtableSet<F: JoltField>:
 }
 
 pub mod and;
+pub mod bit_reverse;
+pub mod byte_extract;
 pub mod eq;
 pub mod eq_abs;
 pub mod eq_msb;
+pub mod ge;
 pub mod gt_msb;
 pub mod identity;
 pub mod lt_abs;
 pub mod ltu;
+pub mod mod_reduce;
+pub mod neq;
 pub mod or;
+pub mod range_between;
+pub mod saturating_add;
 pub mod sign_extend;
 pub mod sll;
 pub mod sra_sign;
@@ -0,0 +1,160 @@
+use crate::poly::field::JoltField;
+use crate::utils::powers_of_two;
+use std::marker::PhantomData;
+
+use super::LassoSubtable;
+
+/// Subtable of `i % modulus` for a fixed `modulus`.
+///
+/// The MLE is evaluated bit by bit from the MSB down, tracking the weight of each remainder
+/// modulo `modulus` of the bits read so far, in time `O(b * modulus)` for `b` variables.
+/// Alternatively, writing `i = q * modulus + r`, the MLE is linear in the identity MLE and the
+/// MLE of the quotient `q`, which the prover may supply as a witness (see
+/// `quotient_and_remainder` and `evaluate_mle_with_quotient`). The caller is then responsible
+/// for validating that witness, i.e. that `q * modulus` is computed correctly (mul subtable)
+/// and that `r < modulus` (range/LTU subtable).
+///
+/// Because it is parameterized by `modulus`, this subtable cannot be constructed with a
+/// parameterless `new()` and so cannot be used in a `subtable_enum!`.
+pub struct ModReduceSubtable<F: JoltField> {
+    modulus: u64,
+    _field: PhantomData<F>,
+}
+
+impl<F: JoltField> ModReduceSubtable<F> {
+    pub fn new(modulus: u64) -> Self {
+        assert!(modulus > 0, "modulus must be nonzero");
+        Self {
+            modulus,
+            _field: PhantomData,
+        }
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    /// Returns the witness `(q, r)` such that `i = q * modulus + r` and `r < modulus`.
+    pub fn quotient_and_remainder(&self, i: u64) -> (u64, u64) {
+        (i / self.modulus, i % self.modulus)
+    }
+
+    /// Evaluates the MLE of this subtable at `point`, given `quotient_eval`, the evaluation of
+    /// the MLE of the quotient table `i / modulus` at `point`.
+    pub fn evaluate_mle_with_quotient(&self, point: &[F], quotient_eval: F) -> F {
+        Self::evaluate_identity_mle(point) - F::from_u64(self.modulus).unwrap() * quotient_eval
+    }
+
+    fn evaluate_identity_mle(point: &[F]) -> F {
+        let mut result = F::zero();
+        for (power, x) in powers_of_two::<F>(point.len()).zip(point.iter().rev()) {
+            result += power * x;
+        }
+        result
+    }
+}
+
+impl<F: JoltField> LassoSubtable<F> for ModReduceSubtable<F> {
+    fn materialize(&self, M: usize) -> Vec<F> {
+        (0..M)
+            .map(|i| F::from_u64(i as u64 % self.modulus).unwrap())
+            .collect()
+    }
+
+    fn evaluate_mle(&self, point: &[F]) -> F {
+        let b = point.len();
+        // If the modulus exceeds every index, the reduction is the identity
+        if b < 64 && self.modulus >= 1 << b {
+            return Self::evaluate_identity_mle(point);
+        }
+
+        // weights[r] is the sum of eq(x_{<j}, prefix) over the j-bit prefixes congruent to r
+        let modulus = self.modulus as usize;
+        let mut weights = vec![F::zero(); modulus];
+        weights[0] = F::one();
+        for x in point {
+            let mut next = vec![F::zero(); modulus];
+            for (r, weight) in weights.iter().enumerate() {
+                next[(2 * r) % modulus] += *weight * (F::one() - x);
+                next[(2 * r + 1) % modulus] += *weight * x;
+            }
+            weights = next;
+        }
+        weights
+            .iter()
+            .enumerate()
+            .map(|(r, weight)| F::from_u64(r as u64).unwrap() * weight)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ark_bn254::Fr;
+    use ark_std::test_rng;
+
+    use crate::{
+        jolt::subtable::{mod_reduce::ModReduceSubtable, LassoSubtable},
+        poly::{dense_mlpoly::DensePolynomial, field::JoltField},
+        utils::index_to_field_bitvector,
+    };
+
+    const M: usize = 256;
+    const MODULI: [u64; 5] = [1, 2, 3, 7, 255];
+
+    #[test]
+    fn mod_reduce_native_equivalence() {
+        for modulus in MODULI {
+            let subtable = ModReduceSubtable::<Fr>::new(modulus);
+            let materialized = subtable.materialize(M);
+            for i in 0..M {
+                let expected = Fr::from_u64(i as u64 % modulus).unwrap();
+                assert_eq!(
+                    materialized[i], expected,
+                    "modulus {}, index {}",
+                    modulus, i
+                );
+                assert_eq!(
+                    subtable.evaluate_mle(&index_to_field_bitvector(i, 8)),
+                    expected,
+                    "MLE did not match i % {} at index {}",
+                    modulus,
+                    i
+                );
+
+                let (q, r) = subtable.quotient_and_remainder(i as u64);
+                assert_eq!(q * modulus + r, i as u64);
+                assert!(r < modulus);
+            }
+        }
+    }
+
+    #[test]
+    fn mod_reduce_mle_with_quotient_witness() {
+        let mut rng = test_rng();
+        for modulus in MODULI {
+            let subtable = ModReduceSubtable::<Fr>::new(modulus);
+            let point: Vec<Fr> = (0..8).map(|_| Fr::random(&mut rng)).collect();
+
+            // The honest quotient witness reproduces the MLE; any other does not.
+            let quotients: Vec<Fr> = (0..M as u64)
+                .map(|i| Fr::from_u64(subtable.quotient_and_remainder(i).0).unwrap())
+                .collect();
+            let quotient_eval = DensePolynomial::new(quotients).evaluate(&point);
+            let mle_eval = subtable.evaluate_mle(&point);
+            assert_eq!(
+                mle_eval,
+                DensePolynomial::new(subtable.materialize(M)).evaluate(&point)
+            );
+            assert_eq!(
+                subtable.evaluate_mle_with_quotient(&point, quotient_eval),
+                mle_eval
+            );
+            assert_ne!(
+                subtable
+                    .evaluate_mle_with_quotient(&point, quotient_eval + Fr::from_u64(1).unwrap()),
+                mle_eval
+            );
+        }
+    }
+}
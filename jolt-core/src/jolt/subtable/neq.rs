@@ -0,0 +1,95 @@
+use crate::poly::field::JoltField;
+use ark_std::log2;
+use std::marker::PhantomData;
+
+use super::LassoSubtable;
+
+/// Subtable indicating whether the two operand halves of the index differ, i.e. the
+/// complement of `EqSubtable`. Used directly (rather than as `1 - EQ`) so that BNE-style
+/// instructions don't need an extra lookup column.
+#[derive(Default)]
+pub struct NeqSubtable<F: JoltField> {
+    _field: PhantomData<F>,
+}
+
+impl<F: JoltField> NeqSubtable<F> {
+    pub fn new() -> Self {
+        Self {
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<F: JoltField> LassoSubtable<F> for NeqSubtable<F> {
+    fn materialize(&self, M: usize) -> Vec<F> {
+        let mut entries: Vec<F> = vec![F::one(); M];
+        let bits_per_operand = (log2(M) / 2) as usize;
+
+        // table[x | y] = x != y
+        for idx in 0..(1 << bits_per_operand) {
+            let concat_idx = idx | (idx << bits_per_operand);
+            entries[concat_idx] = F::zero();
+        }
+        entries
+    }
+
+    fn evaluate_mle(&self, point: &[F]) -> F {
+        // 1 - \prod_i x_i * y_i + (1 - x_i) * (1 - y_i)
+        debug_assert!(point.len() % 2 == 0);
+        let b = point.len() / 2;
+        let (x, y) = point.split_at(b);
+
+        let mut eq = F::one();
+        for i in 0..b {
+            eq *= x[i] * y[i] + (F::one() - x[i]) * (F::one() - y[i]);
+        }
+        F::one() - eq
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ark_bn254::Fr;
+
+    use crate::{
+        jolt::subtable::{neq::NeqSubtable, LassoSubtable},
+        poly::field::JoltField,
+        subtable_materialize_mle_parity_test,
+        utils::index_to_field_bitvector,
+    };
+
+    const OPERAND_BITS: usize = 4;
+
+    subtable_materialize_mle_parity_test!(neq_materialize_mle_parity, NeqSubtable<Fr>, Fr, 256);
+
+    fn assert_neq(x: usize, y: usize) {
+        let subtable = NeqSubtable::<Fr>::new();
+        let index = (x << OPERAND_BITS) | y;
+        let expected = Fr::from_u64((x != y) as u64).unwrap();
+        assert_eq!(
+            subtable.evaluate_mle(&index_to_field_bitvector(index, 2 * OPERAND_BITS)),
+            expected,
+            "{} != {}",
+            x,
+            y
+        );
+        assert_eq!(
+            subtable.materialize(1 << (2 * OPERAND_BITS))[index],
+            expected
+        );
+    }
+
+    #[test]
+    fn neq_native_equivalence_equal_operands() {
+        for x in 0..1 << OPERAND_BITS {
+            assert_neq(x, x);
+        }
+    }
+
+    #[test]
+    fn neq_native_equivalence_differing_operands() {
+        for (x, y) in [(1, 0), (0, 1), (7, 8), (8, 7), (15, 0), (0, 15), (14, 15)] {
+            assert_neq(x, y);
+        }
+    }
+}
@@ -3,7 +3,7 @@ use ark_std::log2;
 use std::marker::PhantomData;
 
 use super::LassoSubtable;
-use crate::utils::split_bits;
+use crate::utils::{powers_of_two, split_bits};
 
 #[derive(Default)]
 pub struct OrSubtable<F: JoltField> {
@@ -39,10 +39,10 @@ impl<F: JoltField> LassoSubtable<F> for OrSubtable<F> {
         let (x, y) = point.split_at(b);
 
         let mut result = F::zero();
-        for i in 0..b {
+        for (i, power) in powers_of_two::<F>(b).enumerate() {
             let x = x[b - i - 1];
             let y = y[b - i - 1];
-            result += F::from_u64(1u64 << i).unwrap() * (x + y - x * y);
+            result += power * (x + y - x * y);
         }
         result
     }
@@ -53,9 +53,14 @@ mod test {
     use ark_bn254::Fr;
 
     use crate::{
-        jolt::subtable::{or::OrSubtable, LassoSubtable},
+        jolt::subtable::{or::OrSubtable, test::assert_materialize_deterministic, LassoSubtable},
         subtable_materialize_mle_parity_test,
     };
 
     subtable_materialize_mle_parity_test!(or_materialize_mle_parity, OrSubtable<Fr>, Fr, 256);
+
+    #[test]
+    fn or_materialize_deterministic() {
+        assert_materialize_deterministic(&OrSubtable::<Fr>::new(), 256);
+    }
 }
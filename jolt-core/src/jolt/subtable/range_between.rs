@@ -0,0 +1,134 @@
+use crate::poly::field::JoltField;
+use std::marker::PhantomData;
+
+use super::LassoSubtable;
+
+/// Subtable of `1` if `LO <= i <= HI` and `0` otherwise.
+///
+/// Since `i < LO` implies `i < HI + 1`, the table is the difference of the tables of
+/// `i < HI + 1` and `i < LO`, and so is its MLE.
+#[derive(Default)]
+pub struct RangeBetweenSubtable<F: JoltField, const LO: u64, const HI: u64> {
+    _field: PhantomData<F>,
+}
+
+impl<F: JoltField, const LO: u64, const HI: u64> RangeBetweenSubtable<F, LO, HI> {
+    pub fn new() -> Self {
+        assert!(LO <= HI, "range [{}, {}] is empty", LO, HI);
+        Self {
+            _field: PhantomData,
+        }
+    }
+
+    /// Evaluates the MLE of the table of `i < bound` at `point`.
+    fn evaluate_lt_mle(point: &[F], bound: u64) -> F {
+        let b = point.len();
+        if b < 64 && bound >= 1 << b {
+            return F::one();
+        }
+
+        // \sum_j [bound_j = 1] * (1 - x_j) * \prod_{k < j} eq(x_k, bound_k), from the MSB down
+        let mut result = F::zero();
+        let mut eq_term = F::one();
+        for (j, x) in point.iter().enumerate() {
+            let bit = bound.checked_shr((b - 1 - j) as u32).unwrap_or(0) & 1;
+            if bit == 1 {
+                result += eq_term * (F::one() - x);
+                eq_term *= x;
+            } else {
+                eq_term *= F::one() - x;
+            }
+        }
+        result
+    }
+}
+
+impl<F: JoltField, const LO: u64, const HI: u64> LassoSubtable<F>
+    for RangeBetweenSubtable<F, LO, HI>
+{
+    fn materialize(&self, M: usize) -> Vec<F> {
+        (0..M as u64)
+            .map(|i| {
+                if (LO..=HI).contains(&i) {
+                    F::one()
+                } else {
+                    F::zero()
+                }
+            })
+            .collect()
+    }
+
+    fn evaluate_mle(&self, point: &[F]) -> F {
+        let below_hi = match HI.checked_add(1) {
+            Some(bound) => Self::evaluate_lt_mle(point, bound),
+            None => F::one(),
+        };
+        below_hi - Self::evaluate_lt_mle(point, LO)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ark_bn254::Fr;
+
+    use crate::{
+        jolt::subtable::{range_between::RangeBetweenSubtable, LassoSubtable},
+        poly::field::JoltField,
+        subtable_materialize_mle_parity_test,
+        utils::index_to_field_bitvector,
+    };
+
+    const M: usize = 256;
+
+    subtable_materialize_mle_parity_test!(
+        range_between_single_index_materialize_mle_parity,
+        RangeBetweenSubtable<Fr, 17, 17>,
+        Fr,
+        256
+    );
+    subtable_materialize_mle_parity_test!(
+        range_between_materialize_mle_parity,
+        RangeBetweenSubtable<Fr, 17, 200>,
+        Fr,
+        256
+    );
+    subtable_materialize_mle_parity_test!(
+        range_between_past_end_materialize_mle_parity,
+        RangeBetweenSubtable<Fr, 100, 1000>,
+        Fr,
+        256
+    );
+
+    fn assert_native_equivalence<const LO: u64, const HI: u64>() {
+        let subtable = RangeBetweenSubtable::<Fr, LO, HI>::new();
+        for i in 0..M {
+            let expected = if (LO..=HI).contains(&(i as u64)) {
+                Fr::one()
+            } else {
+                Fr::zero()
+            };
+            assert_eq!(
+                subtable.evaluate_mle(&index_to_field_bitvector(i, 8)),
+                expected,
+                "MLE of [{}, {}] at index {}",
+                LO,
+                HI,
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn range_between_native_equivalence() {
+        assert_native_equivalence::<0, 0>();
+        assert_native_equivalence::<17, 200>();
+        // Ranges touching the last index M - 1
+        assert_native_equivalence::<0, 255>();
+        assert_native_equivalence::<200, 255>();
+        assert_native_equivalence::<255, 255>();
+        // Ranges extending past the end of the subtable
+        assert_native_equivalence::<100, 1000>();
+        assert_native_equivalence::<256, 300>();
+        assert_native_equivalence::<0, { u64::MAX }>();
+    }
+}
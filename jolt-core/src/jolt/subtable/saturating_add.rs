@@ -0,0 +1,117 @@
+use crate::poly::field::JoltField;
+use crate::utils::powers_of_two;
+use ark_std::log2;
+use std::marker::PhantomData;
+
+use super::LassoSubtable;
+
+/// Subtable of `min(x + y, 2^WIDTH - 1)`, where `x` and `y` are the two `WIDTH`-bit operand
+/// halves of the index. The subtable must therefore have size `M = 2^(2 * WIDTH)`.
+#[derive(Default)]
+pub struct SaturatingAddSubtable<F: JoltField, const WIDTH: usize> {
+    _field: PhantomData<F>,
+}
+
+impl<F: JoltField, const WIDTH: usize> SaturatingAddSubtable<F, WIDTH> {
+    pub fn new() -> Self {
+        Self {
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<F: JoltField, const WIDTH: usize> LassoSubtable<F> for SaturatingAddSubtable<F, WIDTH> {
+    fn materialize(&self, M: usize) -> Vec<F> {
+        let bits_per_operand = (log2(M) / 2) as usize;
+        assert_eq!(bits_per_operand, WIDTH);
+        let saturated: u64 = (1 << WIDTH) - 1;
+
+        (0..M)
+            .map(|idx| {
+                let x = (idx >> WIDTH) as u64;
+                let y = (idx & ((1 << WIDTH) - 1)) as u64;
+                F::from_u64(std::cmp::min(x + y, saturated)).unwrap()
+            })
+            .collect()
+    }
+
+    fn evaluate_mle(&self, point: &[F]) -> F {
+        // Let g_k be the MLE over k-bit operands, and consider their top bits x_t, y_t.
+        // - x_t = y_t = 0: the sum cannot overflow, so g_k = x' + y' for the remaining bits.
+        // - x_t = y_t = 1: the sum always carries out, so g_k is the constant 2^k - 1.
+        // - Otherwise, the top bits contribute exactly 2^(k-1), so g_k = 2^(k-1) + g_{k-1}.
+        // Interpolating these three cases in (x_t, y_t) yields g_k, starting from g_0 = 0.
+        debug_assert!(point.len() == 2 * WIDTH);
+        let b = point.len() / 2;
+        let (x, y) = point.split_at(b);
+
+        let mut result = F::zero();
+        // MLEs of the operands' bits below the current top bit
+        let mut x_rest = F::zero();
+        let mut y_rest = F::zero();
+        for (k, low_half) in (1..=b).zip(powers_of_two::<F>(b)) {
+            let x_top = x[b - k];
+            let y_top = y[b - k];
+
+            let both_zero = (F::one() - x_top) * (F::one() - y_top);
+            let both_one = x_top * y_top;
+            let one_set = x_top + y_top - both_one - both_one;
+            result = both_zero * (x_rest + y_rest)
+                + both_one * (low_half + low_half - F::one())
+                + one_set * (low_half + result);
+
+            x_rest += low_half * x_top;
+            y_rest += low_half * y_top;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ark_bn254::Fr;
+
+    use crate::{
+        jolt::subtable::{saturating_add::SaturatingAddSubtable, LassoSubtable},
+        poly::field::JoltField,
+        subtable_materialize_mle_parity_test,
+        utils::index_to_field_bitvector,
+    };
+
+    const WIDTH: usize = 4;
+
+    subtable_materialize_mle_parity_test!(
+        saturating_add_materialize_mle_parity,
+        SaturatingAddSubtable<Fr, WIDTH>,
+        Fr,
+        256
+    );
+
+    fn assert_saturating_add(x: u64, y: u64) {
+        let subtable = SaturatingAddSubtable::<Fr, WIDTH>::new();
+        let index = ((x << WIDTH) | y) as usize;
+        let expected = Fr::from_u64(std::cmp::min(x + y, (1 << WIDTH) - 1)).unwrap();
+        assert_eq!(
+            subtable.evaluate_mle(&index_to_field_bitvector(index, 2 * WIDTH)),
+            expected,
+            "saturating {} + {}",
+            x,
+            y
+        );
+        assert_eq!(subtable.materialize(1 << (2 * WIDTH))[index], expected);
+    }
+
+    #[test]
+    fn saturating_add_no_overflow() {
+        for (x, y) in [(0, 0), (1, 2), (7, 8), (8, 7), (15, 0), (0, 15), (5, 5)] {
+            assert_saturating_add(x, y);
+        }
+    }
+
+    #[test]
+    fn saturating_add_overflow() {
+        for (x, y) in [(15, 15), (8, 8), (1, 15), (15, 1), (9, 7), (12, 10)] {
+            assert_saturating_add(x, y);
+        }
+    }
+}
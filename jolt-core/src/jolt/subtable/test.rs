@@ -1,3 +1,79 @@
+use super::LassoSubtable;
+use crate::poly::field::JoltField;
+use crate::utils::test_lib::assert_field_vecs_eq;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::test_rng;
+use std::io::{Read, Write};
+
+/// Number of random points at which a fixture records the subtable's MLE.
+const NUM_FIXTURE_EVALUATIONS: usize = 8;
+
+/// A subtable's materialization together with evaluations of its MLE at a handful of random
+/// points, used to check that a subtable's behavior is preserved across refactors.
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+struct SubtableFixture<F: JoltField> {
+    materialized: Vec<F>,
+    points: Vec<Vec<F>>,
+    evaluations: Vec<F>,
+}
+
+/// Writes a fixture for `subtable` of size `M` to `writer` (e.g. a file): the materialized
+/// subtable and `NUM_FIXTURE_EVALUATIONS` evaluations of its MLE at random points.
+pub fn export_fixture<F: JoltField>(subtable: &dyn LassoSubtable<F>, M: usize, writer: impl Write) {
+    let log_M = ark_std::log2(M) as usize;
+    let mut rng = test_rng();
+    let points: Vec<Vec<F>> = (0..NUM_FIXTURE_EVALUATIONS)
+        .map(|_| (0..log_M).map(|_| F::random(&mut rng)).collect())
+        .collect();
+    let fixture = SubtableFixture {
+        materialized: subtable.materialize(M),
+        evaluations: points
+            .iter()
+            .map(|point| subtable.evaluate_mle(point))
+            .collect(),
+        points,
+    };
+
+    fixture.serialize_compressed(writer).unwrap();
+}
+
+/// Asserts that `subtable` (e.g. a re-implementation of the subtable the fixture was exported
+/// from) matches the fixture read from `reader`, both in its materialization and its MLE
+/// evaluations.
+pub fn check_against_fixture<F: JoltField>(subtable: &dyn LassoSubtable<F>, reader: impl Read) {
+    let fixture = SubtableFixture::<F>::deserialize_compressed(reader).unwrap();
+
+    let materialized = subtable.materialize(fixture.materialized.len());
+    for (i, (actual, expected)) in materialized
+        .iter()
+        .zip(fixture.materialized.iter())
+        .enumerate()
+    {
+        assert_eq!(
+            actual, expected,
+            "materialized subtable did not match fixture at index {}",
+            i
+        );
+    }
+    for (point, expected) in fixture.points.iter().zip(fixture.evaluations.iter()) {
+        assert_eq!(
+            subtable.evaluate_mle(point),
+            *expected,
+            "MLE did not match fixture at point {:?}",
+            point
+        );
+    }
+}
+
+/// Materializes `subtable` twice and asserts that both materializations are equal, i.e. that
+/// `materialize` does not depend on mutable or global state.
+pub fn assert_materialize_deterministic<F: JoltField>(subtable: &dyn LassoSubtable<F>, M: usize) {
+    let first = subtable.materialize(M);
+    let second = subtable.materialize(M);
+    assert_eq!(first.len(), M);
+    assert_field_vecs_eq(&first, &second);
+}
+
 #[macro_export]
 /// Tests the consistency of a subtable's `materialize` and `evaluate_mle` methods.
 /// Specifically, checks that the `evaluate_mle` method outputs the same value as the
@@ -2,7 +2,7 @@ use crate::poly::field::JoltField;
 use ark_std::log2;
 use std::marker::PhantomData;
 
-use crate::utils::split_bits;
+use crate::utils::{powers_of_two, split_bits};
 
 use super::LassoSubtable;
 
@@ -42,8 +42,8 @@ impl<F: JoltField, const WORD_SIZE: usize> LassoSubtable<F>
         let cutoff = WORD_SIZE % log_M;
 
         let mut result = F::zero();
-        for i in 0..cutoff {
-            result += F::from_u64(1u64 << i).unwrap() * point[point.len() - 1 - i];
+        for (i, power) in powers_of_two::<F>(cutoff).enumerate() {
+            result += power * point[point.len() - 1 - i];
         }
         result
     }
@@ -1,4 +1,5 @@
 use crate::poly::field::JoltField;
+use crate::utils::powers_of_two;
 use std::marker::PhantomData;
 
 use super::LassoSubtable;
@@ -27,8 +28,8 @@ impl<F: JoltField> LassoSubtable<F> for ZeroLSBSubtable<F> {
     fn evaluate_mle(&self, point: &[F]) -> F {
         let mut result = F::zero();
         // skip LSB
-        for i in 1..point.len() {
-            result += F::from_u64(1u64 << i).unwrap() * point[point.len() - 1 - i];
+        for (i, power) in powers_of_two::<F>(point.len()).enumerate().skip(1) {
+            result += power * point[point.len() - 1 - i];
         }
         result
     }
@@ -132,3 +132,88 @@ fn load_value(row: &RVTraceRow) -> u64 {
         _ => panic!("Unexpected Write"),
     }
 }
+
+/// Decodes a raw RV32I ALU instruction word (register-register or register-immediate) into
+/// its lookup instruction, using `rs1_val` and `rs2_val` as the register operands. For
+/// register-immediate instructions the second operand is the sign-extended immediate and
+/// `rs2_val` is ignored.
+#[rustfmt::skip] // keep matches pretty
+pub fn decode_opcode(raw: u32, rs1_val: u64, rs2_val: u64) -> Result<RV32I, &'static str> {
+    let opcode = raw & 0x7f;
+    let funct3 = (raw >> 12) & 0x7;
+    let funct7 = raw >> 25;
+    let imm = ((raw as i32) >> 20) as u32 as u64;
+    let shamt = imm & 0x1f;
+
+    match (opcode, funct3, funct7) {
+        // OP
+        (0b0110011, 0b000, 0b0000000) => Ok(ADDInstruction(rs1_val, rs2_val).into()),
+        (0b0110011, 0b000, 0b0100000) => Ok(SUBInstruction(rs1_val, rs2_val).into()),
+        (0b0110011, 0b001, 0b0000000) => Ok(SLLInstruction(rs1_val, rs2_val).into()),
+        (0b0110011, 0b010, 0b0000000) => Ok(SLTInstruction(rs1_val, rs2_val).into()),
+        (0b0110011, 0b011, 0b0000000) => Ok(SLTUInstruction(rs1_val, rs2_val).into()),
+        (0b0110011, 0b100, 0b0000000) => Ok(XORInstruction(rs1_val, rs2_val).into()),
+        (0b0110011, 0b101, 0b0000000) => Ok(SRLInstruction(rs1_val, rs2_val).into()),
+        (0b0110011, 0b101, 0b0100000) => Ok(SRAInstruction(rs1_val, rs2_val).into()),
+        (0b0110011, 0b110, 0b0000000) => Ok(ORInstruction(rs1_val, rs2_val).into()),
+        (0b0110011, 0b111, 0b0000000) => Ok(ANDInstruction(rs1_val, rs2_val).into()),
+
+        // OP-IMM
+        (0b0010011, 0b000, _)         => Ok(ADDInstruction(rs1_val, imm).into()),
+        (0b0010011, 0b001, 0b0000000) => Ok(SLLInstruction(rs1_val, shamt).into()),
+        (0b0010011, 0b010, _)         => Ok(SLTInstruction(rs1_val, imm).into()),
+        (0b0010011, 0b011, _)         => Ok(SLTUInstruction(rs1_val, imm).into()),
+        (0b0010011, 0b100, _)         => Ok(XORInstruction(rs1_val, imm).into()),
+        (0b0010011, 0b101, 0b0000000) => Ok(SRLInstruction(rs1_val, shamt).into()),
+        (0b0010011, 0b101, 0b0100000) => Ok(SRAInstruction(rs1_val, shamt).into()),
+        (0b0010011, 0b110, _)         => Ok(ORInstruction(rs1_val, imm).into()),
+        (0b0010011, 0b111, _)         => Ok(ANDInstruction(rs1_val, imm).into()),
+
+        _ => Err("Unsupported instruction encoding"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jolt::instruction::JoltInstruction;
+
+    #[test]
+    fn decode_opcode_matches_native() {
+        let (x, y) = (0xdeadbeefu64, 0x1234_5677u64);
+        let (sx, sy) = (x as u32 as i32, y as u32 as i32);
+
+        // (encoding of `op x3, x1, x2`, native result)
+        let cases: Vec<(u32, u64)> = vec![
+            (0x002081b3, (x as u32).wrapping_add(y as u32) as u64), // add
+            (0x402081b3, (x as u32).wrapping_sub(y as u32) as u64), // sub
+            (0x002091b3, (x as u32).wrapping_shl(y as u32) as u64), // sll
+            (0x0020a1b3, (sx < sy) as u64),                         // slt
+            (0x0020b1b3, (x < y) as u64),                           // sltu
+            (0x0020c1b3, x ^ y),                                    // xor
+            (0x0020d1b3, (x as u32).wrapping_shr(y as u32) as u64), // srl
+            (0x4020d1b3, sx.wrapping_shr(y as u32) as u32 as u64),  // sra
+            (0x0020e1b3, x | y),                                    // or
+            (0x0020f1b3, x & y),                                    // and
+            (0xfff08193, (x as u32).wrapping_sub(1) as u64),        // addi x3, x1, -1
+            (0x0ff0f193, x & 0xff),                                 // andi x3, x1, 255
+            (0x4040d193, (sx >> 4) as u32 as u64),                  // srai x3, x1, 4
+            (0xfff0b193, 1),                                        // sltiu x3, x1, -1
+        ];
+
+        for (raw, expected) in cases {
+            let instruction = decode_opcode(raw, x, y).unwrap();
+            assert_eq!(instruction.lookup_entry(), expected, "raw = {:#010x}", raw);
+        }
+    }
+
+    #[test]
+    fn decode_opcode_unsupported() {
+        // mul x3, x1, x2
+        assert!(decode_opcode(0x022081b3, 0, 0).is_err());
+        // lw x3, 0(x1)
+        assert!(decode_opcode(0x0000a183, 0, 0).is_err());
+        // slli with a nonzero funct7
+        assert!(decode_opcode(0x40109193, 0, 0).is_err());
+    }
+}
@@ -922,6 +922,19 @@ where
         commitment: &InstructionCommitment<CS>,
         transcript: &mut ProofTranscript,
     ) -> Result<(), ProofVerifyError> {
+        Self::verify_with_points(preprocessing, generators, proof, commitment, transcript)
+            .map(|_| ())
+    }
+
+    /// Same as `verify`, but also returns the points at which the primary sumcheck and
+    /// read/write openings were verified.
+    fn verify_with_points(
+        preprocessing: &InstructionLookupsPreprocessing<F>,
+        generators: &CS::Setup,
+        proof: InstructionLookupsProof<C, M, F, CS, InstructionSet, Subtables>,
+        commitment: &InstructionCommitment<CS>,
+        transcript: &mut ProofTranscript,
+    ) -> Result<(Vec<F>, Vec<F>), ProofVerifyError> {
         transcript.append_protocol_name(Self::protocol_name());
 
         let r_eq = transcript.challenge_vector(
@@ -958,7 +971,7 @@ where
             transcript,
         )?;
 
-        Self::verify_memory_checking(
+        let (r_read_write, _) = Self::verify_memory_checking_with_points(
             preprocessing,
             generators,
             proof.memory_checking,
@@ -966,6 +979,92 @@ where
             transcript,
         )?;
 
+        Ok((r_primary_sumcheck, r_read_write))
+    }
+
+    /// Proves the lookups of every step in `instructions` under a single transcript: their
+    /// primary sumchecks are batched into one sumcheck, and their memory checking into one
+    /// batched grand product. Returns the combined proof along with the commitment to the
+    /// instruction lookup polynomials, which is appended to `transcript` before proving.
+    #[tracing::instrument(skip_all, name = "InstructionLookups::batch_prove_instructions")]
+    pub fn batch_prove_instructions(
+        preprocessing: &InstructionLookupsPreprocessing<F>,
+        generators: &CS::Setup,
+        instructions: &[InstructionSet],
+        transcript: &mut ProofTranscript,
+    ) -> (Self, InstructionCommitment<CS>) {
+        let ops: Vec<Option<InstructionSet>> = instructions.iter().cloned().map(Some).collect();
+        let polynomials = Self::polynomialize(preprocessing, &ops);
+        let commitment = polynomials.commit(generators);
+        commitment.append_to_transcript(b"instruction_commitment", transcript);
+
+        let proof = Self::prove(&polynomials, preprocessing, transcript);
+        (proof, commitment)
+    }
+
+    /// Verifies a proof produced by `batch_prove_instructions` for the same `instructions`.
+    ///
+    /// The instructions (including their operands) are public, so the verifier can evaluate
+    /// the `dim`, instruction flag and lookup output polynomials itself. Once the proof
+    /// verifies, their openings in the proof, which are bound to `commitment`, must match
+    /// these evaluations; otherwise the proof was produced for other instructions and
+    /// `ProofVerifyError::PublicInputsMismatch` is returned. This takes time linear in the
+    /// number of instructions but, unlike committing to the polynomials, no MSMs.
+    pub fn batch_verify_instructions(
+        preprocessing: &InstructionLookupsPreprocessing<F>,
+        generators: &CS::Setup,
+        instructions: &[InstructionSet],
+        proof: Self,
+        commitment: &InstructionCommitment<CS>,
+        transcript: &mut ProofTranscript,
+    ) -> Result<(), ProofVerifyError> {
+        let ops: Vec<Option<InstructionSet>> = instructions.iter().cloned().map(Some).collect();
+        let m = ops.len().next_power_of_two();
+        if proof.primary_sumcheck.num_rounds != m.log_2() {
+            return Err(ProofVerifyError::PublicInputsMismatch);
+        }
+
+        let dim_openings = proof
+            .memory_checking
+            .read_write_openings
+            .dim_openings
+            .clone();
+        let flag_openings = proof.primary_sumcheck.openings.flag_openings.clone();
+        let lookup_outputs_opening = proof.primary_sumcheck.openings.lookup_outputs_opening;
+
+        commitment.append_to_transcript(b"instruction_commitment", transcript);
+        let (r_primary_sumcheck, r_read_write) =
+            Self::verify_with_points(preprocessing, generators, proof, commitment, transcript)?;
+        if r_read_write.len() != m.log_2() {
+            return Err(ProofVerifyError::PublicInputsMismatch);
+        }
+
+        let chis = EqPolynomial::new(r_primary_sumcheck).evals();
+        let expected_flag_openings: Vec<F> = Self::instruction_flag_bitvectors(&ops)
+            .par_iter()
+            .map(|flag_bitvector| {
+                DensePolynomial::from_u64(flag_bitvector).evaluate_at_chi_low_optimized(&chis)
+            })
+            .collect();
+        let mut lookup_outputs = Self::compute_lookup_outputs(&ops);
+        lookup_outputs.resize(m, F::zero());
+        let expected_lookup_outputs_opening =
+            DensePolynomial::new(lookup_outputs).evaluate_at_chi_low_optimized(&chis);
+
+        let chis = EqPolynomial::new(r_read_write).evals();
+        let expected_dim_openings: Vec<F> = Self::subtable_lookup_indices(&ops)
+            .par_iter()
+            .map(|access_sequence| {
+                DensePolynomial::from_usize(access_sequence).evaluate_at_chi_low_optimized(&chis)
+            })
+            .collect();
+
+        if expected_flag_openings != flag_openings
+            || expected_lookup_outputs_opening != lookup_outputs_opening
+            || expected_dim_openings != dim_openings
+        {
+            return Err(ProofVerifyError::PublicInputsMismatch);
+        }
         Ok(())
     }
 
@@ -1039,13 +1138,7 @@ where
             })
             .collect();
 
-        let mut instruction_flag_bitvectors: Vec<Vec<u64>> =
-            vec![vec![0u64; m]; Self::NUM_INSTRUCTIONS];
-        for (j, op) in ops.iter().enumerate() {
-            if let Some(op) = op {
-                instruction_flag_bitvectors[InstructionSet::enum_index(op)][j] = 1;
-            }
-        }
+        let instruction_flag_bitvectors = Self::instruction_flag_bitvectors(ops);
 
         let instruction_flag_polys: Vec<DensePolynomial<F>> = instruction_flag_bitvectors
             .par_iter()
@@ -1441,6 +1534,20 @@ where
         vec![read_write_generator_shape, init_final_generator_shape]
     }
 
+    /// Computes, for each instruction in the set, the bitvector flagging the steps of `ops`
+    /// that execute it. Each bitvector is padded to a power of two.
+    fn instruction_flag_bitvectors(ops: &Vec<Option<InstructionSet>>) -> Vec<Vec<u64>> {
+        let m = ops.len().next_power_of_two();
+        let mut instruction_flag_bitvectors: Vec<Vec<u64>> =
+            vec![vec![0u64; m]; Self::NUM_INSTRUCTIONS];
+        for (j, op) in ops.iter().enumerate() {
+            if let Some(op) = op {
+                instruction_flag_bitvectors[InstructionSet::enum_index(op)][j] = 1;
+            }
+        }
+        instruction_flag_bitvectors
+    }
+
     #[tracing::instrument(skip_all, name = "InstructionLookupsProof::compute_lookup_outputs")]
     fn compute_lookup_outputs(instructions: &Vec<Option<InstructionSet>>) -> Vec<F> {
         instructions
@@ -151,16 +151,21 @@ pub type RV32IJoltProof<F, CS> = JoltProof<C, M, F, CS, RV32I, RV32ISubtables<F>
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use ark_bn254::{Fr, G1Projective};
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use common::rv_trace::ELFInstruction;
+    use rand::SeedableRng;
 
     use std::collections::HashSet;
 
     use crate::host;
-    use crate::jolt::instruction::JoltInstruction;
-    use crate::jolt::vm::rv32i_vm::{Jolt, RV32IJoltVM, C, M};
+    use crate::jolt::vm::instruction_lookups::{
+        InstructionLookupsPreprocessing, InstructionLookupsProof,
+    };
     use crate::poly::commitment::hyrax::HyraxScheme;
+    use crate::utils::{errors::ProofVerifyError, transcript::ProofTranscript};
     use std::sync::Mutex;
-    use strum::{EnumCount, IntoEnumIterator};
 
     // If multiple tests try to read the same trace artifacts simultaneously, they will fail
     lazy_static::lazy_static! {
@@ -189,6 +194,116 @@ mod tests {
         );
     }
 
+    instruction_set!(
+      BatchTestInstructionSet,
+      ADD: ADDInstruction<WORD_SIZE>,
+      AND: ANDInstruction,
+      XOR: XORInstruction
+    );
+    subtable_enum!(
+      BatchTestSubtables,
+      AND: AndSubtable<F>,
+      IDENTITY: IdentitySubtable<F>,
+      TRUNCATE: TruncateOverflowSubtable<F, WORD_SIZE>,
+      XOR: XorSubtable<F>
+    );
+
+    impl TryFrom<&ELFInstruction> for BatchTestInstructionSet {
+        type Error = &'static str;
+
+        fn try_from(_: &ELFInstruction) -> Result<Self, Self::Error> {
+            Err("BatchTestInstructionSet is not decoded from ELF instructions")
+        }
+    }
+
+    #[test]
+    fn batch_prove_instructions_e2e() {
+        type CS = HyraxScheme<G1Projective>;
+        type BatchProof =
+            InstructionLookupsProof<C, M, Fr, CS, BatchTestInstructionSet, BatchTestSubtables<Fr>>;
+
+        let mut rng = StdRng::seed_from_u64(246);
+        let instructions: Vec<BatchTestInstructionSet> = (0..16)
+            .map(|i| match i % 3 {
+                0 => ADDInstruction::<WORD_SIZE>::default()
+                    .random(&mut rng)
+                    .into(),
+                1 => ANDInstruction::default().random(&mut rng).into(),
+                _ => XORInstruction::default().random(&mut rng).into(),
+            })
+            .collect();
+
+        let preprocessing = InstructionLookupsPreprocessing::preprocess::<
+            C,
+            M,
+            BatchTestInstructionSet,
+            BatchTestSubtables<Fr>,
+        >();
+        let generators = CS::setup(&BatchProof::commitment_shapes(
+            &preprocessing,
+            instructions.len(),
+        ));
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let (proof, commitment) = BatchProof::batch_prove_instructions(
+            &preprocessing,
+            &generators,
+            &instructions,
+            &mut transcript,
+        );
+        let mut proof_bytes = vec![];
+        proof.serialize_compressed(&mut proof_bytes).unwrap();
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let verification_result = BatchProof::batch_verify_instructions(
+            &preprocessing,
+            &generators,
+            &instructions,
+            proof,
+            &commitment,
+            &mut transcript,
+        );
+        assert!(
+            verification_result.is_ok(),
+            "Verification failed with error: {:?}",
+            verification_result.err()
+        );
+
+        // Altering the operands of any single step must be rejected
+        for step in 0..instructions.len() {
+            let mut tampered = instructions.clone();
+            tampered[step] = match tampered[step] {
+                BatchTestInstructionSet::ADD(ADDInstruction(x, y)) => {
+                    ADDInstruction::<WORD_SIZE>(x ^ 1, y).into()
+                }
+                BatchTestInstructionSet::AND(ANDInstruction(x, y)) => {
+                    ANDInstruction(x, y ^ 1).into()
+                }
+                BatchTestInstructionSet::XOR(XORInstruction(x, y)) => {
+                    XORInstruction(x ^ 1, y).into()
+                }
+            };
+
+            let proof = BatchProof::deserialize_compressed(&proof_bytes[..]).unwrap();
+            let mut transcript = ProofTranscript::new(b"test_transcript");
+            assert!(
+                matches!(
+                    BatchProof::batch_verify_instructions(
+                        &preprocessing,
+                        &generators,
+                        &tampered,
+                        proof,
+                        &commitment,
+                        &mut transcript,
+                    ),
+                    Err(ProofVerifyError::PublicInputsMismatch)
+                ),
+                "Tampered step {} verified",
+                step
+            );
+        }
+    }
+
     #[test]
     fn fib_e2e() {
         let _guard = FIB_FILE_LOCK.lock().unwrap();
@@ -0,0 +1,738 @@
+use crate::poly::{commitment::commitment_scheme::BatchType, field::JoltField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use std::marker::PhantomData;
+
+use crate::{
+    lasso::memory_checking::{
+        Fingerprinter, MemoryCheckingProof, MemoryCheckingProver, MemoryCheckingVerifier,
+        NoPreprocessing, ReedSolomonFingerprinter,
+    },
+    poly::{
+        commitment::commitment_scheme::CommitmentScheme,
+        dense_mlpoly::DensePolynomial,
+        eq_poly::EqPolynomial,
+        identity_poly::IdentityPolynomial,
+        structured_poly::{StructuredCommitment, StructuredOpeningProof},
+    },
+    utils::{
+        errors::ProofVerifyError,
+        index_to_field_bitvector, is_power_of_two,
+        math::Math,
+        transcript::{AppendToTranscript, ProofTranscript},
+    },
+};
+
+/// Polynomials for lookups into a single table that is an arbitrary committed vector, rather
+/// than a structured subtable whose MLE the verifier can evaluate itself.
+pub struct CommittedTablePolys<F, PCS>
+where
+    F: JoltField,
+    PCS: CommitmentScheme<Field = F>,
+{
+    _marker: PhantomData<PCS>,
+    pub table: DensePolynomial<F>,
+    pub dim: DensePolynomial<F>,
+    /// `values[i] = table[dim[i]]`
+    pub values: DensePolynomial<F>,
+    pub read_cts: DensePolynomial<F>,
+    pub final_cts: DensePolynomial<F>,
+}
+
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+pub struct CommittedTableCommitment<CS: CommitmentScheme> {
+    /// Commitments to the dim, values, and read_cts polynomials.
+    pub read_write_commitment: Vec<CS::Commitment>,
+    /// Commitment to the final_cts polynomial.
+    pub final_commitment: CS::Commitment,
+    /// Commitment to the table the prover looked up into, checked by the verifier against its
+    /// own (see `CommittedTableProof::verify`).
+    pub table_commitment: Option<CS::Commitment>,
+}
+
+impl<F, PCS> StructuredCommitment<PCS> for CommittedTablePolys<F, PCS>
+where
+    F: JoltField,
+    PCS: CommitmentScheme<Field = F>,
+{
+    type Commitment = CommittedTableCommitment<PCS>;
+
+    #[tracing::instrument(skip_all, name = "CommittedTablePolys::commit")]
+    fn commit(&self, generators: &PCS::Setup) -> Self::Commitment {
+        let read_write_commitment = PCS::batch_commit_polys_ref(
+            &[&self.dim, &self.values, &self.read_cts],
+            generators,
+            BatchType::SurgeReadWrite,
+        );
+        let final_commitment =
+            PCS::batch_commit_polys_ref(&[&self.final_cts], generators, BatchType::SurgeInitFinal)
+                .pop()
+                .unwrap();
+
+        Self::Commitment {
+            read_write_commitment,
+            final_commitment,
+            table_commitment: None,
+        }
+    }
+}
+
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+pub struct CommittedTableReadWriteOpenings<F: JoltField> {
+    dim_opening: F,
+    value_opening: F,
+    read_opening: F,
+}
+
+impl<F, PCS> StructuredOpeningProof<F, PCS, CommittedTablePolys<F, PCS>>
+    for CommittedTableReadWriteOpenings<F>
+where
+    F: JoltField,
+    PCS: CommitmentScheme<Field = F>,
+{
+    type Proof = PCS::BatchedProof;
+
+    #[tracing::instrument(skip_all, name = "CommittedTableReadWriteOpenings::open")]
+    fn open(polynomials: &CommittedTablePolys<F, PCS>, opening_point: &[F]) -> Self {
+        let chis = EqPolynomial::new(opening_point.to_vec()).evals();
+        Self {
+            dim_opening: polynomials.dim.evaluate_at_chi(&chis),
+            value_opening: polynomials.values.evaluate_at_chi(&chis),
+            read_opening: polynomials.read_cts.evaluate_at_chi(&chis),
+        }
+    }
+
+    #[tracing::instrument(skip_all, name = "CommittedTableReadWriteOpenings::prove_openings")]
+    fn prove_openings(
+        polynomials: &CommittedTablePolys<F, PCS>,
+        opening_point: &[F],
+        openings: &Self,
+        transcript: &mut ProofTranscript,
+    ) -> Self::Proof {
+        PCS::batch_prove(
+            &[&polynomials.dim, &polynomials.values, &polynomials.read_cts],
+            opening_point,
+            &[
+                openings.dim_opening,
+                openings.value_opening,
+                openings.read_opening,
+            ],
+            BatchType::SurgeReadWrite,
+            transcript,
+        )
+    }
+
+    fn verify_openings(
+        &self,
+        generators: &PCS::Setup,
+        opening_proof: &Self::Proof,
+        commitment: &CommittedTableCommitment<PCS>,
+        opening_point: &[F],
+        transcript: &mut ProofTranscript,
+    ) -> Result<(), ProofVerifyError> {
+        PCS::batch_verify(
+            opening_proof,
+            generators,
+            opening_point,
+            &[self.dim_opening, self.value_opening, self.read_opening],
+            &commitment.read_write_commitment.iter().collect::<Vec<_>>(),
+            transcript,
+        )
+    }
+}
+
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+pub struct CommittedTableInitFinalOpenings<F: JoltField> {
+    final_opening: F,
+    /// Opening of the committed table, which takes the place of the subtable MLE evaluation
+    /// computed by the verifier in Surge.
+    table_opening: F,
+    a_init_final: Option<F>, // Computed by verifier
+}
+
+impl<F, PCS> StructuredOpeningProof<F, PCS, CommittedTablePolys<F, PCS>>
+    for CommittedTableInitFinalOpenings<F>
+where
+    F: JoltField,
+    PCS: CommitmentScheme<Field = F>,
+{
+    type Proof = PCS::BatchedProof;
+
+    #[tracing::instrument(skip_all, name = "CommittedTableInitFinalOpenings::open")]
+    fn open(polynomials: &CommittedTablePolys<F, PCS>, opening_point: &[F]) -> Self {
+        let chis = EqPolynomial::new(opening_point.to_vec()).evals();
+        Self {
+            final_opening: polynomials.final_cts.evaluate_at_chi(&chis),
+            table_opening: polynomials.table.evaluate_at_chi(&chis),
+            a_init_final: None,
+        }
+    }
+
+    #[tracing::instrument(skip_all, name = "CommittedTableInitFinalOpenings::prove_openings")]
+    fn prove_openings(
+        polynomials: &CommittedTablePolys<F, PCS>,
+        opening_point: &[F],
+        openings: &Self,
+        transcript: &mut ProofTranscript,
+    ) -> Self::Proof {
+        PCS::batch_prove(
+            &[&polynomials.final_cts, &polynomials.table],
+            opening_point,
+            &[openings.final_opening, openings.table_opening],
+            BatchType::SurgeInitFinal,
+            transcript,
+        )
+    }
+
+    fn compute_verifier_openings(&mut self, _: &NoPreprocessing, opening_point: &[F]) {
+        self.a_init_final =
+            Some(IdentityPolynomial::new(opening_point.len()).evaluate(opening_point));
+    }
+
+    fn verify_openings(
+        &self,
+        generators: &PCS::Setup,
+        opening_proof: &Self::Proof,
+        commitment: &CommittedTableCommitment<PCS>,
+        opening_point: &[F],
+        transcript: &mut ProofTranscript,
+    ) -> Result<(), ProofVerifyError> {
+        let table_commitment = commitment
+            .table_commitment
+            .as_ref()
+            .ok_or(ProofVerifyError::InternalError)?;
+        PCS::batch_verify(
+            opening_proof,
+            generators,
+            opening_point,
+            &[self.final_opening, self.table_opening],
+            &[&commitment.final_commitment, table_commitment],
+            transcript,
+        )
+    }
+}
+
+/// Proof that the committed `values` are lookups into a table given only by its commitment, at
+/// the committed addresses. The init and final multisets are checked against an opening of the
+/// table commitment instead of an evaluation of a known subtable MLE, which generalizes Lasso to
+/// arbitrary user-supplied tables at the cost of one more opening.
+pub struct CommittedTableProof<F, PCS>
+where
+    F: JoltField,
+    PCS: CommitmentScheme<Field = F>,
+{
+    /// Commitments to all polynomials except the table
+    commitment: CommittedTableCommitment<PCS>,
+
+    memory_checking: MemoryCheckingProof<
+        F,
+        PCS,
+        CommittedTablePolys<F, PCS>,
+        CommittedTableReadWriteOpenings<F>,
+        CommittedTableInitFinalOpenings<F>,
+    >,
+}
+
+/// Succinct proof that a single value is an entry of a committed table (see
+/// `CommittedTableProof::prove_single_lookup`).
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+pub struct SingleLookupProof<PCS: CommitmentScheme> {
+    /// Index of the table entry equal to the value
+    address: usize,
+    /// Opening of the table commitment at `address`
+    opening_proof: PCS::BatchedProof,
+}
+
+impl<F, PCS> CommittedTableProof<F, PCS>
+where
+    F: JoltField,
+    PCS: CommitmentScheme<Field = F>,
+{
+    fn protocol_name() -> &'static [u8] {
+        b"Committed table lookups"
+    }
+
+    /// Commits to `table` as expected by `verify`.
+    pub fn commit_table(table: &DensePolynomial<F>, generators: &PCS::Setup) -> PCS::Commitment {
+        PCS::batch_commit_polys_ref(&[table], generators, BatchType::SurgeInitFinal)
+            .pop()
+            .unwrap()
+    }
+
+    /// Returns the commitment to the looked-up values, `table[addresses[i]]`, padded with
+    /// `table[0]` to a power of two.
+    pub fn values_commitment(&self) -> &PCS::Commitment {
+        &self.commitment.read_write_commitment[1]
+    }
+
+    #[tracing::instrument(skip_all, name = "CommittedTableProof::prove")]
+    pub fn prove(
+        generators: &PCS::Setup,
+        table: &DensePolynomial<F>,
+        addresses: &[usize],
+        transcript: &mut ProofTranscript,
+    ) -> Self {
+        transcript.append_protocol_name(Self::protocol_name());
+
+        let polynomials = Self::construct_polys(table, addresses);
+        let mut commitment = polynomials.commit(generators);
+        commitment.table_commitment = Some(Self::commit_table(table, generators));
+        // The fingerprint challenges must be drawn after the witness is fixed
+        Self::append_commitments(&commitment, transcript);
+        let memory_checking =
+            Self::prove_memory_checking(&NoPreprocessing, &polynomials, transcript);
+
+        Self {
+            commitment,
+            memory_checking,
+        }
+    }
+
+    /// Verifies `proof` against `table_commitment` (see `commit_table`), which the verifier
+    /// must obtain independently of the proof. Returns `TableCommitmentMismatch` if the proof
+    /// looks up into a table committed to differently.
+    pub fn verify(
+        generators: &PCS::Setup,
+        mut proof: Self,
+        table_commitment: PCS::Commitment,
+        transcript: &mut ProofTranscript,
+    ) -> Result<(), ProofVerifyError> {
+        transcript.append_protocol_name(Self::protocol_name());
+
+        // Commitments are compared by their canonical encodings
+        let encode = |commitment: &PCS::Commitment| {
+            let mut bytes = vec![];
+            commitment
+                .serialize_compressed(&mut bytes)
+                .map(|_| bytes)
+                .map_err(|_| ProofVerifyError::InternalError)
+        };
+        let proof_table_commitment = proof
+            .commitment
+            .table_commitment
+            .as_ref()
+            .ok_or(ProofVerifyError::InternalError)?;
+        if encode(proof_table_commitment)? != encode(&table_commitment)? {
+            return Err(ProofVerifyError::TableCommitmentMismatch);
+        }
+        proof.commitment.table_commitment = Some(table_commitment);
+
+        Self::append_commitments(&proof.commitment, transcript);
+        Self::verify_memory_checking(
+            &NoPreprocessing,
+            generators,
+            proof.memory_checking,
+            &proof.commitment,
+            transcript,
+        )
+    }
+
+    /// Appends the table commitment followed by the prover's commitments to `transcript`.
+    fn append_commitments(
+        commitment: &CommittedTableCommitment<PCS>,
+        transcript: &mut ProofTranscript,
+    ) {
+        if let Some(table_commitment) = &commitment.table_commitment {
+            table_commitment.append_to_transcript(b"table_commitment", transcript);
+        }
+        for read_write_commitment in &commitment.read_write_commitment {
+            read_write_commitment.append_to_transcript(b"read_write_commitment", transcript);
+        }
+        commitment
+            .final_commitment
+            .append_to_transcript(b"final_commitment", transcript);
+    }
+
+    fn single_lookup_protocol_name() -> &'static [u8] {
+        b"Committed table single lookup"
+    }
+
+    /// Proves that `value` is an entry of `table`, committed to as `table_commitment` (see
+    /// `commit_table`), returning `None` if it is not. This is `prove` specialized to a single
+    /// lookup: with one access, memory checking reduces to `table[address] == value`, so
+    /// instead of grand products the proof is just an opening of the table commitment at the
+    /// Boolean point `address`, which the proof reveals. Its size and verification cost are
+    /// those of one opening.
+    #[tracing::instrument(skip_all, name = "CommittedTableProof::prove_single_lookup")]
+    pub fn prove_single_lookup(
+        table: &DensePolynomial<F>,
+        table_commitment: &PCS::Commitment,
+        value: F,
+        transcript: &mut ProofTranscript,
+    ) -> Option<SingleLookupProof<PCS>> {
+        let address = table.evals_ref().iter().position(|entry| *entry == value)?;
+
+        Self::append_single_lookup(table_commitment, address, &value, transcript);
+        let point: Vec<F> = index_to_field_bitvector(address, table.get_num_vars());
+        let opening_proof = PCS::batch_prove(
+            &[table],
+            &point,
+            &[value],
+            BatchType::SurgeInitFinal,
+            transcript,
+        );
+
+        Some(SingleLookupProof {
+            address,
+            opening_proof,
+        })
+    }
+
+    /// Verifies `proof` that `value` is an entry of the table of size `table_size` committed to
+    /// as `table_commitment`, which the verifier must obtain independently of the proof.
+    /// Returns `InvalidTableSize` if `table_size` is not a power of two or the proof's address
+    /// is out of its range.
+    pub fn verify_single_lookup(
+        generators: &PCS::Setup,
+        proof: &SingleLookupProof<PCS>,
+        table_commitment: &PCS::Commitment,
+        table_size: usize,
+        value: F,
+        transcript: &mut ProofTranscript,
+    ) -> Result<(), ProofVerifyError> {
+        if !is_power_of_two(table_size) || proof.address >= table_size {
+            return Err(ProofVerifyError::InvalidTableSize(
+                proof.address,
+                table_size,
+            ));
+        }
+
+        Self::append_single_lookup(table_commitment, proof.address, &value, transcript);
+        let point: Vec<F> = index_to_field_bitvector(proof.address, table_size.log_2());
+        PCS::batch_verify(
+            &proof.opening_proof,
+            generators,
+            &point,
+            &[value],
+            &[table_commitment],
+            transcript,
+        )
+    }
+
+    fn append_single_lookup(
+        table_commitment: &PCS::Commitment,
+        address: usize,
+        value: &F,
+        transcript: &mut ProofTranscript,
+    ) {
+        transcript.append_protocol_name(Self::single_lookup_protocol_name());
+        table_commitment.append_to_transcript(b"table_commitment", transcript);
+        transcript.append_scalar(b"value", value);
+        transcript.append_u64(b"address", address as u64);
+    }
+
+    #[tracing::instrument(skip_all, name = "CommittedTableProof::construct_polys")]
+    fn construct_polys(
+        table: &DensePolynomial<F>,
+        addresses: &[usize],
+    ) -> CommittedTablePolys<F, PCS> {
+        let M = table.len();
+        assert!(is_power_of_two(M));
+        let num_lookups = addresses.len().next_power_of_two();
+
+        let mut dim = vec![0usize; num_lookups];
+        let mut read_cts = vec![0usize; num_lookups];
+        let mut final_cts = vec![0usize; M];
+
+        // As in Surge, padding lookups read address 0 so that write_ts = read_ts + 1 holds
+        // throughout.
+        for (i, memory_address) in addresses
+            .iter()
+            .copied()
+            .chain(std::iter::repeat(0))
+            .take(num_lookups)
+            .enumerate()
+        {
+            assert!(memory_address < M);
+            dim[i] = memory_address;
+            read_cts[i] = final_cts[memory_address];
+            final_cts[memory_address] += 1;
+        }
+
+        let values: Vec<F> = dim.iter().map(|&address| table[address]).collect();
+
+        CommittedTablePolys {
+            _marker: PhantomData,
+            table: table.clone(),
+            dim: DensePolynomial::from_usize(&dim),
+            values: DensePolynomial::new(values),
+            read_cts: DensePolynomial::from_usize(&read_cts),
+            final_cts: DensePolynomial::from_usize(&final_cts),
+        }
+    }
+}
+
+impl<F, PCS> MemoryCheckingProver<F, PCS, CommittedTablePolys<F, PCS>>
+    for CommittedTableProof<F, PCS>
+where
+    F: JoltField,
+    PCS: CommitmentScheme<Field = F>,
+{
+    type ReadWriteOpenings = CommittedTableReadWriteOpenings<F>;
+    type InitFinalOpenings = CommittedTableInitFinalOpenings<F>;
+
+    fn fingerprint(inputs: &(F, F, F), gamma: &F, tau: &F) -> F {
+        ReedSolomonFingerprinter::fingerprint(
+            inputs,
+            &ReedSolomonFingerprinter::challenges(gamma, tau),
+        )
+    }
+
+    #[tracing::instrument(skip_all, name = "CommittedTableProof::compute_leaves")]
+    fn compute_leaves(
+        _: &NoPreprocessing,
+        polynomials: &CommittedTablePolys<F, PCS>,
+        gamma: &F,
+        tau: &F,
+    ) -> (Vec<DensePolynomial<F>>, Vec<DensePolynomial<F>>) {
+        let challenges = ReedSolomonFingerprinter::challenges(gamma, tau);
+        let (read_fingerprints, write_fingerprints): (Vec<F>, Vec<F>) = (0..polynomials.dim.len())
+            .map(|i| {
+                let a = polynomials.dim[i];
+                let v = polynomials.values[i];
+                let t = polynomials.read_cts[i];
+                let read_fingerprint =
+                    ReedSolomonFingerprinter::fingerprint(&(a, v, t), &challenges);
+                let write_fingerprint = ReedSolomonFingerprinter::add_to_timestamp(
+                    &read_fingerprint,
+                    &(a, v, t),
+                    &F::one(),
+                    &challenges,
+                );
+                (read_fingerprint, write_fingerprint)
+            })
+            .unzip();
+
+        let (init_fingerprints, final_fingerprints): (Vec<F>, Vec<F>) =
+            (0..polynomials.table.len())
+                .map(|i| {
+                    let a = F::from_u64(i as u64).unwrap();
+                    let v = polynomials.table[i];
+                    let t = polynomials.final_cts[i];
+                    let init_fingerprint =
+                        ReedSolomonFingerprinter::fingerprint(&(a, v, F::zero()), &challenges);
+                    let final_fingerprint = ReedSolomonFingerprinter::add_to_timestamp(
+                        &init_fingerprint,
+                        &(a, v, F::zero()),
+                        &t,
+                        &challenges,
+                    );
+                    (init_fingerprint, final_fingerprint)
+                })
+                .unzip();
+
+        (
+            vec![
+                DensePolynomial::new(read_fingerprints),
+                DensePolynomial::new(write_fingerprints),
+            ],
+            vec![
+                DensePolynomial::new(init_fingerprints),
+                DensePolynomial::new(final_fingerprints),
+            ],
+        )
+    }
+
+    fn protocol_name() -> &'static [u8] {
+        b"Committed table memory checking"
+    }
+}
+
+impl<F, PCS> MemoryCheckingVerifier<F, PCS, CommittedTablePolys<F, PCS>>
+    for CommittedTableProof<F, PCS>
+where
+    F: JoltField,
+    PCS: CommitmentScheme<Field = F>,
+{
+    fn read_tuples(
+        _: &NoPreprocessing,
+        openings: &Self::ReadWriteOpenings,
+    ) -> Vec<Self::MemoryTuple> {
+        vec![(
+            openings.dim_opening,
+            openings.value_opening,
+            openings.read_opening,
+        )]
+    }
+    fn write_tuples(
+        _: &NoPreprocessing,
+        openings: &Self::ReadWriteOpenings,
+    ) -> Vec<Self::MemoryTuple> {
+        vec![(
+            openings.dim_opening,
+            openings.value_opening,
+            openings.read_opening + F::one(),
+        )]
+    }
+    fn init_tuples(
+        _: &NoPreprocessing,
+        openings: &Self::InitFinalOpenings,
+    ) -> Vec<Self::MemoryTuple> {
+        vec![(
+            openings.a_init_final.unwrap(),
+            openings.table_opening,
+            F::zero(),
+        )]
+    }
+    fn final_tuples(
+        _: &NoPreprocessing,
+        openings: &Self::InitFinalOpenings,
+    ) -> Vec<Self::MemoryTuple> {
+        vec![(
+            openings.a_init_final.unwrap(),
+            openings.table_opening,
+            openings.final_opening,
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommittedTableProof, SingleLookupProof};
+    use crate::{
+        poly::{
+            commitment::{hyrax::HyraxScheme, pedersen::PedersenGenerators},
+            dense_mlpoly::DensePolynomial,
+            field::JoltField,
+        },
+        utils::{errors::ProofVerifyError, transcript::ProofTranscript},
+    };
+    use ark_bn254::{Fr, G1Projective};
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::test_rng;
+    use rand_chacha::rand_core::RngCore;
+
+    type Proof = CommittedTableProof<Fr, HyraxScheme<G1Projective>>;
+
+    #[test]
+    fn e2e() {
+        const M: usize = 1 << 8;
+        let mut rng = test_rng();
+        let table = DensePolynomial::new((0..M).map(|_| Fr::random(&mut rng)).collect());
+        let other_table = DensePolynomial::new((0..M).map(|_| Fr::random(&mut rng)).collect());
+        let addresses: Vec<usize> = (0..12).map(|_| rng.next_u32() as usize % M).collect();
+        let generators = PedersenGenerators::new(1 << 8, b"test");
+
+        let prove = || {
+            let mut transcript = ProofTranscript::new(b"test_transcript");
+            Proof::prove(&generators, &table, &addresses, &mut transcript)
+        };
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        Proof::verify(
+            &generators,
+            prove(),
+            Proof::commit_table(&table, &generators),
+            &mut transcript,
+        )
+        .expect("should work");
+
+        // The verifier holds a commitment to a different table
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        assert!(matches!(
+            Proof::verify(
+                &generators,
+                prove(),
+                Proof::commit_table(&other_table, &generators),
+                &mut transcript,
+            ),
+            Err(ProofVerifyError::TableCommitmentMismatch)
+        ));
+
+        // Claiming the verifier's table commitment does not help a prover that looked up into
+        // another table
+        let mut proof = prove();
+        proof.commitment.table_commitment = Some(Proof::commit_table(&other_table, &generators));
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        assert!(Proof::verify(
+            &generators,
+            proof,
+            Proof::commit_table(&other_table, &generators),
+            &mut transcript,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn single_lookup() {
+        const M: usize = 1 << 8;
+        let mut rng = test_rng();
+        let table = DensePolynomial::new((0..M).map(|_| Fr::random(&mut rng)).collect());
+        let generators = PedersenGenerators::new(1 << 8, b"test");
+        let table_commitment = Proof::commit_table(&table, &generators);
+
+        let value = table[42];
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let proof = Proof::prove_single_lookup(&table, &table_commitment, value, &mut transcript)
+            .expect("value is in the table");
+
+        // The proof survives a serialization round trip
+        let mut bytes = vec![];
+        proof.serialize_compressed(&mut bytes).unwrap();
+        let proof =
+            SingleLookupProof::<HyraxScheme<G1Projective>>::deserialize_compressed(&bytes[..])
+                .unwrap();
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        Proof::verify_single_lookup(
+            &generators,
+            &proof,
+            &table_commitment,
+            M,
+            value,
+            &mut transcript,
+        )
+        .expect("should work");
+
+        // The proof's address must index a table of the given size
+        for table_size in [M / 8, M - 1] {
+            let mut transcript = ProofTranscript::new(b"test_transcript");
+            assert!(matches!(
+                Proof::verify_single_lookup(
+                    &generators,
+                    &proof,
+                    &table_commitment,
+                    table_size,
+                    value,
+                    &mut transcript,
+                ),
+                Err(ProofVerifyError::InvalidTableSize(42, size)) if size == table_size
+            ));
+        }
+
+        // A commitment to a tampered table does not verify
+        let mut tampered_evals = table.evals_ref().to_vec();
+        tampered_evals[42] += Fr::from_u64(1).unwrap();
+        let tampered_table = DensePolynomial::new(tampered_evals);
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        assert!(matches!(
+            Proof::verify_single_lookup(
+                &generators,
+                &proof,
+                &Proof::commit_table(&tampered_table, &generators),
+                M,
+                value,
+                &mut transcript,
+            ),
+            Err(ProofVerifyError::InternalError)
+        ));
+
+        // An absent value cannot be proven, and a proof for another value does not verify
+        let absent = Fr::random(&mut rng);
+        assert!(!table.evals_ref().contains(&absent));
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        assert!(
+            Proof::prove_single_lookup(&table, &table_commitment, absent, &mut transcript)
+                .is_none()
+        );
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        assert!(Proof::verify_single_lookup(
+            &generators,
+            &proof,
+            &table_commitment,
+            M,
+            absent,
+            &mut transcript
+        )
+        .is_err());
+    }
+}
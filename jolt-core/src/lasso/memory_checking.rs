@@ -9,7 +9,8 @@ use crate::poly::{
 use crate::subprotocols::grand_product::{
     BatchedGrandProductArgument, BatchedGrandProductCircuit, GrandProductCircuit,
 };
-use crate::utils::errors::ProofVerifyError;
+use crate::utils::errors::{MemoryCheckingStage, ProofVerifyError, WitnessError};
+use crate::utils::mul_0_1_optimized;
 use crate::utils::transcript::ProofTranscript;
 
 use crate::poly::field::JoltField;
@@ -69,6 +70,71 @@ where
 // Empty struct to represent that no preprocessing data is used.
 pub struct NoPreprocessing;
 
+/// Combines a memory tuple (a, v, t) into a single field element, parametrized by `gamma` and
+/// `tau`. Used by memory-checking instances that let the fingerprint be configured, in which
+/// case the prover and verifier must be instantiated with the same `Fingerprinter`.
+pub trait Fingerprinter<F: JoltField>: Sync {
+    /// Values derived from `gamma` and `tau`, computed once per batch of fingerprints.
+    type Challenges: Sync;
+
+    fn challenges(gamma: &F, tau: &F) -> Self::Challenges;
+
+    fn fingerprint(tuple: &(F, F, F), challenges: &Self::Challenges) -> F;
+
+    /// Returns the fingerprint of `(a, v, t + dt)`, given `fingerprint`, that of `tuple` =
+    /// `(a, v, t)`. Fingerprints that are linear in `t` should derive it from `fingerprint`
+    /// rather than recompute it.
+    fn add_to_timestamp(
+        _fingerprint: &F,
+        tuple: &(F, F, F),
+        dt: &F,
+        challenges: &Self::Challenges,
+    ) -> F {
+        let (a, v, t) = tuple;
+        Self::fingerprint(&(*a, *v, *t + *dt), challenges)
+    }
+}
+
+/// The Reed-Solomon fingerprint `t * gamma^2 + v * gamma + a - tau`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ReedSolomonFingerprinter;
+
+/// `gamma`, `gamma^2` and `tau`, as used by `ReedSolomonFingerprinter`.
+pub struct ReedSolomonChallenges<F: JoltField> {
+    gamma: F,
+    gamma_squared: F,
+    tau: F,
+}
+
+impl<F: JoltField> Fingerprinter<F> for ReedSolomonFingerprinter {
+    type Challenges = ReedSolomonChallenges<F>;
+
+    fn challenges(gamma: &F, tau: &F) -> Self::Challenges {
+        ReedSolomonChallenges {
+            gamma: *gamma,
+            gamma_squared: gamma.square(),
+            tau: *tau,
+        }
+    }
+
+    fn fingerprint(tuple: &(F, F, F), challenges: &Self::Challenges) -> F {
+        let (a, v, t) = tuple;
+        mul_0_1_optimized(t, &challenges.gamma_squared)
+            + mul_0_1_optimized(v, &challenges.gamma)
+            + *a
+            - challenges.tau
+    }
+
+    fn add_to_timestamp(
+        fingerprint: &F,
+        _tuple: &(F, F, F),
+        dt: &F,
+        challenges: &Self::Challenges,
+    ) -> F {
+        *fingerprint + mul_0_1_optimized(dt, &challenges.gamma_squared)
+    }
+}
+
 pub trait MemoryCheckingProver<F, C, Polynomials>
 where
     F: JoltField,
@@ -328,7 +394,7 @@ where
     fn verify_memory_checking(
         preprocessing: &Self::Preprocessing,
         generators: &C::Setup,
-        mut proof: MemoryCheckingProof<
+        proof: MemoryCheckingProof<
             F,
             C,
             Polynomials,
@@ -338,6 +404,32 @@ where
         commitments: &Polynomials::Commitment,
         transcript: &mut ProofTranscript,
     ) -> Result<(), ProofVerifyError> {
+        Self::verify_memory_checking_with_points(
+            preprocessing,
+            generators,
+            proof,
+            commitments,
+            transcript,
+        )
+        .map(|_| ())
+    }
+
+    /// Same as `verify_memory_checking`, but also returns the points at which the read/write
+    /// and init/final openings were verified, e.g. for a caller to check openings of
+    /// polynomials it can evaluate itself.
+    fn verify_memory_checking_with_points(
+        preprocessing: &Self::Preprocessing,
+        generators: &C::Setup,
+        mut proof: MemoryCheckingProof<
+            F,
+            C,
+            Polynomials,
+            Self::ReadWriteOpenings,
+            Self::InitFinalOpenings,
+        >,
+        commitments: &Polynomials::Commitment,
+        transcript: &mut ProofTranscript,
+    ) -> Result<(Vec<F>, Vec<F>), ProofVerifyError> {
         // Fiat-Shamir randomness for multiset hashes
         let gamma: F = transcript.challenge_scalar(b"Memory checking gamma");
         let tau: F = transcript.challenge_scalar(b"Memory checking tau");
@@ -387,9 +479,8 @@ where
             &proof.init_final_openings,
             &gamma,
             &tau,
-        );
-
-        Ok(())
+        )?;
+        Ok((r_read_write, r_init_final))
     }
 
     /// Computes "read" memory tuples (one per memory) from the given `openings`.
@@ -413,8 +504,16 @@ where
         openings: &Self::InitFinalOpenings,
     ) -> Vec<Self::MemoryTuple>;
 
+    /// Maps the index of a memory to the chunk reported when its fingerprints mismatch, e.g. the
+    /// dimension of the lookups into it. By default, each memory is its own chunk.
+    fn memory_to_chunk_index(memory_index: usize) -> usize {
+        memory_index
+    }
+
     /// Checks that the claimed multiset hashes (output by grand product) are consistent with the
-    /// openings given by `read_write_openings` and `init_final_openings`.
+    /// openings given by `read_write_openings` and `init_final_openings`. On a mismatch, returns
+    /// a `MemoryCheckingError` naming the first failing multiset and the chunk of the memory it
+    /// belongs to (see `memory_to_chunk_index`).
     fn check_fingerprints(
         preprocessing: &Self::Preprocessing,
         claims_read_write: Vec<F>,
@@ -423,7 +522,7 @@ where
         init_final_openings: &Self::InitFinalOpenings,
         gamma: &F,
         tau: &F,
-    ) {
+    ) -> Result<(), ProofVerifyError> {
         let read_hashes: Vec<_> = Self::read_tuples(preprocessing, read_write_openings)
             .iter()
             .map(|tuple| Self::fingerprint(tuple, gamma, tau))
@@ -449,20 +548,115 @@ where
             claims_init_final.len()
         );
 
-        let multiset_hashes = MultisetHashes {
-            read_hashes,
-            write_hashes,
-            init_hashes,
-            final_hashes,
-        };
-        let (read_write_hashes, init_final_hashes) =
-            Self::interleave_hashes(preprocessing, &multiset_hashes);
+        // Claims are uninterleaved so that a mismatch can be attributed to a memory and stage
+        let claims = Self::uninterleave_hashes(preprocessing, claims_read_write, claims_init_final);
+        let stages = [
+            (MemoryCheckingStage::Read, claims.read_hashes, read_hashes),
+            (
+                MemoryCheckingStage::Write,
+                claims.write_hashes,
+                write_hashes,
+            ),
+            (MemoryCheckingStage::Init, claims.init_hashes, init_hashes),
+            (
+                MemoryCheckingStage::Final,
+                claims.final_hashes,
+                final_hashes,
+            ),
+        ];
+        for (stage, claims, fingerprints) in stages {
+            for (memory_index, (claim, fingerprint)) in zip(claims, fingerprints).enumerate() {
+                if claim != fingerprint {
+                    return Err(ProofVerifyError::MemoryCheckingError(
+                        Self::memory_to_chunk_index(memory_index),
+                        stage,
+                    ));
+                }
+            }
+        }
 
-        for (claim, fingerprint) in zip(claims_read_write, read_write_hashes) {
-            assert_eq!(claim, fingerprint);
+        Ok(())
+    }
+}
+
+/// Checks the timestamps of a single memory against the access sequence `indices` by replaying
+/// the timestamp logic of offline memory checking: each access reads its cell's current
+/// counter as its timestamp and writes back the counter plus one, and `final_cts` holds each
+/// cell's counter after all accesses. A witness failing this check would only be caught by the
+/// memory-checking grand products, after committing and with no indication of the culprit; this
+/// reports the first inconsistent access or cell instead.
+pub fn verify_timestamps(
+    read_ts: &[usize],
+    final_cts: &[usize],
+    indices: &[usize],
+) -> Result<(), WitnessError> {
+    if read_ts.len() != indices.len() {
+        return Err(WitnessError::LengthMismatch(
+            "read_ts",
+            indices.len(),
+            read_ts.len(),
+        ));
+    }
+
+    let memory_size = final_cts.len();
+    let mut counters = vec![0usize; memory_size];
+    for (access_index, (&address, &ts)) in zip(indices, read_ts).enumerate() {
+        if address >= memory_size {
+            return Err(WitnessError::AddressOutOfBounds(
+                access_index,
+                address,
+                memory_size,
+            ));
         }
-        for (claim, fingerprint) in zip(claims_init_final, init_final_hashes) {
-            assert_eq!(claim, fingerprint);
+        if ts != counters[address] {
+            return Err(WitnessError::ReadTimestampMismatch(
+                access_index,
+                counters[address],
+                ts,
+            ));
         }
+        counters[address] += 1;
+    }
+
+    for (address, (&expected, &actual)) in zip(&counters, final_cts).enumerate() {
+        if expected != actual {
+            return Err(WitnessError::FinalTimestampMismatch(
+                address, expected, actual,
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::verify_timestamps;
+    use crate::utils::errors::WitnessError;
+
+    #[test]
+    fn timestamps() {
+        let indices = [2, 0, 2, 3, 2, 0];
+        let read_ts = [0, 0, 1, 0, 2, 1];
+        let final_cts = [2, 0, 3, 1];
+        assert_eq!(verify_timestamps(&read_ts, &final_cts, &indices), Ok(()));
+
+        let mut tampered = read_ts;
+        tampered[4] = 1;
+        assert_eq!(
+            verify_timestamps(&tampered, &final_cts, &indices),
+            Err(WitnessError::ReadTimestampMismatch(4, 2, 1))
+        );
+
+        let mut tampered = final_cts;
+        tampered[1] = 1;
+        assert_eq!(
+            verify_timestamps(&read_ts, &tampered, &indices),
+            Err(WitnessError::FinalTimestampMismatch(1, 0, 1))
+        );
+
+        assert_eq!(
+            verify_timestamps(&read_ts, &final_cts[..2], &indices),
+            Err(WitnessError::AddressOutOfBounds(0, 2, 2))
+        );
     }
 }
@@ -1,2 +1,3 @@
+pub mod committed_table;
 pub mod memory_checking;
 pub mod surge;
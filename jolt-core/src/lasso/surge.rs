@@ -1,20 +1,33 @@
 use crate::poly::{commitment::commitment_scheme::BatchType, field::JoltField};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
+use std::borrow::Cow;
 use std::marker::{PhantomData, Sync};
 
 use crate::{
-    jolt::instruction::JoltInstruction,
-    lasso::memory_checking::{MemoryCheckingProof, MemoryCheckingProver, MemoryCheckingVerifier},
+    jolt::{instruction::JoltInstruction, subtable::LassoSubtable},
+    lasso::memory_checking::{
+        Fingerprinter, MemoryCheckingProof, MemoryCheckingProver, MemoryCheckingVerifier,
+        MultisetHashes, ReedSolomonFingerprinter,
+    },
     poly::{
-        commitment::{commitment_scheme::CommitmentScheme, hyrax::matrix_dimensions},
+        commitment::{
+            commitment_scheme::CommitmentScheme,
+            hyrax::{batch_type_to_ratio, matrix_dimensions},
+        },
         dense_mlpoly::DensePolynomial,
-        eq_poly::EqPolynomial,
+        eq_poly::{EqPolynomial, PrecomputedEq},
         identity_poly::IdentityPolynomial,
         structured_poly::{StructuredCommitment, StructuredOpeningProof},
     },
     subprotocols::sumcheck::SumcheckInstanceProof,
-    utils::{errors::ProofVerifyError, math::Math, mul_0_1_optimized, transcript::ProofTranscript},
+    utils::{
+        compute_dotproduct,
+        errors::{AssembleError, ProofVerifyError},
+        index_to_field_bitvector,
+        math::Math,
+        transcript::{AppendToTranscript, ProofTranscript, TranscriptTrace},
+    },
 };
 
 pub struct SurgePolys<F, PCS>
@@ -39,6 +52,30 @@ pub struct SurgeCommitment<CS: CommitmentScheme> {
     pub E_commitment: Vec<CS::Commitment>,
 }
 
+impl<F, PCS> SurgePolys<F, PCS>
+where
+    F: JoltField,
+    PCS: CommitmentScheme<Field = F>,
+{
+    /// Returns the access positions `(dimension_index, lookup_index)` whose memory cell was
+    /// already accessed by an earlier lookup in the same dimension, i.e. those with a nonzero
+    /// read timestamp. Includes the padding lookups (to address 0) added by the prover.
+    pub fn duplicate_indices(&self) -> Vec<(usize, usize)> {
+        self.read_cts
+            .iter()
+            .enumerate()
+            .flat_map(|(dimension_index, read_cts)| {
+                read_cts
+                    .evals_ref()
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, read_ts)| !read_ts.is_zero())
+                    .map(move |(lookup_index, _)| (dimension_index, lookup_index))
+            })
+            .collect()
+    }
+}
+
 impl<F, PCS> StructuredCommitment<PCS> for SurgePolys<F, PCS>
 where
     F: JoltField,
@@ -293,20 +330,20 @@ where
     }
 }
 
-impl<F, PCS, Instruction, const C: usize, const M: usize>
-    MemoryCheckingProver<F, PCS, SurgePolys<F, PCS>> for SurgeProof<F, PCS, Instruction, C, M>
+impl<F, PCS, Instruction, const C: usize, const M: usize, FP>
+    MemoryCheckingProver<F, PCS, SurgePolys<F, PCS>> for SurgeProof<F, PCS, Instruction, C, M, FP>
 where
     F: JoltField,
     PCS: CommitmentScheme<Field = F>,
     Instruction: JoltInstruction + Default + Sync,
+    FP: Fingerprinter<F>,
 {
     type Preprocessing = SurgePreprocessing<F, Instruction, C, M>;
     type ReadWriteOpenings = SurgeReadWriteOpenings<F>;
     type InitFinalOpenings = SurgeFinalOpenings<F, Instruction, C, M>;
 
     fn fingerprint(inputs: &(F, F, F), gamma: &F, tau: &F) -> F {
-        let (a, v, t) = *inputs;
-        t * gamma.square() + v * *gamma + a - tau
+        FP::fingerprint(inputs, &FP::challenges(gamma, tau))
     }
 
     #[tracing::instrument(skip_all, name = "Surge::compute_leaves")]
@@ -316,25 +353,28 @@ where
         gamma: &F,
         tau: &F,
     ) -> (Vec<DensePolynomial<F>>, Vec<DensePolynomial<F>>) {
-        let gamma_squared = gamma.square();
         let num_lookups = polynomials.dim[0].len();
+        let challenges = FP::challenges(gamma, tau);
 
         let read_write_leaves = (0..Self::num_memories())
             .into_par_iter()
             .flat_map_iter(|memory_index| {
                 let dim_index = Self::memory_to_dimension_index(memory_index);
-                let read_fingerprints: Vec<F> = (0..num_lookups)
+                let (read_fingerprints, write_fingerprints): (Vec<F>, Vec<F>) = (0..num_lookups)
                     .map(|i| {
-                        mul_0_1_optimized(&polynomials.read_cts[dim_index][i], &gamma_squared)
-                            + mul_0_1_optimized(&polynomials.E_polys[memory_index][i], gamma)
-                            + polynomials.dim[dim_index][i]
-                            - *tau
+                        let a = polynomials.dim[dim_index][i];
+                        let v = polynomials.E_polys[memory_index][i];
+                        let t = polynomials.read_cts[dim_index][i];
+                        let read_fingerprint = FP::fingerprint(&(a, v, t), &challenges);
+                        let write_fingerprint = FP::add_to_timestamp(
+                            &read_fingerprint,
+                            &(a, v, t),
+                            &F::one(),
+                            &challenges,
+                        );
+                        (read_fingerprint, write_fingerprint)
                     })
-                    .collect();
-                let write_fingerprints = read_fingerprints
-                    .iter()
-                    .map(|read_fingerprint| *read_fingerprint + gamma_squared)
-                    .collect();
+                    .unzip();
 
                 vec![
                     DensePolynomial::new(read_fingerprints),
@@ -343,40 +383,44 @@ where
             })
             .collect();
 
-        let init_final_leaves = (0..Self::num_memories())
-            .into_par_iter()
-            .flat_map_iter(|memory_index| {
-                let dim_index = Self::memory_to_dimension_index(memory_index);
-                let subtable_index = Self::memory_to_subtable_index(memory_index);
-                // TODO(moodlezoup): Only need one init polynomial per subtable
-                let init_fingerprints: Vec<F> = (0..M)
-                    .map(|i| {
-                        // 0 * gamma^2 +
-                        mul_0_1_optimized(
-                            &preprocessing.materialized_subtables[subtable_index][i],
-                            gamma,
-                        ) + F::from_u64(i as u64).unwrap()
-                            - *tau
-                    })
-                    .collect();
-                let final_fingerprints = init_fingerprints
-                    .iter()
-                    .enumerate()
-                    .map(|(i, init_fingerprint)| {
-                        *init_fingerprint
-                            + mul_0_1_optimized(
-                                &polynomials.final_cts[dim_index][i],
-                                &gamma_squared,
-                            )
-                    })
-                    .collect();
-
-                vec![
-                    DensePolynomial::new(init_fingerprints),
-                    DensePolynomial::new(final_fingerprints),
-                ]
-            })
-            .collect();
+        // Each subtable's entries are shared by the memories of all dimensions. Subtables are
+        // materialized (if not preprocessed) one at a time, only for the memories that use them;
+        // those memories are contiguous, so the leaves stay in memory order.
+        let mut init_final_leaves = Vec::with_capacity(2 * Self::num_memories());
+        for subtable_index in 0..preprocessing.subtables.len() {
+            let subtable_entries = preprocessing.subtable_entries(subtable_index);
+            let leaves: Vec<DensePolynomial<F>> = (0..Self::num_memories())
+                .into_par_iter()
+                .filter(|&memory_index| {
+                    Self::memory_to_subtable_index(memory_index) == subtable_index
+                })
+                .flat_map_iter(|memory_index| {
+                    let dim_index = Self::memory_to_dimension_index(memory_index);
+                    // TODO(moodlezoup): Only need one init polynomial per subtable
+                    let (init_fingerprints, final_fingerprints): (Vec<F>, Vec<F>) = (0..M)
+                        .map(|i| {
+                            let a = F::from_u64(i as u64).unwrap();
+                            let v = subtable_entries[i];
+                            let t = polynomials.final_cts[dim_index][i];
+                            let init_fingerprint = FP::fingerprint(&(a, v, F::zero()), &challenges);
+                            let final_fingerprint = FP::add_to_timestamp(
+                                &init_fingerprint,
+                                &(a, v, F::zero()),
+                                &t,
+                                &challenges,
+                            );
+                            (init_fingerprint, final_fingerprint)
+                        })
+                        .unzip();
+
+                    vec![
+                        DensePolynomial::new(init_fingerprints),
+                        DensePolynomial::new(final_fingerprints),
+                    ]
+                })
+                .collect();
+            init_final_leaves.extend(leaves);
+        }
 
         (read_write_leaves, init_final_leaves)
     }
@@ -386,13 +430,18 @@ where
     }
 }
 
-impl<F, CS, Instruction, const C: usize, const M: usize>
-    MemoryCheckingVerifier<F, CS, SurgePolys<F, CS>> for SurgeProof<F, CS, Instruction, C, M>
+impl<F, CS, Instruction, const C: usize, const M: usize, FP>
+    MemoryCheckingVerifier<F, CS, SurgePolys<F, CS>> for SurgeProof<F, CS, Instruction, C, M, FP>
 where
     F: JoltField,
     CS: CommitmentScheme<Field = F>,
     Instruction: JoltInstruction + Default + Sync,
+    FP: Fingerprinter<F>,
 {
+    fn memory_to_chunk_index(memory_index: usize) -> usize {
+        Self::memory_to_dimension_index(memory_index)
+    }
+
     fn read_tuples(
         _preprocessing: &SurgePreprocessing<F, Instruction, C, M>,
         openings: &Self::ReadWriteOpenings,
@@ -472,22 +521,166 @@ where
     opening_proof: PCS::BatchedProof,
 }
 
+/// Proof that the outputs of the lookups proven by a `SurgeProof` equal the values committed
+/// to by an external protocol (see `SurgeProof::prove_output_binding`).
+pub struct OutputBindingProof<F, PCS>
+where
+    F: JoltField,
+    PCS: CommitmentScheme<Field = F>,
+{
+    /// Evaluation of the externally committed outputs at the random binding point.
+    output_eval: F,
+    output_opening_proof: PCS::Proof,
+    /// Proves that the lookup outputs, as determined by the `SurgeProof`'s `E_i` commitments,
+    /// evaluate to `output_eval` at the binding point.
+    primary_sumcheck: SurgePrimarySumcheck<F, PCS>,
+}
+
+/// Intermediate claims of a `SurgeProof`, exposed so that they can be reconciled against an
+/// independent computation.
+#[derive(Debug)]
+pub struct SurgeAuditReport<F: JoltField> {
+    /// Claimed sum over the Boolean hypercube, proven by the primary sumcheck.
+    pub hypercube_sum: F,
+    /// Openings of the E_i polynomials at the primary sumcheck's random point, from which
+    /// the final sumcheck claim is computed.
+    pub primary_sumcheck_openings: Vec<F>,
+    /// Read/write/init/final grand products, one of each per memory.
+    pub read_hashes: Vec<F>,
+    pub write_hashes: Vec<F>,
+    pub init_hashes: Vec<F>,
+    pub final_hashes: Vec<F>,
+}
+
+/// Optional checks and inputs of `SurgeProof::verify_with_config`.
+#[derive(Clone, Copy, Debug)]
+pub struct VerifierConfig<'a, F: JoltField> {
+    /// Check that the proof's commitments, openings, multiset hashes and grand products have
+    /// the sizes implied by `C`, `M` and the instruction's subtables (as `SurgeProof::assemble`
+    /// does) before verifying, returning `InvalidInputLength` for a misshapen proof instead of
+    /// panicking on it. A well-shaped proof with a forged grand product layer still fails an
+    /// assertion in `BatchedGrandProductArgument::verify`.
+    pub audit: bool,
+    /// Require every primary sumcheck round polynomial to have exactly the expected degree,
+    /// rather than at most the expected degree.
+    pub strict_degree_bound: bool,
+    /// eq(r, x) bound ahead of time (see `EqPolynomial::precompute_bound`) to the primary
+    /// sumcheck's random point r, from which the MLE of `public_inputs`' outputs is evaluated
+    /// at r. As r only depends on the transcript state and the number of lookups, a single
+    /// `PrecomputedEq` serves every proof verified from the same state. Verification fails if
+    /// it is bound to a different point; it is unused without `public_inputs`.
+    pub precomputed_eq: Option<&'a PrecomputedEq<F>>,
+    /// Public inputs the proof must have been produced for (see `verify_with_public_inputs`).
+    pub public_inputs: Option<&'a PublicInputs<F>>,
+    /// Check the multiset equalities `init_i * write_i == final_i * read_i` of all memories
+    /// as a single random linear combination, only going through the memories one at a time
+    /// to find the failing one if it does not hold.
+    pub batch: bool,
+}
+
+impl<'a, F: JoltField> Default for VerifierConfig<'a, F> {
+    fn default() -> Self {
+        Self {
+            audit: false,
+            strict_degree_bound: true,
+            precomputed_eq: None,
+            public_inputs: None,
+            batch: false,
+        }
+    }
+}
+
+/// Approximate cost of verifying a `SurgeProof` (see `SurgeProof::verifier_cost_estimate`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VerifierCost {
+    /// Field multiplications, excluding those spent evaluating subtable MLEs.
+    pub field_mults: usize,
+    /// Total number of bases across all multi-scalar multiplications.
+    pub msm_size: usize,
+    /// Number of challenges squeezed from the transcript.
+    pub hash_invocations: usize,
+}
+
+/// Identifies the tables a `SurgeProof` performs lookups into. It is a digest of `C`, `M` and
+/// each subtable's MLE evaluated at a fixed point derived from `C` and `M` alone. Identical
+/// tables always carry the same handle, and distinct tables that were not chosen with the
+/// (public) point in mind carry different ones with high probability. The handle is not
+/// binding: tables can be constructed to agree with others at the point, so it guards against
+/// honestly mismatched preprocessing, not against a prover choosing its tables.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TableHandle<F: JoltField> {
+    digest: F,
+}
+
+impl<F: JoltField> TableHandle<F> {
+    fn new(subtables: &[Box<dyn LassoSubtable<F>>], C: usize, M: usize) -> Self {
+        let mut transcript = ProofTranscript::new(b"TableHandle");
+        transcript.append_u64(b"C", C as u64);
+        transcript.append_u64(b"M", M as u64);
+        let point: Vec<F> = transcript.challenge_vector(b"point", M.log_2());
+        let evals: Vec<F> = subtables
+            .iter()
+            .map(|subtable| subtable.evaluate_mle(&point))
+            .collect();
+        transcript.append_scalars(b"subtable_evals", &evals);
+        Self {
+            digest: transcript.challenge_scalar(b"digest"),
+        }
+    }
+}
+
+/// The public inputs of a `SurgeProof`: the tables looked up into, the decomposition
+/// parameters and the claimed outputs of every lookup, including the padding lookups (see
+/// `SurgeProof::prove_with_public_inputs`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicInputs<F: JoltField> {
+    pub table_handle: TableHandle<F>,
+    pub C: usize,
+    pub M: usize,
+    pub outputs: Vec<F>,
+}
+
+impl<F: JoltField> AppendToTranscript for PublicInputs<F> {
+    fn append_to_transcript(&self, label: &'static [u8], transcript: &mut ProofTranscript) {
+        transcript.append_message(label, b"public_inputs_begin");
+        transcript.append_scalar(b"table_handle", &self.table_handle.digest);
+        transcript.append_u64(b"C", self.C as u64);
+        transcript.append_u64(b"M", self.M as u64);
+        transcript.append_scalars(b"outputs", &self.outputs);
+        transcript.append_message(label, b"public_inputs_end");
+    }
+}
+
 pub struct SurgePreprocessing<F, Instruction, const C: usize, const M: usize>
 where
     F: JoltField,
     Instruction: JoltInstruction + Default,
 {
     _instruction: PhantomData<Instruction>,
-    materialized_subtables: Vec<Vec<F>>,
+    subtables: Vec<Box<dyn LassoSubtable<F>>>,
+    /// `None` if subtable entries are instead computed from their MLEs (see
+    /// `preprocess_mle_only`).
+    materialized_subtables: Option<Vec<Vec<F>>>,
+    table_handle: TableHandle<F>,
 }
 
+/// `FP` is the fingerprint used by memory checking; the prover and verifier must agree on it.
 #[allow(clippy::type_complexity)]
-pub struct SurgeProof<F, PCS, Instruction, const C: usize, const M: usize>
-where
+pub struct SurgeProof<
+    F,
+    PCS,
+    Instruction,
+    const C: usize,
+    const M: usize,
+    FP = ReedSolomonFingerprinter,
+> where
     F: JoltField,
     PCS: CommitmentScheme<Field = F>,
     Instruction: JoltInstruction + Default,
 {
+    /// Handle of the tables looked up into, checked by the verifier against its preprocessing
+    table_handle: TableHandle<F>,
+
     /// Commitments to all polynomials
     commitment: SurgeCommitment<PCS>,
 
@@ -501,6 +694,29 @@ where
         SurgeReadWriteOpenings<F>,
         SurgeFinalOpenings<F, Instruction, C, M>,
     >,
+
+    _fingerprinter: PhantomData<FP>,
+}
+
+/// The independently provable parts of a `SurgeProof`, e.g. produced on different machines,
+/// as returned by `SurgeProof::into_components` and consumed by `SurgeProof::assemble`.
+#[allow(clippy::type_complexity)]
+pub struct SurgeProofComponents<F, PCS, Instruction, const C: usize, const M: usize>
+where
+    F: JoltField,
+    PCS: CommitmentScheme<Field = F>,
+    Instruction: JoltInstruction + Default,
+{
+    pub table_handle: TableHandle<F>,
+    pub commitment: SurgeCommitment<PCS>,
+    pub primary_sumcheck: SurgePrimarySumcheck<F, PCS>,
+    pub memory_checking: MemoryCheckingProof<
+        F,
+        PCS,
+        SurgePolys<F, PCS>,
+        SurgeReadWriteOpenings<F>,
+        SurgeFinalOpenings<F, Instruction, C, M>,
+    >,
 }
 
 impl<F, Instruction, const C: usize, const M: usize> SurgePreprocessing<F, Instruction, C, M>
@@ -510,26 +726,81 @@ where
 {
     #[tracing::instrument(skip_all, name = "Surge::preprocess")]
     pub fn preprocess() -> Self {
-        let instruction = Instruction::default();
+        let mut preprocessing = Self::preprocess_mle_only();
+        preprocessing.materialized_subtables = Some(
+            preprocessing
+                .subtables
+                .par_iter()
+                .map(|subtable| subtable.materialize(M))
+                .collect(),
+        );
+        preprocessing
+    }
 
-        let materialized_subtables = instruction
+    /// Preprocessing that does not hold materialized subtables. The prover still needs every
+    /// entry of every subtable -- the `E_i` polynomials index into them and the init leaves span
+    /// them all -- so it materializes each subtable in turn, twice per proof, and holds at most
+    /// one at a time. This saves the memory of all but the largest subtable, at the cost of
+    /// recomputing them on every proof. The init/final check itself evaluates the subtable MLEs
+    /// at the grand product's opening point, as the verifier does. This is only sound for
+    /// subtables whose `evaluate_mle` is exact on the hypercube, which is already required of
+    /// the verifier. Proofs are identical to those produced with `preprocess`.
+    pub fn preprocess_mle_only() -> Self {
+        let subtables = Instruction::default()
             .subtables(C, M)
-            .par_iter()
-            .map(|(subtable, _)| subtable.materialize(M))
-            .collect();
+            .into_iter()
+            .map(|(subtable, _)| subtable)
+            .collect::<Vec<_>>();
+        let table_handle = TableHandle::new(&subtables, C, M);
 
         Self {
             _instruction: PhantomData,
-            materialized_subtables,
+            subtables,
+            materialized_subtables: None,
+            table_handle,
+        }
+    }
+
+    pub fn table_handle(&self) -> &TableHandle<F> {
+        &self.table_handle
+    }
+
+    /// The public inputs of a proof over these tables whose (padded) lookups output `outputs`.
+    pub fn public_inputs(&self, outputs: Vec<F>) -> PublicInputs<F> {
+        PublicInputs {
+            table_handle: self.table_handle,
+            C,
+            M,
+            outputs,
+        }
+    }
+
+    /// Returns the entries of the subtable at `subtable_index`, materializing them if they were
+    /// not preprocessed.
+    fn subtable_entries(&self, subtable_index: usize) -> Cow<[F]> {
+        match &self.materialized_subtables {
+            Some(materialized_subtables) => Cow::Borrowed(&materialized_subtables[subtable_index]),
+            None => Cow::Owned(self.subtables[subtable_index].materialize(M)),
+        }
+    }
+
+    /// Returns the entry at `index` of the subtable at `subtable_index`.
+    fn subtable_entry(&self, subtable_index: usize, index: usize) -> F {
+        match &self.materialized_subtables {
+            Some(materialized_subtables) => materialized_subtables[subtable_index][index],
+            None => self.subtables[subtable_index]
+                .evaluate_mle(&index_to_field_bitvector(index, M.log_2())),
         }
     }
 }
 
-impl<F, PCS, Instruction, const C: usize, const M: usize> SurgeProof<F, PCS, Instruction, C, M>
+impl<F, PCS, Instruction, const C: usize, const M: usize, FP>
+    SurgeProof<F, PCS, Instruction, C, M, FP>
 where
     F: JoltField,
     PCS: CommitmentScheme<Field = F>,
     Instruction: JoltInstruction + Default + Sync,
+    FP: Fingerprinter<F>,
 {
     fn num_memories() -> usize {
         C * Instruction::default().subtables::<F>(C, M).len()
@@ -549,6 +820,12 @@ where
         b"Surge"
     }
 
+    /// Returns the handle of the tables this proof looks up into. Lookups batched into the
+    /// same table should carry equal handles.
+    pub fn table_handle(&self) -> &TableHandle<F> {
+        &self.table_handle
+    }
+
     /// Computes the maximum number of group generators needed to commit to Surge polynomials
     /// using Hyrax, given `M` and the maximum number of lookups.
     pub fn num_generators(max_num_lookups: usize) -> usize {
@@ -559,29 +836,216 @@ where
         std::cmp::max(num_read_write_generators, num_init_final_generators)
     }
 
-    #[tracing::instrument(skip_all, name = "Surge::prove")]
+    /// Estimates the work done by `verify` on a proof of `num_lookups` lookups, assuming the
+    /// Hyrax commitment scheme (as does `num_generators`).
+    pub fn verifier_cost_estimate(num_lookups: usize) -> VerifierCost {
+        let num_memories = Self::num_memories();
+        let num_rounds = num_lookups.next_power_of_two().log_2();
+        let log_M = M.log_2();
+        let primary_sumcheck_degree = Instruction::default().g_poly_degree(C) + 1;
+
+        // A batched Hyrax opening of `num_polys` polynomials squeezes one RLC coefficient per
+        // polynomial, then computes an MSM over the L_size row commitments and an MSM over
+        // R_size generators. Computing the factored eq evals and the final dot product takes
+        // O(L_size + R_size) multiplications.
+        let opening_cost = |num_vars: usize, batch_type: BatchType, num_polys: usize| {
+            let (L_size, R_size) = matrix_dimensions(num_vars, batch_type_to_ratio(&batch_type));
+            VerifierCost {
+                field_mults: L_size + 2 * R_size + num_polys,
+                msm_size: L_size + R_size,
+                hash_invocations: num_polys,
+            }
+        };
+        // A batched grand product over `num_claims` circuits of depth `num_layers` runs a
+        // cubic sumcheck with `layer` rounds for each layer, preceded by one RLC coefficient
+        // per claim and followed by one challenge to reduce to the next layer. Each round costs
+        // ~4 multiplications to evaluate the round polynomial and ~3 to evaluate eq, and each
+        // claim ~5 to combine and reduce.
+        let grand_product_cost = |num_claims: usize, num_layers: usize| {
+            (0..num_layers).fold(
+                VerifierCost {
+                    field_mults: 0,
+                    msm_size: 0,
+                    hash_invocations: 0,
+                },
+                |cost, layer| VerifierCost {
+                    field_mults: cost.field_mults + 7 * layer + 5 * num_claims,
+                    msm_size: 0,
+                    hash_invocations: cost.hash_invocations + layer + num_claims + 1,
+                },
+            )
+        };
+
+        let costs = [
+            // Primary sumcheck: challenges for the eq point and each round, eq(r, r_z) and the
+            // E_i openings
+            VerifierCost {
+                field_mults: num_rounds * (primary_sumcheck_degree + 1) + 3 * num_rounds,
+                msm_size: 0,
+                hash_invocations: 2 * num_rounds,
+            },
+            opening_cost(num_rounds, BatchType::SurgeReadWrite, num_memories),
+            // Memory checking: gamma and tau, the two grand products, their openings, and the
+            // read/write/init/final fingerprints
+            VerifierCost {
+                field_mults: 4 * num_memories * 2,
+                msm_size: 0,
+                hash_invocations: 2,
+            },
+            grand_product_cost(2 * num_memories, num_rounds),
+            grand_product_cost(2 * num_memories, log_M),
+            opening_cost(num_rounds, BatchType::SurgeReadWrite, 2 * C + num_memories),
+            opening_cost(log_M, BatchType::SurgeInitFinal, C),
+        ];
+        VerifierCost {
+            field_mults: costs.iter().map(|cost| cost.field_mults).sum(),
+            msm_size: costs.iter().map(|cost| cost.msm_size).sum(),
+            hash_invocations: costs.iter().map(|cost| cost.hash_invocations).sum(),
+        }
+    }
+
+    /// Estimates the peak number of bytes allocated by `prove` for `num_lookups` lookups,
+    /// excluding the preprocessing. The committed polynomials (`dim`, `read_cts`, `final_cts`
+    /// and `E_polys`) live throughout proving; on top of them, the peak is reached in either
+    /// - the primary sumcheck, which binds copies of the `E_polys` and an eq table, or
+    /// - memory checking, which holds the grand product leaves and circuit layers (about twice
+    ///   the leaves) of the read/write circuits while building the init/final circuits.
+    ///
+    /// The actual peak is expected to be within a factor of 2 of this estimate.
+    pub fn peak_memory_estimate(num_lookups: usize) -> usize {
+        let num_memories = Self::num_memories();
+        let num_lookups = num_lookups.next_power_of_two();
+
+        let committed = (2 * C + num_memories) * num_lookups + C * M;
+        let primary_sumcheck = (num_memories + 1) * num_lookups;
+        // Read/write leaves and circuits plus init/final leaves, or read/write circuits plus
+        // init/final leaves and circuits
+        let memory_checking =
+            2 * num_memories * std::cmp::max(3 * num_lookups + M, 2 * num_lookups + 3 * M);
+
+        (committed + std::cmp::max(primary_sumcheck, memory_checking)) * std::mem::size_of::<F>()
+    }
+
     pub fn prove(
         preprocessing: &SurgePreprocessing<F, Instruction, C, M>,
         generators: &PCS::Setup,
         ops: Vec<Instruction>,
         transcript: &mut ProofTranscript,
     ) -> Self {
-        transcript.append_protocol_name(Self::protocol_name());
+        Self::prove_with_opening_point(preprocessing, generators, ops, transcript).0
+    }
 
-        let num_lookups = ops.len().next_power_of_two();
+    /// Same as `prove`, but also returns the point `r_z` that the primary sumcheck reduced to
+    /// and the openings of the E_i polynomials at `r_z`, so that an outer protocol can continue
+    /// from this claim without re-deriving the sumcheck's challenges. `verify` checks these
+    /// openings against the E_i commitments.
+    #[tracing::instrument(skip_all, name = "Surge::prove")]
+    pub fn prove_with_opening_point(
+        preprocessing: &SurgePreprocessing<F, Instruction, C, M>,
+        generators: &PCS::Setup,
+        ops: Vec<Instruction>,
+        transcript: &mut ProofTranscript,
+    ) -> (Self, Vec<F>, Vec<F>) {
+        let polynomials = Self::construct_polys(preprocessing, &ops);
+        Self::prove_with_polynomials(preprocessing, generators, &polynomials, transcript)
+    }
+
+    /// Same as `prove`, but also proves with `prove_output_binding` that the outputs of `ops`,
+    /// including the padding lookups, are the values committed to as `external_commitment`,
+    /// reusing the polynomials constructed for the `SurgeProof`.
+    pub fn prove_with_output_binding(
+        preprocessing: &SurgePreprocessing<F, Instruction, C, M>,
+        generators: &PCS::Setup,
+        ops: Vec<Instruction>,
+        lookup_outputs: &DensePolynomial<F>,
+        external_commitment: &PCS::Commitment,
+        transcript: &mut ProofTranscript,
+    ) -> (Self, OutputBindingProof<F, PCS>) {
         let polynomials = Self::construct_polys(preprocessing, &ops);
+        let (proof, _, _) =
+            Self::prove_with_polynomials(preprocessing, generators, &polynomials, transcript);
+        let binding = Self::prove_output_binding(
+            &polynomials,
+            lookup_outputs,
+            external_commitment,
+            transcript,
+        );
+        (proof, binding)
+    }
+
+    fn prove_with_polynomials(
+        preprocessing: &SurgePreprocessing<F, Instruction, C, M>,
+        generators: &PCS::Setup,
+        polynomials: &SurgePolys<F, PCS>,
+        transcript: &mut ProofTranscript,
+    ) -> (Self, Vec<F>, Vec<F>) {
+        transcript.append_protocol_name(Self::protocol_name());
+
         let commitment = polynomials.commit(generators);
 
-        let num_rounds = num_lookups.log_2();
-        let instruction = Instruction::default();
+        let num_rounds = polynomials.E_polys[0].get_num_vars();
 
         // TODO(sragss): Commit some of this stuff to transcript?
 
         // Primary sumcheck
         let r_primary_sumcheck = transcript.challenge_vector(b"primary_sumcheck", num_rounds);
+        let (primary_sumcheck, r_z) =
+            Self::prove_primary_sumcheck(polynomials, &r_primary_sumcheck, transcript);
+        let openings = primary_sumcheck.openings.clone();
+
+        let memory_checking = Self::prove_memory_checking(preprocessing, polynomials, transcript);
+
+        let proof = SurgeProof {
+            table_handle: preprocessing.table_handle,
+            commitment,
+            primary_sumcheck,
+            memory_checking,
+            _fingerprinter: PhantomData,
+        };
+        (proof, r_z, openings)
+    }
+
+    /// Same as `prove`, but first binds the proof's public inputs -- the table handle, `C`, `M`
+    /// and the outputs of `ops` padded to a power of two -- into `transcript`, so that the proof
+    /// only verifies (with `verify_with_public_inputs`) against exactly these public inputs.
+    /// Returns the proof along with its public inputs.
+    pub fn prove_with_public_inputs(
+        preprocessing: &SurgePreprocessing<F, Instruction, C, M>,
+        generators: &PCS::Setup,
+        ops: Vec<Instruction>,
+        transcript: &mut ProofTranscript,
+    ) -> (Self, PublicInputs<F>) {
+        // Padding lookups read entry 0 of every memory
+        let padding_entries: Vec<F> = (0..Self::num_memories())
+            .map(|i| preprocessing.subtable_entry(Self::memory_to_subtable_index(i), 0))
+            .collect();
+        let padding_output = Instruction::default().combine_lookups(&padding_entries, C, M);
+        let mut outputs: Vec<F> = ops
+            .iter()
+            .map(|op| F::from_u64(op.lookup_entry()).unwrap())
+            .collect();
+        outputs.resize(ops.len().next_power_of_two(), padding_output);
+
+        let public_inputs = preprocessing.public_inputs(outputs);
+        public_inputs.append_to_transcript(b"public_inputs", transcript);
+        let proof = Self::prove(preprocessing, generators, ops, transcript);
+        (proof, public_inputs)
+    }
+
+    /// Proves the claimed value of `sum_x eq(r_primary_sumcheck, x) * g(E_1(x), ..., E_α(x))`,
+    /// i.e. the MLE of the lookup outputs evaluated at `r_primary_sumcheck`. Also returns the
+    /// point `r_z` the sumcheck reduced to.
+    fn prove_primary_sumcheck(
+        polynomials: &SurgePolys<F, PCS>,
+        r_primary_sumcheck: &[F],
+        transcript: &mut ProofTranscript,
+    ) -> (SurgePrimarySumcheck<F, PCS>, Vec<F>) {
+        let num_rounds = r_primary_sumcheck.len();
+        let instruction = Instruction::default();
+
         let eq: DensePolynomial<F> =
             DensePolynomial::new(EqPolynomial::new(r_primary_sumcheck.to_vec()).evals());
-        let sumcheck_claim: F = Self::compute_primary_sumcheck_claim(&polynomials, &eq);
+        let sumcheck_claim: F = Self::compute_primary_sumcheck_claim(polynomials, &eq);
 
         transcript.append_scalar(b"sumcheck_claim", &sumcheck_claim);
         let mut combined_sumcheck_polys = polynomials.E_polys.clone();
@@ -602,9 +1066,9 @@ where
             transcript,
         );
 
-        let sumcheck_openings = PrimarySumcheckOpenings::open(&polynomials, &r_z); // TODO: use return value from prove_arbitrary?
+        let sumcheck_openings = PrimarySumcheckOpenings::open(polynomials, &r_z); // TODO: use return value from prove_arbitrary?
         let sumcheck_opening_proof = PrimarySumcheckOpenings::prove_openings(
-            &polynomials,
+            polynomials,
             &r_z,
             &sumcheck_openings,
             transcript,
@@ -617,47 +1081,232 @@ where
             openings: sumcheck_openings,
             opening_proof: sumcheck_opening_proof,
         };
+        (primary_sumcheck, r_z)
+    }
 
-        let memory_checking =
-            SurgeProof::prove_memory_checking(preprocessing, &polynomials, transcript);
+    /// Verifies `proof`. `transcript` is not reinitialized, so it may already contain messages
+    /// from an outer protocol, in which case it must be in the same state as the prover's
+    /// transcript was when `prove` was called: every challenge depends on that prefix.
+    pub fn verify(
+        preprocessing: &SurgePreprocessing<F, Instruction, C, M>,
+        generators: &PCS::Setup,
+        proof: SurgeProof<F, PCS, Instruction, C, M, FP>,
+        transcript: &mut ProofTranscript,
+    ) -> Result<(), ProofVerifyError> {
+        Self::verify_with_config(
+            preprocessing,
+            generators,
+            proof,
+            transcript,
+            &VerifierConfig::default(),
+        )
+    }
 
-        SurgeProof {
-            commitment,
-            primary_sumcheck,
-            memory_checking,
+    /// Re-runs the verifier's transcript interactions for `proof` and returns every challenge
+    /// it squeezes, as `(label, values)` pairs in order (see `TranscriptTrace::challenges`).
+    /// `transcript` must be in the state the verifier would start from. If `proof` fails to
+    /// verify, the challenges up to the point of failure are returned, which can be diffed
+    /// against the prover's to locate where the two transcripts diverge.
+    ///
+    /// Enables tracing on `transcript`, which is left in the verifier's final state.
+    pub fn dump_challenges(
+        preprocessing: &SurgePreprocessing<F, Instruction, C, M>,
+        generators: &PCS::Setup,
+        proof: SurgeProof<F, PCS, Instruction, C, M, FP>,
+        transcript: &mut ProofTranscript,
+    ) -> Vec<(&'static [u8], Vec<F>)> {
+        let start = transcript.trace().map_or(0, |trace| trace.events.len());
+        transcript.enable_trace();
+        let _ = Self::verify(preprocessing, generators, proof, transcript);
+
+        let trace = TranscriptTrace {
+            events: transcript.trace().unwrap().events[start..].to_vec(),
+        };
+        trace.challenges()
+    }
+
+    /// Proves `ops` and then verifies the proof using matching prover and verifier transcripts,
+    /// returning the verification result. Intended for tests and examples.
+    pub fn prove_and_verify(
+        preprocessing: &SurgePreprocessing<F, Instruction, C, M>,
+        generators: &PCS::Setup,
+        ops: Vec<Instruction>,
+    ) -> Result<(), ProofVerifyError> {
+        Self::prove_mutate_and_verify(preprocessing, generators, ops, |_| {})
+    }
+
+    /// Same as `prove_and_verify`, but applies `mutate` to the proof before verifying it.
+    fn prove_mutate_and_verify(
+        preprocessing: &SurgePreprocessing<F, Instruction, C, M>,
+        generators: &PCS::Setup,
+        ops: Vec<Instruction>,
+        mutate: impl FnOnce(&mut Self),
+    ) -> Result<(), ProofVerifyError> {
+        let mut transcript = ProofTranscript::new(b"Surge prove_and_verify");
+        let mut proof = Self::prove(preprocessing, generators, ops, &mut transcript);
+        mutate(&mut proof);
+
+        let mut transcript = ProofTranscript::new(b"Surge prove_and_verify");
+        Self::verify(preprocessing, generators, proof, &mut transcript)
+    }
+
+    /// Verifies `proof`, performing the optional checks enabled in `config`. `verify` uses the
+    /// default config.
+    pub fn verify_with_config(
+        preprocessing: &SurgePreprocessing<F, Instruction, C, M>,
+        generators: &PCS::Setup,
+        proof: SurgeProof<F, PCS, Instruction, C, M, FP>,
+        transcript: &mut ProofTranscript,
+        config: &VerifierConfig<F>,
+    ) -> Result<(), ProofVerifyError> {
+        if let Some(public_inputs) = config.public_inputs {
+            // The number of rounds comes from the proof, so it may be arbitrarily large
+            let num_lookups = u32::try_from(proof.primary_sumcheck.num_rounds)
+                .ok()
+                .and_then(|num_rounds| 1usize.checked_shl(num_rounds))
+                .ok_or(ProofVerifyError::InternalError)?;
+            if public_inputs.table_handle != preprocessing.table_handle {
+                return Err(ProofVerifyError::TableHandleMismatch);
+            }
+            if public_inputs.C != C
+                || public_inputs.M != M
+                || public_inputs.outputs.len() != num_lookups
+            {
+                return Err(ProofVerifyError::PublicInputsMismatch);
+            }
+        }
+        if proof.table_handle != preprocessing.table_handle {
+            return Err(ProofVerifyError::TableHandleMismatch);
+        }
+        if config.audit {
+            Self::check_shapes(
+                &proof.commitment,
+                &proof.primary_sumcheck,
+                &proof.memory_checking,
+            )
+            .map_err(|err| match err {
+                AssembleError::ShapeMismatch(_, expected, actual)
+                | AssembleError::NumRoundsMismatch(expected, actual) => {
+                    ProofVerifyError::InvalidInputLength(expected, actual)
+                }
+            })?;
+        }
+
+        if let Some(public_inputs) = config.public_inputs {
+            public_inputs.append_to_transcript(b"public_inputs", transcript);
         }
+        Self::verify_primary_sumcheck_with_config(generators, &proof, transcript, config)?;
+
+        Self::check_multiset_hashes(&proof.memory_checking.multiset_hashes, config.batch)?;
+        Self::verify_memory_checking(
+            preprocessing,
+            generators,
+            proof.memory_checking,
+            &proof.commitment,
+            transcript,
+        )
     }
 
-    pub fn verify(
+    /// Verifies a proof produced by `prove_with_public_inputs` against `public_inputs`, which
+    /// are bound into `transcript` at the same position as by the prover. Besides the table
+    /// handle, `C` and `M`, this checks the claimed outputs: the primary sumcheck's claim must
+    /// be the MLE of `public_inputs.outputs` at the sumcheck's random point. Returns
+    /// `TableHandleMismatch` if `public_inputs` name different tables than `preprocessing`, and
+    /// `PublicInputsMismatch` if the proof was otherwise produced for different public inputs.
+    /// Equivalent to `verify_with_config` with only `public_inputs` set.
+    pub fn verify_with_public_inputs(
         preprocessing: &SurgePreprocessing<F, Instruction, C, M>,
         generators: &PCS::Setup,
-        proof: SurgeProof<F, PCS, Instruction, C, M>,
+        proof: SurgeProof<F, PCS, Instruction, C, M, FP>,
+        public_inputs: &PublicInputs<F>,
         transcript: &mut ProofTranscript,
     ) -> Result<(), ProofVerifyError> {
+        let config = VerifierConfig {
+            public_inputs: Some(public_inputs),
+            ..VerifierConfig::default()
+        };
+        Self::verify_with_config(preprocessing, generators, proof, transcript, &config)
+    }
+
+    /// Verifies only the primary sumcheck of `proof` (and the openings it reduces to), leaving
+    /// `transcript` in the state expected by `verify_memory_checking`.
+    /// Returns the verified openings of the E_i polynomials at the sumcheck's random point.
+    pub fn verify_primary_sumcheck(
+        generators: &PCS::Setup,
+        proof: &SurgeProof<F, PCS, Instruction, C, M, FP>,
+        transcript: &mut ProofTranscript,
+    ) -> Result<Vec<F>, ProofVerifyError> {
+        Self::verify_primary_sumcheck_with_config(
+            generators,
+            proof,
+            transcript,
+            &VerifierConfig::default(),
+        )
+    }
+
+    /// If `config.public_inputs` is set, also checks that the primary sumcheck's claim is the
+    /// MLE of the public outputs evaluated at `r_primary_sumcheck`.
+    fn verify_primary_sumcheck_with_config(
+        generators: &PCS::Setup,
+        proof: &SurgeProof<F, PCS, Instruction, C, M, FP>,
+        transcript: &mut ProofTranscript,
+        config: &VerifierConfig<F>,
+    ) -> Result<Vec<F>, ProofVerifyError> {
         transcript.append_protocol_name(Self::protocol_name());
         let instruction = Instruction::default();
 
         let r_primary_sumcheck =
             transcript.challenge_vector(b"primary_sumcheck", proof.primary_sumcheck.num_rounds);
+        if let Some(public_inputs) = config.public_inputs {
+            let outputs = &public_inputs.outputs;
+            let outputs_eval = match config.precomputed_eq {
+                Some(eq) => {
+                    if eq.point != r_primary_sumcheck {
+                        return Err(ProofVerifyError::InternalError);
+                    }
+                    if eq.L.len() * eq.R.len() != outputs.len() {
+                        return Err(ProofVerifyError::InvalidInputLength(
+                            outputs.len(),
+                            eq.L.len() * eq.R.len(),
+                        ));
+                    }
+                    // eq(r, x) = L[x_hi] * R[x_lo], so the MLE is L^T * outputs * R with
+                    // outputs laid out as a matrix with rows of length |R|
+                    outputs
+                        .chunks(eq.R.len())
+                        .zip(eq.L.iter())
+                        .map(|(row, L_i)| compute_dotproduct(row, &eq.R) * L_i)
+                        .sum()
+                }
+                None => DensePolynomial::new(outputs.clone()).evaluate(&r_primary_sumcheck),
+            };
+            if outputs_eval != proof.primary_sumcheck.claimed_evaluation {
+                return Err(ProofVerifyError::PublicInputsMismatch);
+            }
+        }
 
         transcript.append_scalar(
             b"sumcheck_claim",
             &proof.primary_sumcheck.claimed_evaluation,
         );
         let primary_sumcheck_poly_degree = instruction.g_poly_degree(C) + 1;
-        let (claim_last, r_z) = proof.primary_sumcheck.sumcheck_proof.verify(
-            proof.primary_sumcheck.claimed_evaluation,
-            proof.primary_sumcheck.num_rounds,
-            primary_sumcheck_poly_degree,
-            transcript,
-        )?;
+        let (claim_last, r_z) = proof
+            .primary_sumcheck
+            .sumcheck_proof
+            .verify_with_degree_check(
+                proof.primary_sumcheck.claimed_evaluation,
+                proof.primary_sumcheck.num_rounds,
+                primary_sumcheck_poly_degree,
+                config.strict_degree_bound,
+                transcript,
+            )?;
 
         let eq_eval = EqPolynomial::new(r_primary_sumcheck.to_vec()).evaluate(&r_z);
-        assert_eq!(
-            eq_eval * instruction.combine_lookups(&proof.primary_sumcheck.openings, C, M),
-            claim_last,
-            "Primary sumcheck check failed."
-        );
+        if eq_eval * instruction.combine_lookups(&proof.primary_sumcheck.openings, C, M)
+            != claim_last
+        {
+            return Err(ProofVerifyError::InternalError);
+        }
 
         proof.primary_sumcheck.openings.verify_openings(
             generators,
@@ -667,23 +1316,279 @@ where
             transcript,
         )?;
 
-        Self::verify_memory_checking(
-            preprocessing,
-            generators,
-            proof.memory_checking,
-            &proof.commitment,
-            transcript,
-        )
+        Ok(proof.primary_sumcheck.openings.clone())
     }
 
-    #[tracing::instrument(skip_all, name = "Surge::construct_polys")]
-    fn construct_polys(
-        preprocessing: &SurgePreprocessing<F, Instruction, C, M>,
-        ops: &Vec<Instruction>,
-    ) -> SurgePolys<F, PCS> {
-        let num_lookups = ops.len().next_power_of_two();
-        let mut dim_usize: Vec<Vec<usize>> = vec![vec![0; num_lookups]; C];
-
+    /// Checks that `init_i * write_i == final_i * read_i` for every memory i, i.e. that the
+    /// read/write and init/final multisets are equal. With `batch`, a random linear combination
+    /// of the equalities is checked first, and the memories are only checked one at a time if it
+    /// fails. Returns `MultisetHashMismatch` naming the first failing memory.
+    fn check_multiset_hashes(
+        multiset_hashes: &MultisetHashes<F>,
+        batch: bool,
+    ) -> Result<(), ProofVerifyError> {
+        let differences = || {
+            multiset_hashes
+                .init_hashes
+                .iter()
+                .zip(multiset_hashes.write_hashes.iter())
+                .zip(multiset_hashes.final_hashes.iter())
+                .zip(multiset_hashes.read_hashes.iter())
+                .map(|(((init, write), fin), read)| *init * write - *fin * read)
+        };
+        if batch {
+            // Derived from the hashes alone, so the verifier's transcript is left untouched
+            let mut batch_transcript = ProofTranscript::new(b"Surge multiset equality");
+            multiset_hashes.append_to_transcript(&mut batch_transcript);
+            let rho: F = batch_transcript.challenge_scalar(b"multiset_equality_rho");
+            let combined = differences()
+                .rev()
+                .fold(F::zero(), |acc, diff| acc * rho + diff);
+            if combined.is_zero() {
+                return Ok(());
+            }
+        }
+        match differences().position(|diff| !diff.is_zero()) {
+            Some(memory_index) => Err(ProofVerifyError::MultisetHashMismatch(memory_index)),
+            None => Ok(()),
+        }
+    }
+
+    /// Proves that `lookup_outputs` -- the outputs of the lookups whose `polynomials` were
+    /// proven, including the padding lookups -- are the values committed to as
+    /// `external_commitment` by an outer protocol. Should be called with the transcript used to
+    /// prove the lookups, right after it (see `prove_with_output_binding`).
+    ///
+    /// Both parties derive a random point `r` after absorbing `external_commitment`. The prover
+    /// opens `external_commitment` at `r` and then proves, with another instance of the primary
+    /// sumcheck over the `E_i` polynomials already committed to in the `SurgeProof`, that the
+    /// lookup outputs evaluate to the same value at `r`. By Schwartz-Zippel this implies the two
+    /// output vectors are equal.
+    pub fn prove_output_binding(
+        polynomials: &SurgePolys<F, PCS>,
+        lookup_outputs: &DensePolynomial<F>,
+        external_commitment: &PCS::Commitment,
+        transcript: &mut ProofTranscript,
+    ) -> OutputBindingProof<F, PCS> {
+        let num_rounds = polynomials.E_polys[0].get_num_vars();
+        assert_eq!(lookup_outputs.get_num_vars(), num_rounds);
+
+        external_commitment.append_to_transcript(b"output_commitment", transcript);
+        let r_binding = transcript.challenge_vector(b"output_binding", num_rounds);
+        let output_eval = lookup_outputs.evaluate(&r_binding);
+        let output_opening_proof = PCS::prove(lookup_outputs, &r_binding, transcript);
+
+        let (primary_sumcheck, _) =
+            Self::prove_primary_sumcheck(polynomials, &r_binding, transcript);
+
+        OutputBindingProof {
+            output_eval,
+            output_opening_proof,
+            primary_sumcheck,
+        }
+    }
+
+    /// Verifies an `OutputBindingProof` against `commitment` and `primary_sumcheck`, parts of a
+    /// `SurgeProof` that must already have been verified with the same transcript. Returns
+    /// `OutputBindingMismatch` if the lookup outputs differ from the externally committed ones.
+    pub fn verify_output_binding(
+        generators: &PCS::Setup,
+        commitment: &SurgeCommitment<PCS>,
+        primary_sumcheck: &SurgePrimarySumcheck<F, PCS>,
+        binding: &OutputBindingProof<F, PCS>,
+        external_commitment: &PCS::Commitment,
+        transcript: &mut ProofTranscript,
+    ) -> Result<(), ProofVerifyError> {
+        // The binding must be over as many lookups as the verified proof
+        let num_rounds = primary_sumcheck.num_rounds;
+        if binding.primary_sumcheck.num_rounds != num_rounds {
+            return Err(ProofVerifyError::InvalidInputLength(
+                num_rounds,
+                binding.primary_sumcheck.num_rounds,
+            ));
+        }
+        let instruction = Instruction::default();
+
+        external_commitment.append_to_transcript(b"output_commitment", transcript);
+        let r_binding = transcript.challenge_vector(b"output_binding", num_rounds);
+        PCS::verify(
+            &binding.output_opening_proof,
+            generators,
+            transcript,
+            &r_binding,
+            &binding.output_eval,
+            external_commitment,
+        )?;
+
+        if binding.primary_sumcheck.claimed_evaluation != binding.output_eval {
+            return Err(ProofVerifyError::OutputBindingMismatch);
+        }
+        transcript.append_scalar(b"sumcheck_claim", &binding.output_eval);
+        let (claim_last, r_z) = binding.primary_sumcheck.sumcheck_proof.verify(
+            binding.output_eval,
+            num_rounds,
+            instruction.g_poly_degree(C) + 1,
+            transcript,
+        )?;
+
+        let eq_eval = EqPolynomial::new(r_binding).evaluate(&r_z);
+        if eq_eval * instruction.combine_lookups(&binding.primary_sumcheck.openings, C, M)
+            != claim_last
+        {
+            return Err(ProofVerifyError::InternalError);
+        }
+
+        binding.primary_sumcheck.openings.verify_openings(
+            generators,
+            &binding.primary_sumcheck.opening_proof,
+            commitment,
+            &r_z,
+            transcript,
+        )
+    }
+
+    /// Splits this proof into its independently provable components.
+    pub fn into_components(self) -> SurgeProofComponents<F, PCS, Instruction, C, M> {
+        SurgeProofComponents {
+            table_handle: self.table_handle,
+            commitment: self.commitment,
+            primary_sumcheck: self.primary_sumcheck,
+            memory_checking: self.memory_checking,
+        }
+    }
+
+    /// Bundles independently proven components into a `SurgeProof`, after checking that they
+    /// are mutually consistent: every commitment, opening and multiset hash vector has the size
+    /// implied by `C` and the instruction's subtables, and the primary sumcheck and the
+    /// read/write grand product are over the same number of lookups.
+    ///
+    /// These are structural checks only; the assembled proof must still be verified.
+    pub fn assemble(
+        components: SurgeProofComponents<F, PCS, Instruction, C, M>,
+    ) -> Result<Self, AssembleError> {
+        Self::check_shapes(
+            &components.commitment,
+            &components.primary_sumcheck,
+            &components.memory_checking,
+        )?;
+
+        Ok(SurgeProof {
+            table_handle: components.table_handle,
+            commitment: components.commitment,
+            primary_sumcheck: components.primary_sumcheck,
+            memory_checking: components.memory_checking,
+            _fingerprinter: PhantomData,
+        })
+    }
+
+    /// Checks that every commitment, opening and multiset hash vector has the size implied by
+    /// `C` and the instruction's subtables, and that the primary sumcheck and the read/write
+    /// grand product are over the same number of lookups.
+    #[allow(clippy::type_complexity)]
+    fn check_shapes(
+        commitment: &SurgeCommitment<PCS>,
+        primary_sumcheck: &SurgePrimarySumcheck<F, PCS>,
+        memory_checking: &MemoryCheckingProof<
+            F,
+            PCS,
+            SurgePolys<F, PCS>,
+            SurgeReadWriteOpenings<F>,
+            SurgeFinalOpenings<F, Instruction, C, M>,
+        >,
+    ) -> Result<(), AssembleError> {
+        let num_memories = Self::num_memories();
+        let read_write_openings = &memory_checking.read_write_openings;
+        let multiset_hashes = &memory_checking.multiset_hashes;
+        let shapes: [(&'static str, usize, usize); 13] = [
+            (
+                "dim/read_cts commitment",
+                2 * C,
+                commitment.dim_read_commitment.len(),
+            ),
+            ("final_cts commitment", C, commitment.final_commitment.len()),
+            ("E commitment", num_memories, commitment.E_commitment.len()),
+            (
+                "primary sumcheck openings",
+                num_memories,
+                primary_sumcheck.openings.len(),
+            ),
+            ("dim openings", C, read_write_openings.dim_openings.len()),
+            (
+                "read_cts openings",
+                C,
+                read_write_openings.read_openings.len(),
+            ),
+            (
+                "E openings",
+                num_memories,
+                read_write_openings.E_poly_openings.len(),
+            ),
+            (
+                "final_cts openings",
+                C,
+                memory_checking.init_final_openings.final_openings.len(),
+            ),
+            (
+                "read hashes",
+                num_memories,
+                multiset_hashes.read_hashes.len(),
+            ),
+            (
+                "write hashes",
+                num_memories,
+                multiset_hashes.write_hashes.len(),
+            ),
+            (
+                "init hashes",
+                num_memories,
+                multiset_hashes.init_hashes.len(),
+            ),
+            (
+                "final hashes",
+                num_memories,
+                multiset_hashes.final_hashes.len(),
+            ),
+            (
+                "init/final grand product layers",
+                M.log_2(),
+                memory_checking.init_final_grand_product.num_layers(),
+            ),
+        ];
+        for (name, expected, actual) in shapes {
+            if expected != actual {
+                return Err(AssembleError::ShapeMismatch(name, expected, actual));
+            }
+        }
+
+        let num_rounds = primary_sumcheck.num_rounds;
+        let num_layers = memory_checking.read_write_grand_product.num_layers();
+        if num_rounds != num_layers {
+            return Err(AssembleError::NumRoundsMismatch(num_rounds, num_layers));
+        }
+        Ok(())
+    }
+
+    /// Returns the intermediate claims made by this proof.
+    pub fn audit(&self) -> SurgeAuditReport<F> {
+        let multiset_hashes = &self.memory_checking.multiset_hashes;
+        SurgeAuditReport {
+            hypercube_sum: self.primary_sumcheck.claimed_evaluation,
+            primary_sumcheck_openings: self.primary_sumcheck.openings.clone(),
+            read_hashes: multiset_hashes.read_hashes.clone(),
+            write_hashes: multiset_hashes.write_hashes.clone(),
+            init_hashes: multiset_hashes.init_hashes.clone(),
+            final_hashes: multiset_hashes.final_hashes.clone(),
+        }
+    }
+
+    #[tracing::instrument(skip_all, name = "Surge::construct_polys")]
+    fn construct_polys(
+        preprocessing: &SurgePreprocessing<F, Instruction, C, M>,
+        ops: &Vec<Instruction>,
+    ) -> SurgePolys<F, PCS> {
+        let num_lookups = ops.len().next_power_of_two();
+        let mut dim_usize: Vec<Vec<usize>> = vec![vec![0; num_lookups]; C];
+
         let mut read_cts = vec![vec![0usize; num_lookups]; C];
         let mut final_cts = vec![vec![0usize; M]; C];
         let log_M = ark_std::log2(M) as usize;
@@ -732,24 +1637,23 @@ where
             .map(|fin| DensePolynomial::from_usize(fin))
             .collect();
 
-        // Construct E
-        let mut E_i_evals = Vec::with_capacity(Self::num_memories());
-        for E_index in 0..Self::num_memories() {
-            let mut E_evals = Vec::with_capacity(num_lookups);
-            for op_index in 0..num_lookups {
-                let dimension_index = Self::memory_to_dimension_index(E_index);
-                let subtable_index = Self::memory_to_subtable_index(E_index);
-
-                let eval_index = dim_usize[dimension_index][op_index];
-                let eval = preprocessing.materialized_subtables[subtable_index][eval_index];
-                E_evals.push(eval);
-            }
-            E_i_evals.push(E_evals);
+        // Construct E, materializing (if not preprocessed) one subtable at a time
+        let mut E_poly: Vec<DensePolynomial<F>> = Vec::with_capacity(Self::num_memories());
+        for subtable_index in 0..preprocessing.subtables.len() {
+            let subtable_entries = preprocessing.subtable_entries(subtable_index);
+            E_poly.extend(
+                (0..Self::num_memories())
+                    .filter(|&E_index| Self::memory_to_subtable_index(E_index) == subtable_index)
+                    .map(|E_index| {
+                        let dimension_index = Self::memory_to_dimension_index(E_index);
+                        let E_evals: Vec<F> = dim_usize[dimension_index]
+                            .iter()
+                            .map(|&eval_index| subtable_entries[eval_index])
+                            .collect();
+                        DensePolynomial::new(E_evals)
+                    }),
+            );
         }
-        let E_poly: Vec<DensePolynomial<F>> = E_i_evals
-            .iter()
-            .map(|E| DensePolynomial::new(E.to_vec()))
-            .collect();
 
         SurgePolys {
             _marker: PhantomData,
@@ -786,12 +1690,39 @@ where
 mod tests {
     use super::SurgePreprocessing;
     use crate::{
-        jolt::instruction::xor::XORInstruction,
-        lasso::surge::SurgeProof,
-        poly::{commitment::hyrax::HyraxScheme, commitment::pedersen::PedersenGenerators},
-        utils::transcript::ProofTranscript,
+        jolt::instruction::{
+            and::ANDInstruction, sltu::SLTUInstruction, sw::SWInstruction, xor::XORInstruction,
+            JoltInstruction,
+        },
+        lasso::{
+            memory_checking::{
+                Fingerprinter, MemoryCheckingProver, MemoryCheckingVerifier, NoPreprocessing,
+                ReedSolomonFingerprinter,
+            },
+            surge::{
+                OutputBindingProof, PublicInputs, SurgeFinalOpenings, SurgeProof,
+                SurgeReadWriteOpenings, VerifierConfig,
+            },
+        },
+        poly::{
+            commitment::commitment_scheme::CommitmentScheme,
+            commitment::hyrax::{HyraxCommitment, HyraxScheme},
+            commitment::pedersen::PedersenGenerators,
+            dense_mlpoly::DensePolynomial,
+            eq_poly::EqPolynomial,
+            structured_poly::StructuredOpeningProof,
+        },
+        utils::{
+            counting_field::{count_mults, CountingField, MockCommitmentScheme},
+            errors::{AssembleError, MemoryCheckingStage, ProofVerifyError},
+            math::Math,
+            transcript::{AppendToTranscript, ProofTranscript},
+        },
     };
     use ark_bn254::{Fr, G1Projective};
+    use ark_ff::Field;
+    use ark_std::{test_rng, UniformRand};
+    use rand::{rngs::StdRng, RngCore, SeedableRng};
 
     #[test]
     fn e2e() {
@@ -822,6 +1753,464 @@ mod tests {
             .expect("should work");
     }
 
+    #[test]
+    fn dump_challenges_matches_verifier() {
+        const C: usize = 2;
+        const M: usize = 1 << 8;
+        type Surge = SurgeProof<Fr, HyraxScheme<G1Projective>, XORInstruction, C, M>;
+
+        let preprocessing = SurgePreprocessing::preprocess();
+        let generators = PedersenGenerators::new(Surge::num_generators(16), b"LassoV1");
+        let ops: Vec<XORInstruction> = (0..8).map(|i| XORInstruction(i, 15 - i)).collect();
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        transcript.enable_trace();
+        let proof = Surge::prove(&preprocessing, &generators, ops, &mut transcript);
+        let prover_challenges: Vec<(&'static [u8], Vec<Fr>)> =
+            transcript.trace().unwrap().challenges();
+        let num_rounds = proof.primary_sumcheck.num_rounds;
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let challenges =
+            Surge::dump_challenges(&preprocessing, &generators, proof, &mut transcript);
+
+        // A valid proof is verified with exactly the prover's challenges
+        assert_eq!(challenges, prover_challenges);
+
+        // The first challenge is the primary sumcheck's eq point
+        let mut expected_transcript = ProofTranscript::new(b"test_transcript");
+        expected_transcript.append_protocol_name(b"Surge");
+        let r_primary_sumcheck: Vec<Fr> =
+            expected_transcript.challenge_vector(b"primary_sumcheck", num_rounds);
+        assert_eq!(
+            challenges[0],
+            (&b"primary_sumcheck"[..], r_primary_sumcheck)
+        );
+        assert!(challenges
+            .iter()
+            .any(|(label, _)| *label == b"Memory checking gamma"));
+    }
+
+    #[test]
+    fn prove_with_opening_point() {
+        const C: usize = 2;
+        const M: usize = 1 << 8;
+        type Surge = SurgeProof<Fr, HyraxScheme<G1Projective>, XORInstruction, C, M>;
+
+        let preprocessing = SurgePreprocessing::preprocess();
+        let generators = PedersenGenerators::new(Surge::num_generators(16), b"LassoV1");
+        let ops: Vec<XORInstruction> = (0..8).map(|i| XORInstruction(i, 15 - i)).collect();
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let (proof, opening_point, openings) =
+            Surge::prove_with_opening_point(&preprocessing, &generators, ops, &mut transcript);
+        let num_rounds = proof.primary_sumcheck.num_rounds;
+        assert_eq!(opening_point.len(), num_rounds);
+
+        // The primary sumcheck's round challenges are the ones squeezed under this label
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        transcript.enable_trace();
+        let verified_openings =
+            Surge::verify_primary_sumcheck(&generators, &proof, &mut transcript).unwrap();
+        let verifier_opening_point: Vec<Fr> = transcript
+            .trace()
+            .unwrap()
+            .challenges::<Fr>()
+            .into_iter()
+            .filter(|(label, _)| *label == b"challenge_nextround")
+            .flat_map(|(_, values)| values)
+            .take(num_rounds)
+            .collect();
+        assert_eq!(opening_point, verifier_opening_point);
+        assert_eq!(openings, verified_openings);
+    }
+
+    #[test]
+    fn assemble_from_components() {
+        const C: usize = 2;
+        const M: usize = 1 << 8;
+        type Surge = SurgeProof<Fr, HyraxScheme<G1Projective>, XORInstruction, C, M>;
+
+        let preprocessing = SurgePreprocessing::preprocess();
+        let generators = PedersenGenerators::new(Surge::num_generators(16), b"LassoV1");
+        let ops = |num_lookups: u64| -> Vec<XORInstruction> {
+            (0..num_lookups)
+                .map(|i| XORInstruction(i % 16, (3 * i) % 16))
+                .collect()
+        };
+        let prove = |ops: Vec<XORInstruction>| -> Surge {
+            let mut transcript = ProofTranscript::new(b"test_transcript");
+            Surge::prove(&preprocessing, &generators, ops, &mut transcript)
+        };
+        let verify = |proof: Surge| -> Result<(), ProofVerifyError> {
+            let mut transcript = ProofTranscript::new(b"test_transcript");
+            Surge::verify(&preprocessing, &generators, proof, &mut transcript)
+        };
+
+        assert!(verify(prove(ops(8))).is_ok());
+        let assembled = Surge::assemble(prove(ops(8)).into_components()).unwrap();
+        assert!(verify(assembled).is_ok());
+
+        // Components proven over different numbers of lookups are inconsistent
+        let mut components = prove(ops(8)).into_components();
+        components.memory_checking = prove(ops(16)).into_components().memory_checking;
+        assert!(matches!(
+            Surge::assemble(components),
+            Err(AssembleError::NumRoundsMismatch(3, 4))
+        ));
+
+        let mut components = prove(ops(8)).into_components();
+        components.commitment.E_commitment.pop();
+        assert!(matches!(
+            Surge::assemble(components),
+            Err(AssembleError::ShapeMismatch("E commitment", 2, 1))
+        ));
+    }
+
+    #[test]
+    fn transcript_trace_stable() {
+        const C: usize = 2;
+        const M: usize = 1 << 8;
+        type Surge = SurgeProof<Fr, HyraxScheme<G1Projective>, XORInstruction, C, M>;
+
+        let preprocessing = SurgePreprocessing::preprocess();
+        let generators = PedersenGenerators::new(Surge::num_generators(16), b"LassoV1");
+        let trace = || {
+            let ops = vec![
+                XORInstruction(12, 12),
+                XORInstruction(12, 82),
+                XORInstruction(12, 12),
+                XORInstruction(25, 12),
+            ];
+            let mut transcript = ProofTranscript::new(b"test_transcript");
+            transcript.enable_trace();
+            let _ = Surge::prove(&preprocessing, &generators, ops, &mut transcript);
+            transcript.trace().unwrap().serialize()
+        };
+
+        let first = trace();
+        assert!(!first.is_empty());
+        assert_eq!(first, trace());
+    }
+
+    #[test]
+    fn output_binding() {
+        const C: usize = 2;
+        const M: usize = 1 << 8;
+        type Surge = SurgeProof<Fr, HyraxScheme<G1Projective>, XORInstruction, C, M>;
+
+        let ops = vec![
+            XORInstruction(12, 12),
+            XORInstruction(12, 82),
+            XORInstruction(12, 12),
+            XORInstruction(25, 12),
+        ];
+        let outputs: Vec<u64> = ops.iter().map(|op| op.lookup_entry()).collect();
+        let mut wrong_outputs = outputs.clone();
+        wrong_outputs[1] += 1;
+
+        let preprocessing = SurgePreprocessing::preprocess();
+        let generators = PedersenGenerators::new(Surge::num_generators(16), b"LassoV1");
+
+        let prove = |ops: Vec<XORInstruction>, external_outputs: &DensePolynomial<Fr>| {
+            let external_commitment = HyraxScheme::commit(external_outputs, &generators);
+            let mut transcript = ProofTranscript::new(b"test_transcript");
+            let (proof, binding) = Surge::prove_with_output_binding(
+                &preprocessing,
+                &generators,
+                ops,
+                external_outputs,
+                &external_commitment,
+                &mut transcript,
+            );
+            (proof, binding, external_commitment)
+        };
+        let verify = |proof: Surge,
+                      binding: &OutputBindingProof<Fr, HyraxScheme<G1Projective>>,
+                      external_commitment: &HyraxCommitment<G1Projective>| {
+            let mut transcript = ProofTranscript::new(b"test_transcript");
+            Surge::verify_primary_sumcheck(&generators, &proof, &mut transcript)
+                .expect("should work");
+            Surge::verify_memory_checking(
+                &preprocessing,
+                &generators,
+                proof.memory_checking,
+                &proof.commitment,
+                &mut transcript,
+            )
+            .expect("should work");
+            Surge::verify_output_binding(
+                &generators,
+                &proof.commitment,
+                &proof.primary_sumcheck,
+                binding,
+                external_commitment,
+                &mut transcript,
+            )
+        };
+
+        let (proof, binding, external_commitment) =
+            prove(ops.clone(), &DensePolynomial::from_u64(&outputs));
+        assert!(verify(proof, &binding, &external_commitment).is_ok());
+
+        let (proof, binding, external_commitment) =
+            prove(ops.clone(), &DensePolynomial::from_u64(&wrong_outputs));
+        assert!(matches!(
+            verify(proof, &binding, &external_commitment),
+            Err(ProofVerifyError::OutputBindingMismatch)
+        ));
+
+        // A binding over a different number of lookups than the proof is rejected
+        let (proof, _, external_commitment) =
+            prove(ops.clone(), &DensePolynomial::from_u64(&outputs));
+        let more_ops = [ops.clone(), ops].concat();
+        let more_outputs: Vec<u64> = more_ops.iter().map(|op| op.lookup_entry()).collect();
+        let (_, more_binding, _) = prove(more_ops, &DensePolynomial::from_u64(&more_outputs));
+        assert!(matches!(
+            verify(proof, &more_binding, &external_commitment),
+            Err(ProofVerifyError::InvalidInputLength(2, 3))
+        ));
+    }
+
+    #[cfg(feature = "profiling")]
+    #[test]
+    fn verifier_cost_estimate_msm_size() {
+        use crate::msm::size_histogram::record_msm_sizes;
+
+        const C: usize = 8;
+        const M: usize = 1 << 8;
+        type Surge = SurgeProof<Fr, HyraxScheme<G1Projective>, XORInstruction, C, M>;
+
+        let ops = vec![
+            XORInstruction(12, 12),
+            XORInstruction(12, 82),
+            XORInstruction(12, 12),
+            XORInstruction(25, 12),
+            XORInstruction(25, 82),
+        ];
+        let num_lookups = ops.len();
+        let preprocessing = SurgePreprocessing::preprocess();
+        let generators = PedersenGenerators::new(Surge::num_generators(16), b"LassoV1");
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let proof = Surge::prove(&preprocessing, &generators, ops, &mut transcript);
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let (result, histogram) =
+            record_msm_sizes(|| Surge::verify(&preprocessing, &generators, proof, &mut transcript));
+        result.expect("should work");
+        assert_eq!(
+            Surge::verifier_cost_estimate(num_lookups).msm_size,
+            histogram.total_elements()
+        );
+    }
+
+    /// Upper bound on the field multiplications performed by `verify` for 64 `XORInstruction`
+    /// lookups with `C = 4` and `M = 1 << 16`, excluding the polynomial openings (whose cost is
+    /// covered by `verifier_cost_estimate_msm_size`). `verifier_cost_estimate` puts the
+    /// remaining steps at ~1900 multiplications, most of them in the 16 layers of the
+    /// init/final grand product; the bound leaves room for the constant factors the estimate
+    /// omits, while verifier work linear in `M` (65536) exceeds it.
+    const VERIFIER_FIELD_MULT_BOUND: usize = 10_000;
+
+    #[test]
+    fn verifier_field_mult_bound() {
+        const C: usize = 4;
+        const M: usize = 1 << 16;
+        type Surge =
+            SurgeProof<CountingField, MockCommitmentScheme<CountingField>, XORInstruction, C, M>;
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let ops: Vec<XORInstruction> = (0..64)
+            .map(|_| XORInstruction(rng.next_u32() as u64, rng.next_u32() as u64))
+            .collect();
+        let preprocessing = SurgePreprocessing::preprocess();
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let proof = Surge::prove(&preprocessing, &(), ops, &mut transcript);
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let (result, field_mults) =
+            count_mults(|| Surge::verify(&preprocessing, &(), proof, &mut transcript));
+        assert!(result.is_ok());
+        assert!(
+            field_mults <= VERIFIER_FIELD_MULT_BOUND,
+            "verifier performed {} field multiplications, more than the documented bound of {}",
+            field_mults,
+            VERIFIER_FIELD_MULT_BOUND
+        );
+    }
+
+    #[test]
+    fn table_handle() {
+        const C: usize = 2;
+        const M: usize = 1 << 8;
+        type Surge = SurgeProof<Fr, HyraxScheme<G1Projective>, XORInstruction, C, M>;
+
+        let preprocessing = SurgePreprocessing::preprocess();
+        assert_eq!(
+            preprocessing.table_handle(),
+            SurgePreprocessing::<Fr, XORInstruction, C, M>::preprocess_mle_only().table_handle()
+        );
+        let generators = PedersenGenerators::new(Surge::num_generators(16), b"LassoV1");
+
+        let prove = |ops: Vec<XORInstruction>| {
+            let mut transcript = ProofTranscript::new(b"test_transcript");
+            Surge::prove(&preprocessing, &generators, ops, &mut transcript)
+        };
+        let verify = |proof: Surge| {
+            let mut transcript = ProofTranscript::new(b"test_transcript");
+            Surge::verify(&preprocessing, &generators, proof, &mut transcript)
+        };
+
+        let proof_a = prove(vec![XORInstruction(12, 12), XORInstruction(12, 82)]);
+        let proof_b = prove(vec![XORInstruction(25, 12), XORInstruction(0, 1)]);
+        assert_eq!(proof_a.table_handle(), proof_b.table_handle());
+        assert!(verify(proof_a).is_ok());
+
+        // A handle for different tables is rejected
+        let other_handle =
+            *SurgePreprocessing::<Fr, ANDInstruction, C, M>::preprocess_mle_only().table_handle();
+        assert_ne!(proof_b.table_handle(), &other_handle);
+        let mut proof_b = proof_b;
+        proof_b.table_handle = other_handle;
+        assert!(matches!(
+            verify(proof_b),
+            Err(ProofVerifyError::TableHandleMismatch)
+        ));
+    }
+
+    #[test]
+    fn prove_and_verify() {
+        const C: usize = 2;
+        const M: usize = 1 << 8;
+        type Surge = SurgeProof<Fr, HyraxScheme<G1Projective>, XORInstruction, C, M>;
+
+        let ops = vec![
+            XORInstruction(12, 12),
+            XORInstruction(12, 82),
+            XORInstruction(25, 12),
+        ];
+        let preprocessing = SurgePreprocessing::preprocess();
+        let generators = PedersenGenerators::new(Surge::num_generators(16), b"LassoV1");
+
+        assert!(Surge::prove_and_verify(&preprocessing, &generators, ops.clone()).is_ok());
+
+        let other_handle =
+            *SurgePreprocessing::<Fr, ANDInstruction, C, M>::preprocess_mle_only().table_handle();
+        assert!(matches!(
+            Surge::prove_mutate_and_verify(&preprocessing, &generators, ops, |proof| {
+                proof.table_handle = other_handle
+            }),
+            Err(ProofVerifyError::TableHandleMismatch)
+        ));
+    }
+
+    #[test]
+    fn verify_with_public_inputs() {
+        const C: usize = 2;
+        const M: usize = 1 << 8;
+        type Surge = SurgeProof<Fr, HyraxScheme<G1Projective>, XORInstruction, C, M>;
+
+        let ops = vec![
+            XORInstruction(12, 12),
+            XORInstruction(12, 82),
+            XORInstruction(25, 12),
+        ];
+        let preprocessing = SurgePreprocessing::preprocess();
+        let generators = PedersenGenerators::new(Surge::num_generators(16), b"LassoV1");
+        let prove = || {
+            let mut transcript = ProofTranscript::new(b"test_transcript");
+            Surge::prove_with_public_inputs(
+                &preprocessing,
+                &generators,
+                ops.clone(),
+                &mut transcript,
+            )
+        };
+        let verify = |proof: Surge, public_inputs: &PublicInputs<Fr>| {
+            let mut transcript = ProofTranscript::new(b"test_transcript");
+            Surge::verify_with_public_inputs(
+                &preprocessing,
+                &generators,
+                proof,
+                public_inputs,
+                &mut transcript,
+            )
+        };
+
+        let (proof, public_inputs) = prove();
+        // The padding lookup XORs entry 0 with itself
+        let expected_outputs: Vec<Fr> = [0u64, 12 ^ 82, 25 ^ 12, 0]
+            .into_iter()
+            .map(Fr::from)
+            .collect();
+        assert_eq!(public_inputs, preprocessing.public_inputs(expected_outputs));
+        assert!(verify(proof, &public_inputs).is_ok());
+
+        // Different claimed outputs
+        let (proof, mut wrong_outputs) = prove();
+        wrong_outputs.outputs[1] += Fr::from(1u64);
+        assert!(matches!(
+            verify(proof, &wrong_outputs),
+            Err(ProofVerifyError::PublicInputsMismatch)
+        ));
+
+        // Different tables
+        let (proof, mut wrong_tables) = prove();
+        wrong_tables.table_handle =
+            *SurgePreprocessing::<Fr, ANDInstruction, C, M>::preprocess_mle_only().table_handle();
+        assert!(matches!(
+            verify(proof, &wrong_tables),
+            Err(ProofVerifyError::TableHandleMismatch)
+        ));
+
+        // A proof that did not bind its public inputs cannot be replayed against them
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let unbound_proof = Surge::prove(&preprocessing, &generators, ops.clone(), &mut transcript);
+        assert!(matches!(
+            verify(unbound_proof, &public_inputs),
+            Err(ProofVerifyError::PublicInputsMismatch)
+        ));
+    }
+
+    fn prove_and_verify_with_prefixes(
+        prover_prefix: u64,
+        verifier_prefix: u64,
+    ) -> Result<(), ProofVerifyError> {
+        const C: usize = 2;
+        const M: usize = 1 << 8;
+        type Surge = SurgeProof<Fr, HyraxScheme<G1Projective>, XORInstruction, C, M>;
+
+        let ops = vec![
+            XORInstruction(12, 12),
+            XORInstruction(12, 82),
+            XORInstruction(25, 12),
+            XORInstruction(0, 1),
+        ];
+        let preprocessing = SurgePreprocessing::preprocess();
+        let generators = PedersenGenerators::new(Surge::num_generators(16), b"LassoV1");
+        let transcript_with_prefix = |prefix: u64| {
+            let mut transcript = ProofTranscript::new(b"outer_protocol");
+            transcript.append_u64(b"outer_message", prefix);
+            transcript
+        };
+
+        let mut transcript = transcript_with_prefix(prover_prefix);
+        let proof = Surge::prove(&preprocessing, &generators, ops, &mut transcript);
+        let mut transcript = transcript_with_prefix(verifier_prefix);
+        Surge::verify(&preprocessing, &generators, proof, &mut transcript)
+    }
+
+    #[test]
+    fn verify_with_transcript_prefix() {
+        prove_and_verify_with_prefixes(1, 1).expect("should work");
+    }
+
+    #[test]
+    fn verify_with_different_transcript_prefix() {
+        // A different prefix changes the verifier's challenges
+        assert!(prove_and_verify_with_prefixes(1, 2).is_err());
+    }
+
     #[test]
     fn e2e_non_pow_2() {
         let ops = vec![
@@ -851,4 +2240,499 @@ mod tests {
         SurgeProof::verify(&preprocessing, &generators, proof, &mut transcript)
             .expect("should work");
     }
+
+    #[test]
+    fn e2e_mle_only() {
+        let ops = vec![
+            SWInstruction(0xdead_beef),
+            SWInstruction(0x1234_5678),
+            SWInstruction(0),
+            SWInstruction(0xdead_beef),
+        ];
+        const C: usize = 4;
+        const M: usize = 1 << 16;
+        type Surge = SurgeProof<Fr, HyraxScheme<G1Projective>, SWInstruction, C, M>;
+
+        let generators = PedersenGenerators::new(Surge::num_generators(16), b"LassoV1");
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let mle_only_preprocessing = SurgePreprocessing::preprocess_mle_only();
+        assert!(mle_only_preprocessing.materialized_subtables.is_none());
+        let proof = Surge::prove(
+            &mle_only_preprocessing,
+            &generators,
+            ops.clone(),
+            &mut transcript,
+        );
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let materialized_proof = Surge::prove(
+            &SurgePreprocessing::preprocess(),
+            &generators,
+            ops,
+            &mut transcript,
+        );
+        assert_eq!(
+            proof.audit().init_hashes,
+            materialized_proof.audit().init_hashes
+        );
+        assert_eq!(
+            proof.audit().final_hashes,
+            materialized_proof.audit().final_hashes
+        );
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        Surge::verify(
+            &SurgePreprocessing::preprocess(),
+            &generators,
+            proof,
+            &mut transcript,
+        )
+        .expect("should work");
+    }
+
+    #[test]
+    fn verify_primary_sumcheck_then_memory_checking() {
+        let ops = vec![
+            XORInstruction(12, 12),
+            XORInstruction(12, 82),
+            XORInstruction(12, 12),
+            XORInstruction(25, 12),
+        ];
+        const C: usize = 8;
+        const M: usize = 1 << 8;
+        type Surge = SurgeProof<Fr, HyraxScheme<G1Projective>, XORInstruction, C, M>;
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let preprocessing = SurgePreprocessing::preprocess();
+        let generators = PedersenGenerators::new(Surge::num_generators(16), b"LassoV1");
+        let proof = Surge::prove(&preprocessing, &generators, ops, &mut transcript);
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let openings = Surge::verify_primary_sumcheck(&generators, &proof, &mut transcript)
+            .expect("should work");
+        assert_eq!(openings, proof.audit().primary_sumcheck_openings);
+
+        Surge::verify_memory_checking(
+            &preprocessing,
+            &generators,
+            proof.memory_checking,
+            &proof.commitment,
+            &mut transcript,
+        )
+        .expect("should work");
+    }
+
+    /// Fingerprints (a, v, t) as `a * gamma^2 + v * gamma + t - tau`.
+    struct PermutedFingerprinter;
+
+    impl Fingerprinter<Fr> for PermutedFingerprinter {
+        type Challenges = (Fr, Fr);
+
+        fn challenges(gamma: &Fr, tau: &Fr) -> Self::Challenges {
+            (*gamma, *tau)
+        }
+
+        fn fingerprint(tuple: &(Fr, Fr, Fr), challenges: &Self::Challenges) -> Fr {
+            let (a, v, t) = *tuple;
+            let (gamma, tau) = challenges;
+            a * gamma.square() + v * gamma + t - tau
+        }
+    }
+
+    #[test]
+    fn default_fingerprinter() {
+        let mut rng = test_rng();
+        let (a, v, t, gamma, tau) = (
+            Fr::rand(&mut rng),
+            Fr::rand(&mut rng),
+            Fr::rand(&mut rng),
+            Fr::rand(&mut rng),
+            Fr::rand(&mut rng),
+        );
+        let challenges = ReedSolomonFingerprinter::challenges(&gamma, &tau);
+        let fingerprint = ReedSolomonFingerprinter::fingerprint(&(a, v, t), &challenges);
+        assert_eq!(fingerprint, t * gamma.square() + v * gamma + a - tau);
+
+        // Bumping the timestamp is derived additively, matching the fingerprint from scratch
+        let dt = Fr::rand(&mut rng);
+        assert_eq!(
+            ReedSolomonFingerprinter::add_to_timestamp(&fingerprint, &(a, v, t), &dt, &challenges),
+            ReedSolomonFingerprinter::fingerprint(&(a, v, t + dt), &challenges)
+        );
+    }
+
+    #[test]
+    fn custom_fingerprinter_e2e() {
+        let ops = vec![
+            XORInstruction(12, 12),
+            XORInstruction(12, 82),
+            XORInstruction(12, 12),
+            XORInstruction(25, 12),
+        ];
+        const C: usize = 8;
+        const M: usize = 1 << 8;
+        type Surge = SurgeProof<Fr, HyraxScheme<G1Projective>, XORInstruction, C, M>;
+        type PermutedSurge =
+            SurgeProof<Fr, HyraxScheme<G1Projective>, XORInstruction, C, M, PermutedFingerprinter>;
+
+        let preprocessing = SurgePreprocessing::preprocess();
+        let generators = PedersenGenerators::new(Surge::num_generators(16), b"LassoV1");
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let proof = Surge::prove(&preprocessing, &generators, ops.clone(), &mut transcript);
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let permuted_proof =
+            PermutedSurge::prove(&preprocessing, &generators, ops, &mut transcript);
+
+        // The primary sumcheck is unaffected, but the grand products are over different leaves.
+        assert_eq!(
+            proof.audit().hypercube_sum,
+            permuted_proof.audit().hypercube_sum
+        );
+        assert_ne!(
+            proof.audit().read_hashes,
+            permuted_proof.audit().read_hashes
+        );
+        assert_ne!(
+            proof.audit().init_hashes,
+            permuted_proof.audit().init_hashes
+        );
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        PermutedSurge::verify(&preprocessing, &generators, permuted_proof, &mut transcript)
+            .expect("should work");
+    }
+
+    #[test]
+    fn verify_with_config() {
+        const C: usize = 8;
+        const M: usize = 1 << 8;
+        type Surge = SurgeProof<Fr, HyraxScheme<G1Projective>, XORInstruction, C, M>;
+
+        let ops = vec![
+            XORInstruction(12, 12),
+            XORInstruction(12, 82),
+            XORInstruction(12, 12),
+            XORInstruction(25, 12),
+        ];
+        let preprocessing = SurgePreprocessing::preprocess();
+        let generators = PedersenGenerators::new(Surge::num_generators(16), b"LassoV1");
+        let (_, public_inputs) = Surge::prove_with_public_inputs(
+            &preprocessing,
+            &generators,
+            ops.clone(),
+            &mut ProofTranscript::new(b"test_transcript"),
+        );
+
+        // The primary sumcheck's random point only depends on the transcript prefix
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        public_inputs.append_to_transcript(b"public_inputs", &mut transcript);
+        transcript.append_protocol_name(Surge::protocol_name());
+        let r_primary_sumcheck: Vec<Fr> =
+            transcript.challenge_vector(b"primary_sumcheck", ops.len().log_2());
+        let eq = EqPolynomial::precompute_bound(r_primary_sumcheck.clone(), 2);
+
+        for audit in [false, true] {
+            for strict_degree_bound in [false, true] {
+                for batch in [false, true] {
+                    for (public_inputs, precomputed_eq) in [
+                        (None, None),
+                        (Some(&public_inputs), None),
+                        (Some(&public_inputs), Some(&eq)),
+                    ] {
+                        let mut transcript = ProofTranscript::new(b"test_transcript");
+                        let proof = match public_inputs {
+                            Some(_) => {
+                                Surge::prove_with_public_inputs(
+                                    &preprocessing,
+                                    &generators,
+                                    ops.clone(),
+                                    &mut transcript,
+                                )
+                                .0
+                            }
+                            None => Surge::prove(
+                                &preprocessing,
+                                &generators,
+                                ops.clone(),
+                                &mut transcript,
+                            ),
+                        };
+
+                        let config = VerifierConfig {
+                            audit,
+                            strict_degree_bound,
+                            precomputed_eq,
+                            public_inputs,
+                            batch,
+                        };
+                        let mut transcript = ProofTranscript::new(b"test_transcript");
+                        Surge::verify_with_config(
+                            &preprocessing,
+                            &generators,
+                            proof,
+                            &mut transcript,
+                            &config,
+                        )
+                        .expect("should work");
+                    }
+                }
+            }
+        }
+
+        // An eq polynomial bound to any other point is rejected
+        let mut wrong_point = r_primary_sumcheck;
+        wrong_point[0] += Fr::from(1u64);
+        let wrong_eq = EqPolynomial::precompute_bound(wrong_point, 2);
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let (proof, _) =
+            Surge::prove_with_public_inputs(&preprocessing, &generators, ops, &mut transcript);
+        let config = VerifierConfig {
+            precomputed_eq: Some(&wrong_eq),
+            public_inputs: Some(&public_inputs),
+            ..VerifierConfig::default()
+        };
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        assert!(Surge::verify_with_config(
+            &preprocessing,
+            &generators,
+            proof,
+            &mut transcript,
+            &config,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn verify_with_config_rejects_tampered_proofs() {
+        const C: usize = 2;
+        const M: usize = 1 << 8;
+        type Surge = SurgeProof<Fr, HyraxScheme<G1Projective>, XORInstruction, C, M>;
+
+        let preprocessing = SurgePreprocessing::preprocess();
+        let generators = PedersenGenerators::new(Surge::num_generators(16), b"LassoV1");
+        let prove_mutate_and_verify = |batch: bool, mutate: fn(&mut Surge)| {
+            let ops = vec![
+                XORInstruction(12, 12),
+                XORInstruction(12, 82),
+                XORInstruction(25, 12),
+                XORInstruction(25, 25),
+            ];
+            let mut transcript = ProofTranscript::new(b"test_transcript");
+            let mut proof = Surge::prove(&preprocessing, &generators, ops, &mut transcript);
+            mutate(&mut proof);
+
+            let config = VerifierConfig {
+                audit: true,
+                batch,
+                ..VerifierConfig::default()
+            };
+            let mut transcript = ProofTranscript::new(b"test_transcript");
+            Surge::verify_with_config(&preprocessing, &generators, proof, &mut transcript, &config)
+        };
+
+        for batch in [false, true] {
+            // Fails the primary sumcheck's final check
+            assert!(matches!(
+                prove_mutate_and_verify(batch, |proof| proof.primary_sumcheck.openings[1] +=
+                    Fr::from(1u64)),
+                Err(ProofVerifyError::InternalError)
+            ));
+            assert!(matches!(
+                prove_mutate_and_verify(batch, |proof| proof
+                    .memory_checking
+                    .multiset_hashes
+                    .read_hashes[1] += Fr::from(1u64)),
+                Err(ProofVerifyError::MultisetHashMismatch(1))
+            ));
+            assert!(matches!(
+                prove_mutate_and_verify(batch, |proof| {
+                    proof.memory_checking.multiset_hashes.final_hashes.pop();
+                }),
+                Err(ProofVerifyError::InvalidInputLength(2, 1))
+            ));
+        }
+    }
+
+    #[test]
+    fn duplicate_indices() {
+        const C: usize = 2;
+        const M: usize = 1 << 8;
+        type Surge = SurgeProof<Fr, HyraxScheme<G1Projective>, XORInstruction, C, M>;
+
+        // XORInstruction(x, y) looks up [x_hi || y_hi, x_lo || y_lo] (4-bit chunks).
+        let ops = vec![
+            XORInstruction(0x0c, 0x0c), // [0x00, 0xcc]
+            XORInstruction(0x0c, 0x52), // [0x05, 0xc2]
+            XORInstruction(0x0c, 0x0c), // [0x00, 0xcc]
+            XORInstruction(0x19, 0x5c), // [0x15, 0x9c]
+            XORInstruction(0x10, 0x5c), // [0x15, 0x0c]
+            XORInstruction(0x00, 0x02), // [0x00, 0x02]
+            XORInstruction(0x12, 0x5c), // [0x15, 0x2c]
+            XORInstruction(0x9c, 0x0c), // [0x90, 0xcc]
+        ];
+        let preprocessing = SurgePreprocessing::preprocess();
+        let polys = Surge::construct_polys(&preprocessing, &ops);
+
+        assert_eq!(
+            polys.duplicate_indices(),
+            vec![(0, 2), (0, 4), (0, 5), (0, 6), (1, 2), (1, 7)]
+        );
+    }
+
+    #[test]
+    fn audit() {
+        let ops = vec![
+            XORInstruction(12, 12),
+            XORInstruction(12, 82),
+            XORInstruction(12, 12),
+            XORInstruction(25, 12),
+        ];
+        const C: usize = 8;
+        const M: usize = 1 << 8;
+        type Surge = SurgeProof<Fr, HyraxScheme<G1Projective>, XORInstruction, C, M>;
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let preprocessing = SurgePreprocessing::preprocess();
+        let generators = PedersenGenerators::new(Surge::num_generators(16), b"LassoV1");
+        let proof = Surge::prove(&preprocessing, &generators, ops.clone(), &mut transcript);
+        let audit = proof.audit();
+
+        // Independently recompute the primary sumcheck's hypercube sum
+        let polynomials = Surge::construct_polys(&preprocessing, &ops);
+        let num_rounds = ops.len().next_power_of_two().log_2();
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        transcript.append_protocol_name(Surge::protocol_name());
+        let r_primary_sumcheck: Vec<Fr> =
+            transcript.challenge_vector(b"primary_sumcheck", num_rounds);
+        let eq = EqPolynomial::new(r_primary_sumcheck).evals();
+        let summands: Vec<Fr> = (0..eq.len())
+            .map(|i| {
+                let vals: Vec<Fr> = polynomials.E_polys.iter().map(|E| E[i]).collect();
+                eq[i] * XORInstruction::default().combine_lookups(&vals, C, M)
+            })
+            .collect();
+        assert_eq!(
+            audit.hypercube_sum,
+            DensePolynomial::new(summands).hypercube_sum()
+        );
+
+        assert_eq!(audit.primary_sumcheck_openings.len(), Surge::num_memories());
+        for i in 0..Surge::num_memories() {
+            assert_eq!(
+                audit.init_hashes[i] * audit.write_hashes[i],
+                audit.final_hashes[i] * audit.read_hashes[i]
+            );
+        }
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        Surge::verify(&preprocessing, &generators, proof, &mut transcript).expect("should work");
+    }
+
+    type HyraxSurge<Instruction, const C: usize, const M: usize> =
+        SurgeProof<Fr, HyraxScheme<G1Projective>, Instruction, C, M>;
+
+    /// Checks the fingerprints of honest openings of `ops`, corrupted by `corrupt`, against
+    /// claims computed from the memory-checking leaves.
+    fn check_corrupted_fingerprints<Instruction, const C: usize, const M: usize>(
+        ops: Vec<Instruction>,
+        corrupt: impl FnOnce(
+            &mut SurgeReadWriteOpenings<Fr>,
+            &mut SurgeFinalOpenings<Fr, Instruction, C, M>,
+        ),
+    ) -> Result<(), ProofVerifyError>
+    where
+        Instruction: JoltInstruction + Default + Sync,
+    {
+        let mut rng = test_rng();
+        let preprocessing = SurgePreprocessing::preprocess();
+        let polynomials = HyraxSurge::<Instruction, C, M>::construct_polys(&preprocessing, &ops);
+        let gamma = Fr::rand(&mut rng);
+        let tau = Fr::rand(&mut rng);
+
+        // Stand in for the grand products' output: the leaves' MLEs evaluated at random points
+        let (read_write_leaves, init_final_leaves) =
+            HyraxSurge::<Instruction, C, M>::compute_leaves(
+                &preprocessing,
+                &polynomials,
+                &gamma,
+                &tau,
+            );
+        let r_read_write: Vec<Fr> = (0..ops.len().log_2()).map(|_| Fr::rand(&mut rng)).collect();
+        let r_init_final: Vec<Fr> = (0..M.log_2()).map(|_| Fr::rand(&mut rng)).collect();
+        let claims_read_write: Vec<Fr> = read_write_leaves
+            .iter()
+            .map(|leaves| leaves.evaluate(&r_read_write))
+            .collect();
+        let claims_init_final: Vec<Fr> = init_final_leaves
+            .iter()
+            .map(|leaves| leaves.evaluate(&r_init_final))
+            .collect();
+
+        let mut read_write_openings: SurgeReadWriteOpenings<Fr> =
+            StructuredOpeningProof::open(&polynomials, &r_read_write);
+        read_write_openings.compute_verifier_openings(&NoPreprocessing, &r_read_write);
+        let mut init_final_openings: SurgeFinalOpenings<Fr, Instruction, C, M> =
+            StructuredOpeningProof::open(&polynomials, &r_init_final);
+        init_final_openings.compute_verifier_openings(&preprocessing, &r_init_final);
+
+        corrupt(&mut read_write_openings, &mut init_final_openings);
+        HyraxSurge::<Instruction, C, M>::check_fingerprints(
+            &preprocessing,
+            claims_read_write,
+            claims_init_final,
+            &read_write_openings,
+            &init_final_openings,
+            &gamma,
+            &tau,
+        )
+    }
+
+    #[test]
+    fn memory_checking_reports_failing_chunk() {
+        const C: usize = 4;
+        const M: usize = 1 << 8;
+
+        let ops = vec![
+            XORInstruction(12, 12),
+            XORInstruction(12, 82),
+            XORInstruction(12, 12),
+            XORInstruction(25, 12),
+        ];
+        check_corrupted_fingerprints::<_, C, M>(ops.clone(), |_, _| {})
+            .expect("honest openings should match");
+
+        // Corrupt chunk 2's final counts
+        assert!(matches!(
+            check_corrupted_fingerprints::<_, C, M>(ops, |_, init_final_openings| {
+                init_final_openings.final_openings[2] += Fr::ONE
+            }),
+            Err(ProofVerifyError::MemoryCheckingError(
+                2,
+                MemoryCheckingStage::Final
+            ))
+        ));
+
+        // With several subtables, memories and chunks are numbered differently: the E_i of
+        // the second subtable in chunk 2 is memory C + 2
+        let ops = vec![
+            SLTUInstruction(12, 12),
+            SLTUInstruction(12, 82),
+            SLTUInstruction(82, 12),
+            SLTUInstruction(25, 12),
+        ];
+        assert_eq!(SLTUInstruction::default().subtables::<Fr>(C, M).len(), 2);
+        check_corrupted_fingerprints::<_, C, M>(ops.clone(), |_, _| {})
+            .expect("honest openings should match");
+        assert!(matches!(
+            check_corrupted_fingerprints::<_, C, M>(ops, |read_write_openings, _| {
+                read_write_openings.E_poly_openings[C + 2] += Fr::ONE
+            }),
+            Err(ProofVerifyError::MemoryCheckingError(
+                2,
+                MemoryCheckingStage::Read
+            ))
+        ));
+    }
 }
@@ -18,5 +18,12 @@ pub mod r1cs;
 mod subprotocols;
 pub mod utils;
 
+// Subprotocols usable on their own
+pub use crate::subprotocols::dot_product::DotProductProof;
+pub use crate::subprotocols::grand_product::{
+    BatchedGrandProductArgument, FractionalSumCombiner, LayerCombiner, LayeredCircuit,
+    ProductCombiner,
+};
+
 // Benchmarks
 pub use crate::subprotocols::sumcheck::bench::sumcheck_bench;
@@ -4,6 +4,9 @@ use ark_std::cmp::Ordering;
 use ark_std::vec::Vec;
 use rayon::prelude::*;
 
+#[cfg(feature = "profiling")]
+pub mod size_histogram;
+
 impl<G: CurveGroup> VariableBaseMSM for G {}
 
 /// Copy of ark_ec::VariableBaseMSM with minor modifications to speed up
@@ -12,6 +15,9 @@ pub trait VariableBaseMSM: ScalarMul {
     fn msm(bases: &[Self::MulBase], scalars: &[Self::ScalarField]) -> Result<Self, usize> {
         (bases.len() == scalars.len())
             .then(|| {
+                #[cfg(feature = "profiling")]
+                size_histogram::record(bases.len());
+
                 let max_num_bits = scalars
                     .par_iter()
                     .map(|s| s.into_bigint().num_bits())
@@ -0,0 +1,105 @@
+//! Instrumentation recording the length of every MSM, to show where MSM time goes (e.g. to
+//! judge whether precomputed bases or GPU offload would pay off).
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+thread_local! {
+    static RECORDER: RefCell<Option<Arc<Mutex<MsmSizeHistogram>>>> = const { RefCell::new(None) };
+}
+
+/// Number of MSMs performed, keyed by MSM length.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MsmSizeHistogram {
+    pub counts: BTreeMap<usize, usize>,
+}
+
+impl MsmSizeHistogram {
+    pub fn num_msms(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Total number of (base, scalar) pairs across all MSMs.
+    pub fn total_elements(&self) -> usize {
+        self.counts.iter().map(|(len, count)| len * count).sum()
+    }
+}
+
+/// Runs `f` and returns its result along with the lengths of all MSMs it performed. `f` runs on
+/// a dedicated thread pool, so that MSMs performed concurrently elsewhere are not recorded.
+pub fn record_msm_sizes<T: Send>(f: impl FnOnce() -> T + Send) -> (T, MsmSizeHistogram) {
+    let histogram = Arc::new(Mutex::new(MsmSizeHistogram::default()));
+    let recorder = histogram.clone();
+    let pool = rayon::ThreadPoolBuilder::new()
+        .start_handler(move |_| {
+            RECORDER.with(|slot| *slot.borrow_mut() = Some(recorder.clone()));
+        })
+        .build()
+        .unwrap();
+    let result = pool.install(f);
+    let histogram = histogram.lock().unwrap().clone();
+    (result, histogram)
+}
+
+pub(super) fn record(len: usize) {
+    RECORDER.with(|slot| {
+        if let Some(histogram) = slot.borrow().as_ref() {
+            *histogram.lock().unwrap().counts.entry(len).or_insert(0) += 1;
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::record_msm_sizes;
+    use crate::poly::{
+        commitment::{
+            commitment_scheme::CommitmentScheme,
+            hyrax::{matrix_dimensions, HyraxScheme},
+            pedersen::PedersenGenerators,
+        },
+        dense_mlpoly::DensePolynomial,
+        field::JoltField,
+    };
+    use crate::utils::transcript::ProofTranscript;
+    use ark_bn254::{Fr, G1Projective};
+    use ark_std::test_rng;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn commit_and_open() {
+        let mut rng = test_rng();
+        let num_vars = [8, 5];
+        let polys: Vec<DensePolynomial<Fr>> = num_vars
+            .iter()
+            .map(|n| DensePolynomial::new((0..1 << n).map(|_| Fr::random(&mut rng)).collect()))
+            .collect();
+        let generators: PedersenGenerators<G1Projective> = PedersenGenerators::new(1 << 8, b"test");
+
+        let points: Vec<Vec<Fr>> = num_vars
+            .iter()
+            .map(|n| (0..*n).map(|_| Fr::random(&mut rng)).collect())
+            .collect();
+
+        let (_, histogram) = record_msm_sizes(|| {
+            for (poly, point) in polys.iter().zip(points.iter()) {
+                let _ = HyraxScheme::<G1Projective>::commit(poly, &generators);
+                let mut transcript = ProofTranscript::new(b"test_transcript");
+                let _ = HyraxScheme::<G1Projective>::prove(poly, point, &mut transcript);
+            }
+        });
+
+        // Committing performs one MSM per row, each as long as a row; opening performs none
+        let mut expected = BTreeMap::new();
+        for n in num_vars {
+            let (num_rows, row_size) = matrix_dimensions(n, 1);
+            *expected.entry(row_size).or_insert(0) += num_rows;
+        }
+        assert_eq!(histogram.counts, expected);
+        assert_eq!(
+            histogram.total_elements(),
+            num_vars.iter().map(|n| 1 << n).sum::<usize>()
+        );
+    }
+}
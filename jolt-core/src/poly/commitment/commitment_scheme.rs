@@ -1,7 +1,11 @@
-use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use std::io::{Read, Write};
 
 use crate::{
-    poly::{dense_mlpoly::DensePolynomial, field::JoltField},
+    poly::{
+        dense_mlpoly::DensePolynomial,
+        field::{ExtensionField, JoltField},
+    },
     utils::{
         errors::ProofVerifyError,
         transcript::{AppendToTranscript, ProofTranscript},
@@ -33,12 +37,20 @@ pub enum BatchType {
 
 pub trait CommitmentScheme: Clone + Sync + Send + 'static {
     type Field: JoltField;
-    type Setup: Clone + Sync + Send;
+    type Setup: Clone + Sync + Send + CanonicalSerialize + CanonicalDeserialize;
     type Commitment: Sync + Send + CanonicalSerialize + CanonicalDeserialize + AppendToTranscript;
     type Proof: Sync + Send + CanonicalSerialize + CanonicalDeserialize;
     type BatchedProof: Sync + Send + CanonicalSerialize + CanonicalDeserialize;
 
     fn setup(shapes: &[CommitShape]) -> Self::Setup;
+    /// Same as `setup`, but derives the generators deterministically from `seed` instead of
+    /// the scheme's default seed.
+    fn setup_from_seed(shapes: &[CommitShape], seed: &[u8]) -> Self::Setup;
+    /// Same as `setup`, but wrapped in a `CommitmentGens` so that it can be saved and shared
+    /// with a verifier.
+    fn gens(shapes: &[CommitShape]) -> CommitmentGens<Self> {
+        CommitmentGens::new(Self::setup(shapes))
+    }
     fn commit(poly: &DensePolynomial<Self::Field>, setup: &Self::Setup) -> Self::Commitment;
     fn batch_commit(
         evals: &[&[Self::Field]],
@@ -84,6 +96,120 @@ pub trait CommitmentScheme: Clone + Sync + Send + 'static {
         commitment: &Self::Commitment,
     ) -> Result<(), ProofVerifyError>;
 
+    /// Same as `verify`, but on success returns the validated `(opening_point, opening)` claim,
+    /// so that composed protocols can feed it onward without re-deriving the evaluation.
+    fn verify_eval(
+        proof: &Self::Proof,
+        setup: &Self::Setup,
+        transcript: &mut ProofTranscript,
+        opening_point: &[Self::Field],
+        opening: &Self::Field,
+        commitment: &Self::Commitment,
+    ) -> Result<(Vec<Self::Field>, Self::Field), ProofVerifyError> {
+        Self::verify(proof, setup, transcript, opening_point, opening, commitment)?;
+        Ok((opening_point.to_vec(), *opening))
+    }
+
+    /// Same as `verify_eval`, but with the opening point and opening given as raw
+    /// little-endian field element encodings of `N` bytes each, e.g. as passed by an external
+    /// system. Each encoding must be canonical, i.e. represent an integer less than the field
+    /// modulus; otherwise `NonCanonicalFieldElement` is returned.
+    fn verify_eval_raw<const N: usize>(
+        proof: &Self::Proof,
+        setup: &Self::Setup,
+        transcript: &mut ProofTranscript,
+        point_bytes: &[[u8; N]],
+        value_bytes: &[u8; N],
+        commitment: &Self::Commitment,
+    ) -> Result<(Vec<Self::Field>, Self::Field), ProofVerifyError> {
+        if N != Self::Field::NUM_BYTES {
+            return Err(ProofVerifyError::InvalidInputLength(
+                Self::Field::NUM_BYTES,
+                N,
+            ));
+        }
+        let parse = |bytes: &[u8; N]| -> Result<Self::Field, ProofVerifyError> {
+            Self::Field::deserialize_compressed(&bytes[..])
+                .map_err(|_| ProofVerifyError::NonCanonicalFieldElement(bytes.to_vec()))
+        };
+        let opening_point = point_bytes
+            .iter()
+            .map(parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        let opening = parse(value_bytes)?;
+        Self::verify_eval(
+            proof,
+            setup,
+            transcript,
+            &opening_point,
+            &opening,
+            commitment,
+        )
+    }
+
+    /// Proves that the committed polynomials `a` and `b` are equal as multilinear polynomials.
+    /// Both commitments are absorbed into the transcript, a single random point `r` is drawn,
+    /// and both polynomials are opened at `r`. By Schwartz-Zippel, if `a != b` then
+    /// `a(r) != b(r)` except with probability `num_vars / |F|`.
+    fn prove_poly_equality(
+        comm_a: &Self::Commitment,
+        comm_b: &Self::Commitment,
+        a: &DensePolynomial<Self::Field>,
+        b: &DensePolynomial<Self::Field>,
+        transcript: &mut ProofTranscript,
+    ) -> PolyEqualityProof<Self> {
+        assert_eq!(a.get_num_vars(), b.get_num_vars());
+        transcript.append_protocol_name(PolyEqualityProof::<Self>::protocol_name());
+        comm_a.append_to_transcript(b"comm_a", transcript);
+        comm_b.append_to_transcript(b"comm_b", transcript);
+        let r: Vec<Self::Field> = transcript.challenge_vector(b"equality_point", a.get_num_vars());
+
+        // If `a != b`, `b(r)` will (w.h.p.) not match this opening and `proof_b` will not verify.
+        let opening = a.evaluate(&r);
+        transcript.append_scalar(b"opening", &opening);
+        let proof_a = Self::prove(a, &r, transcript);
+        let proof_b = Self::prove(b, &r, transcript);
+        PolyEqualityProof {
+            opening,
+            proof_a,
+            proof_b,
+        }
+    }
+
+    /// Verifies a `PolyEqualityProof` that the `num_vars`-variate polynomials committed to by
+    /// `comm_a` and `comm_b` are equal.
+    fn verify_poly_equality(
+        proof: &PolyEqualityProof<Self>,
+        setup: &Self::Setup,
+        comm_a: &Self::Commitment,
+        comm_b: &Self::Commitment,
+        num_vars: usize,
+        transcript: &mut ProofTranscript,
+    ) -> Result<(), ProofVerifyError> {
+        transcript.append_protocol_name(PolyEqualityProof::<Self>::protocol_name());
+        comm_a.append_to_transcript(b"comm_a", transcript);
+        comm_b.append_to_transcript(b"comm_b", transcript);
+        let r: Vec<Self::Field> = transcript.challenge_vector(b"equality_point", num_vars);
+
+        transcript.append_scalar(b"opening", &proof.opening);
+        Self::verify(
+            &proof.proof_a,
+            setup,
+            transcript,
+            &r,
+            &proof.opening,
+            comm_a,
+        )?;
+        Self::verify(
+            &proof.proof_b,
+            setup,
+            transcript,
+            &r,
+            &proof.opening,
+            comm_b,
+        )
+    }
+
     fn batch_verify(
         batch_proof: &Self::BatchedProof,
         setup: &Self::Setup,
@@ -95,3 +221,83 @@ pub trait CommitmentScheme: Clone + Sync + Send + 'static {
 
     fn protocol_name() -> &'static [u8];
 }
+
+/// A `CommitmentScheme` that can open a commitment to a polynomial over its field `F` at a point
+/// over an extension field `E` of `F`, e.g. when the point is drawn by a recursive verifier
+/// working over `E`. The polynomial's coefficients are lifted into `E`, so the opening is in `E`.
+///
+/// Opening at `r` in `E^n` is as sound as opening at a point in `F^n`: the lifted polynomial is
+/// uniquely determined by the committed one, so no additional error is introduced. What changes
+/// is the soundness of the protocol choosing `r`, whose Schwartz-Zippel error drops to
+/// `n / |E|`. In exchange, the argument costs up to `[E : F]` times as much as a base-field
+/// opening, since it is carried out coordinate-wise over `F`.
+pub trait ExtensionOpening: CommitmentScheme {
+    type ExtProof: Sync + Send + CanonicalSerialize + CanonicalDeserialize;
+
+    fn prove_eval_ext<E: ExtensionField<Self::Field>>(
+        poly: &DensePolynomial<Self::Field>,
+        opening_point: &[E], // point at which the polynomial is evaluated
+        transcript: &mut ProofTranscript,
+    ) -> Self::ExtProof;
+
+    fn verify_eval_ext<E: ExtensionField<Self::Field>>(
+        proof: &Self::ExtProof,
+        setup: &Self::Setup,
+        transcript: &mut ProofTranscript,
+        opening_point: &[E], // point at which the polynomial is evaluated
+        opening: &E,         // evaluation \widetilde{Z}(r), lifted into E
+        commitment: &Self::Commitment,
+    ) -> Result<(), ProofVerifyError>;
+}
+
+/// Proof that two committed polynomials are equal: a shared opening of both at a single
+/// random point. See `CommitmentScheme::prove_poly_equality`.
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+pub struct PolyEqualityProof<CS: CommitmentScheme> {
+    /// The claimed common evaluation `a(r) = b(r)`.
+    pub opening: CS::Field,
+    pub proof_a: CS::Proof,
+    pub proof_b: CS::Proof,
+}
+
+impl<CS: CommitmentScheme> PolyEqualityProof<CS> {
+    fn protocol_name() -> &'static [u8] {
+        b"Jolt PolyEqualityProof"
+    }
+}
+
+/// The generators (`Setup`) used to commit with a `CommitmentScheme`. The prover can `save`
+/// these so that a verifier can `load` exactly the same generators.
+#[derive(Clone)]
+pub struct CommitmentGens<CS: CommitmentScheme> {
+    setup: CS::Setup,
+}
+
+impl<CS: CommitmentScheme> CommitmentGens<CS> {
+    pub fn new(setup: CS::Setup) -> Self {
+        Self { setup }
+    }
+
+    /// Derives the generators for `shapes` deterministically from `seed`.
+    pub fn from_seed(shapes: &[CommitShape], seed: &[u8]) -> Self {
+        Self::new(CS::setup_from_seed(shapes, seed))
+    }
+
+    pub fn setup(&self) -> &CS::Setup {
+        &self.setup
+    }
+
+    pub fn into_setup(self) -> CS::Setup {
+        self.setup
+    }
+
+    pub fn save<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.setup.serialize_compressed(writer)
+    }
+
+    pub fn load<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Ok(Self::new(
+            <CS::Setup as CanonicalDeserialize>::deserialize_compressed(reader)?,
+        ))
+    }
+}
@@ -1,27 +1,40 @@
 use std::marker::PhantomData;
 
-use super::commitment_scheme::{BatchType, CommitShape, CommitmentScheme};
+use super::commitment_scheme::{BatchType, CommitShape, CommitmentScheme, ExtensionOpening};
 use super::pedersen::{PedersenCommitment, PedersenGenerators};
 use crate::poly::dense_mlpoly::DensePolynomial;
-use crate::poly::eq_poly::EqPolynomial;
-use crate::poly::field::JoltField;
+use crate::poly::eq_poly::{EqPolynomial, PrecomputedEq};
+use crate::poly::field::{ExtensionField, JoltField};
 use crate::utils::errors::ProofVerifyError;
 use crate::utils::math::Math;
 use crate::utils::transcript::{AppendToTranscript, ProofTranscript};
 use crate::utils::{compute_dotproduct, mul_0_1_optimized};
 use ark_ec::CurveGroup;
-use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+};
 use num_integer::Roots;
 use rayon::prelude::*;
+use std::io::{Read, Write};
 use tracing::trace_span;
 
 use crate::msm::VariableBaseMSM;
 
+/// The Hyrax commitment scheme, committing to polynomials with the given matrix layout (see
+/// `HyraxLayout`): row-major by default, column-major with `COLUMN_MAJOR`.
 #[derive(Clone)]
-pub struct HyraxScheme<G: CurveGroup> {
+pub struct HyraxScheme<G: CurveGroup, const COLUMN_MAJOR: bool = false> {
     marker: PhantomData<G>,
 }
 
+impl<G: CurveGroup, const COLUMN_MAJOR: bool> HyraxScheme<G, COLUMN_MAJOR> {
+    pub const LAYOUT: HyraxLayout = if COLUMN_MAJOR {
+        HyraxLayout::ColumnMajor
+    } else {
+        HyraxLayout::RowMajor
+    };
+}
+
 const TRACE_LEN_R1CS_POLYS_BATCH_RATIO: usize = 64;
 const SURGE_RATIO_READ_WRITE: usize = 16;
 const SURGE_RATIO_FINAL: usize = 4;
@@ -35,6 +48,63 @@ pub fn batch_type_to_ratio(batch_type: &BatchType) -> usize {
     }
 }
 
+/// Layout of the evaluation matrix committed to by Hyrax, viewed as `L_size` rows of
+/// `R_size` evaluations (see `matrix_dimensions`). With `RowMajor`, each commitment is an MSM
+/// over a contiguous row using `R_size` generators; with `ColumnMajor`, each commitment is an
+/// MSM over a strided column using `L_size` generators. A `HyraxCommitment` records the layout
+/// it was made with, and opening proofs are verified with that layout.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HyraxLayout {
+    #[default]
+    RowMajor,
+    ColumnMajor,
+}
+
+impl HyraxLayout {
+    /// The number of generators needed to commit to a `num_vars`-variate polynomial with this
+    /// layout, i.e. the length of each committed row or column.
+    pub fn num_generators(&self, num_vars: usize, ratio: usize) -> usize {
+        let (L_size, R_size) = matrix_dimensions(num_vars, ratio);
+        match self {
+            HyraxLayout::RowMajor => R_size,
+            HyraxLayout::ColumnMajor => L_size,
+        }
+    }
+}
+
+impl CanonicalSerialize for HyraxLayout {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        (*self == HyraxLayout::ColumnMajor).serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        false.serialized_size(compress)
+    }
+}
+
+impl Valid for HyraxLayout {
+    fn check(&self) -> Result<(), SerializationError> {
+        Ok(())
+    }
+}
+
+impl CanonicalDeserialize for HyraxLayout {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        match bool::deserialize_with_mode(reader, compress, validate)? {
+            false => Ok(HyraxLayout::RowMajor),
+            true => Ok(HyraxLayout::ColumnMajor),
+        }
+    }
+}
+
 pub fn matrix_dimensions(num_vars: usize, ratio: usize) -> (usize, usize) {
     let mut row_size = (num_vars / 2).pow2();
     row_size = (row_size * ratio.sqrt()).next_power_of_two();
@@ -47,7 +117,9 @@ pub fn matrix_dimensions(num_vars: usize, ratio: usize) -> (usize, usize) {
     (col_size, row_size)
 }
 
-impl<F: JoltField, G: CurveGroup<ScalarField = F>> CommitmentScheme for HyraxScheme<G> {
+impl<F: JoltField, G: CurveGroup<ScalarField = F>, const COLUMN_MAJOR: bool> CommitmentScheme
+    for HyraxScheme<G, COLUMN_MAJOR>
+{
     type Field = G::ScalarField;
     type Setup = PedersenGenerators<G>;
     type Commitment = HyraxCommitment<G>;
@@ -55,31 +127,33 @@ impl<F: JoltField, G: CurveGroup<ScalarField = F>> CommitmentScheme for HyraxSch
     type BatchedProof = BatchedHyraxOpeningProof<G>;
 
     fn setup(shapes: &[CommitShape]) -> Self::Setup {
-        let mut max_len: usize = 0;
-        for shape in shapes {
-            let len = matrix_dimensions(
-                shape.input_length.log_2(),
-                batch_type_to_ratio(&shape.batch_type),
-            )
-            .1;
-            if len > max_len {
-                max_len = len;
-            }
-        }
-        PedersenGenerators::new(max_len, b"Jolt v1 Hyrax generators")
+        Self::setup_from_seed(shapes, b"Jolt v1 Hyrax generators")
+    }
+    fn setup_from_seed(shapes: &[CommitShape], seed: &[u8]) -> Self::Setup {
+        let max_len = shapes
+            .iter()
+            .map(|shape| {
+                Self::LAYOUT.num_generators(
+                    shape.input_length.log_2(),
+                    batch_type_to_ratio(&shape.batch_type),
+                )
+            })
+            .max()
+            .unwrap_or(0);
+        PedersenGenerators::new(max_len, seed)
     }
     fn commit(poly: &DensePolynomial<Self::Field>, gens: &Self::Setup) -> Self::Commitment {
-        HyraxCommitment::commit(poly, gens)
+        HyraxCommitment::commit_slice_with_layout(poly.evals_ref(), gens, Self::LAYOUT)
     }
     fn batch_commit(
         evals: &[&[Self::Field]],
         gens: &Self::Setup,
         batch_type: BatchType,
     ) -> Vec<Self::Commitment> {
-        HyraxCommitment::batch_commit(evals, gens, batch_type)
+        HyraxCommitment::batch_commit_with_layout(evals, gens, batch_type, Self::LAYOUT)
     }
     fn commit_slice(eval_slice: &[Self::Field], generators: &Self::Setup) -> Self::Commitment {
-        HyraxCommitment::commit_slice(eval_slice, generators)
+        HyraxCommitment::commit_slice_with_layout(eval_slice, generators, Self::LAYOUT)
     }
     fn prove(
         poly: &DensePolynomial<Self::Field>,
@@ -87,7 +161,7 @@ impl<F: JoltField, G: CurveGroup<ScalarField = F>> CommitmentScheme for HyraxSch
         transcript: &mut ProofTranscript,
     ) -> Self::Proof {
         // Implicitly prove is "prove_single", with a ratio = 1
-        HyraxOpeningProof::prove(poly, opening_point, 1, transcript)
+        HyraxOpeningProof::prove_with_layout(poly, opening_point, 1, Self::LAYOUT, transcript)
     }
     fn batch_prove(
         polynomials: &[&DensePolynomial<Self::Field>],
@@ -96,11 +170,12 @@ impl<F: JoltField, G: CurveGroup<ScalarField = F>> CommitmentScheme for HyraxSch
         batch_type: BatchType,
         transcript: &mut ProofTranscript,
     ) -> Self::BatchedProof {
-        BatchedHyraxOpeningProof::prove(
+        BatchedHyraxOpeningProof::prove_with_layout(
             polynomials,
             opening_point,
             openings,
             batch_type,
+            Self::LAYOUT,
             transcript,
         )
     }
@@ -112,8 +187,11 @@ impl<F: JoltField, G: CurveGroup<ScalarField = F>> CommitmentScheme for HyraxSch
         opening: &Self::Field,
         commitment: &Self::Commitment,
     ) -> Result<(), ProofVerifyError> {
+        if commitment.layout != Self::LAYOUT {
+            return Err(ProofVerifyError::InternalError);
+        }
         // Implicitly verify is "prove_single", with a ratio = 1
-        HyraxOpeningProof::verify(
+        HyraxOpeningProof::verify_with_layout(
             proof,
             generators,
             transcript,
@@ -131,6 +209,12 @@ impl<F: JoltField, G: CurveGroup<ScalarField = F>> CommitmentScheme for HyraxSch
         commitments: &[&Self::Commitment],
         transcript: &mut ProofTranscript,
     ) -> Result<(), ProofVerifyError> {
+        if commitments
+            .iter()
+            .any(|commitment| commitment.layout != Self::LAYOUT)
+        {
+            return Err(ProofVerifyError::InternalError);
+        }
         BatchedHyraxOpeningProof::verify(
             batch_proof,
             generators,
@@ -145,6 +229,29 @@ impl<F: JoltField, G: CurveGroup<ScalarField = F>> CommitmentScheme for HyraxSch
     }
 }
 
+impl<F: JoltField, G: CurveGroup<ScalarField = F>> ExtensionOpening for HyraxScheme<G> {
+    type ExtProof = HyraxExtOpeningProof<G>;
+
+    fn prove_eval_ext<E: ExtensionField<F>>(
+        poly: &DensePolynomial<F>,
+        opening_point: &[E],
+        transcript: &mut ProofTranscript,
+    ) -> Self::ExtProof {
+        HyraxExtOpeningProof::prove(poly, opening_point, transcript)
+    }
+
+    fn verify_eval_ext<E: ExtensionField<F>>(
+        proof: &Self::ExtProof,
+        generators: &Self::Setup,
+        transcript: &mut ProofTranscript,
+        opening_point: &[E],
+        opening: &E,
+        commitment: &Self::Commitment,
+    ) -> Result<(), ProofVerifyError> {
+        proof.verify(generators, transcript, opening_point, opening, commitment)
+    }
+}
+
 #[derive(Clone, CanonicalSerialize, CanonicalDeserialize)]
 pub struct HyraxGenerators<G: CurveGroup> {
     pub gens: PedersenGenerators<G>,
@@ -152,7 +259,10 @@ pub struct HyraxGenerators<G: CurveGroup> {
 
 #[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
 pub struct HyraxCommitment<G: CurveGroup> {
+    /// Commitments to the rows of the evaluation matrix, or to its columns for
+    /// `HyraxLayout::ColumnMajor`.
     pub row_commitments: Vec<G>,
+    pub layout: HyraxLayout,
 }
 
 impl<F: JoltField, G: CurveGroup<ScalarField = F>> HyraxCommitment<G> {
@@ -177,7 +287,48 @@ impl<F: JoltField, G: CurveGroup<ScalarField = F>> HyraxCommitment<G> {
             .par_chunks(R_size)
             .map(|row| PedersenCommitment::commit_vector(row, &gens))
             .collect();
-        Self { row_commitments }
+        Self {
+            row_commitments,
+            layout: HyraxLayout::RowMajor,
+        }
+    }
+
+    /// Commits to `eval_slice` using the given matrix `layout`.
+    #[tracing::instrument(skip_all, name = "HyraxCommitment::commit_slice_with_layout")]
+    pub fn commit_slice_with_layout(
+        eval_slice: &[G::ScalarField],
+        generators: &PedersenGenerators<G>,
+        layout: HyraxLayout,
+    ) -> Self {
+        match layout {
+            HyraxLayout::RowMajor => Self::commit_slice(eval_slice, generators),
+            HyraxLayout::ColumnMajor => {
+                let (L_size, _R_size) = matrix_dimensions(eval_slice.len().log_2(), 1);
+                let gens = CurveGroup::normalize_batch(&generators.generators[..L_size]);
+                Self::commit_columns(eval_slice, &gens, 1)
+            }
+        }
+    }
+
+    /// Commits to each column of `eval_slice`, viewed as a matrix with the dimensions given by
+    /// `matrix_dimensions` for `ratio`, using the first `L_size` of `gens`.
+    fn commit_columns(eval_slice: &[G::ScalarField], gens: &[G::Affine], ratio: usize) -> Self {
+        let n = eval_slice.len();
+        let (L_size, R_size) = matrix_dimensions(n.log_2(), ratio);
+        assert_eq!(L_size * R_size, n);
+
+        let row_commitments = (0..R_size)
+            .into_par_iter()
+            .map(|j| {
+                let column: Vec<G::ScalarField> =
+                    (0..L_size).map(|i| eval_slice[i * R_size + j]).collect();
+                PedersenCommitment::commit_vector(&column[..], &gens[..L_size])
+            })
+            .collect();
+        Self {
+            row_commitments,
+            layout: HyraxLayout::ColumnMajor,
+        }
     }
 
     #[tracing::instrument(skip_all, name = "HyraxCommitment::batch_commit")]
@@ -206,9 +357,88 @@ impl<F: JoltField, G: CurveGroup<ScalarField = F>> HyraxCommitment<G> {
             .par_chunks(L_size)
             .map(|chunk| Self {
                 row_commitments: chunk.to_vec(),
+                layout: HyraxLayout::RowMajor,
             })
             .collect()
     }
+
+    /// Same as `batch_commit`, but using the given matrix `layout`.
+    #[tracing::instrument(skip_all, name = "HyraxCommitment::batch_commit_with_layout")]
+    pub fn batch_commit_with_layout(
+        batch: &[&[G::ScalarField]],
+        generators: &PedersenGenerators<G>,
+        batch_type: BatchType,
+        layout: HyraxLayout,
+    ) -> Vec<Self> {
+        match layout {
+            HyraxLayout::RowMajor => Self::batch_commit(batch, generators, batch_type),
+            HyraxLayout::ColumnMajor => {
+                let ratio = batch_type_to_ratio(&batch_type);
+                let (L_size, _R_size) = matrix_dimensions(batch[0].len().log_2(), ratio);
+                let gens = CurveGroup::normalize_batch(&generators.generators[..L_size]);
+                batch
+                    .iter()
+                    .map(|poly| Self::commit_columns(poly, &gens, ratio))
+                    .collect()
+            }
+        }
+    }
+
+    /// Hiding variant of `commit`: row commitment i is additionally blinded by `r_i * blind_gen`,
+    /// for a fresh random `r_i` sampled from `rng`. `blind_gen` must not be one of `generators`.
+    #[tracing::instrument(skip_all, name = "HyraxCommitment::commit_hiding")]
+    pub fn commit_hiding<R: rand_core::RngCore>(
+        poly: &DensePolynomial<G::ScalarField>,
+        generators: &PedersenGenerators<G>,
+        blind_gen: &G,
+        rng: &mut R,
+    ) -> (Self, HyraxBlind<G::ScalarField>) {
+        let commitment = Self::commit(poly, generators);
+        let row_blinds: Vec<G::ScalarField> = (0..commitment.row_commitments.len())
+            .map(|_| G::ScalarField::random(rng))
+            .collect();
+        let row_commitments = commitment
+            .row_commitments
+            .iter()
+            .zip(row_blinds.iter())
+            .map(|(row_commitment, row_blind)| *row_commitment + *blind_gen * row_blind)
+            .collect();
+        (
+            Self {
+                row_commitments,
+                layout: HyraxLayout::RowMajor,
+            },
+            HyraxBlind { row_blinds },
+        )
+    }
+}
+
+/// The blinding factors of a commitment produced by `HyraxCommitment::commit_hiding`, one per
+/// row commitment. Known only to the prover.
+#[derive(Clone, Debug)]
+pub struct HyraxBlind<F: JoltField> {
+    pub row_blinds: Vec<F>,
+}
+
+#[cfg(feature = "zeroize")]
+impl<F: JoltField> HyraxBlind<F> {
+    /// Overwrites the blinding factors with zeros. Uses volatile writes so that the wipe is not
+    /// optimized away as a dead store.
+    pub fn zeroize(&mut self) {
+        for row_blind in self.row_blinds.iter_mut() {
+            // SAFETY: `row_blind` is a valid, aligned reference to an initialized `F: Copy`
+            unsafe { std::ptr::write_volatile(row_blind, F::zero()) };
+        }
+        std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+/// With the `zeroize` feature, blinding factors are wiped when dropped.
+#[cfg(feature = "zeroize")]
+impl<F: JoltField> Drop for HyraxBlind<F> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
 }
 
 impl<G: CurveGroup> AppendToTranscript for HyraxCommitment<G> {
@@ -257,6 +487,109 @@ impl<F: JoltField, G: CurveGroup<ScalarField = F>> HyraxOpeningProof<G> {
         }
     }
 
+    /// Same as `prove`, but for a polynomial committed to with the given matrix `layout`.
+    #[tracing::instrument(skip_all, name = "HyraxOpeningProof::prove_with_layout")]
+    pub fn prove_with_layout(
+        poly: &DensePolynomial<G::ScalarField>,
+        opening_point: &[G::ScalarField], // point at which the polynomial is evaluated
+        ratio: usize,
+        layout: HyraxLayout,
+        transcript: &mut ProofTranscript,
+    ) -> HyraxOpeningProof<G> {
+        match layout {
+            HyraxLayout::RowMajor => Self::prove(poly, opening_point, ratio, transcript),
+            HyraxLayout::ColumnMajor => {
+                transcript.append_protocol_name(Self::protocol_name());
+
+                // assert vectors are of the right size
+                assert_eq!(poly.get_num_vars(), opening_point.len());
+
+                // compute the L and R vectors
+                let (L_size, R_size) = matrix_dimensions(poly.get_num_vars(), ratio);
+                let eq = EqPolynomial::new(opening_point.to_vec());
+                let (_L, R) = eq.compute_factored_evals(L_size);
+
+                // compute matrix-vector product between Z viewed as a matrix and R
+                let vector_matrix_product = poly
+                    .evals_ref()
+                    .par_chunks(R_size)
+                    .map(|row| compute_dotproduct(row, &R))
+                    .collect();
+
+                HyraxOpeningProof {
+                    vector_matrix_product,
+                }
+            }
+        }
+    }
+
+    /// Verifies an opening proof produced by `prove_with_layout`, with the layout `commitment`
+    /// was made with.
+    pub fn verify_with_layout(
+        &self,
+        pedersen_generators: &PedersenGenerators<G>,
+        transcript: &mut ProofTranscript,
+        opening_point: &[G::ScalarField], // point at which the polynomial is evaluated
+        opening: &G::ScalarField,         // evaluation \widetilde{Z}(r)
+        commitment: &HyraxCommitment<G>,
+        ratio: usize,
+    ) -> Result<(), ProofVerifyError> {
+        match commitment.layout {
+            HyraxLayout::RowMajor => self.verify(
+                pedersen_generators,
+                transcript,
+                opening_point,
+                opening,
+                commitment,
+                ratio,
+            ),
+            HyraxLayout::ColumnMajor => {
+                transcript.append_protocol_name(Self::protocol_name());
+
+                let (L_size, R_size) = matrix_dimensions(opening_point.len(), ratio);
+                for (expected, actual) in [
+                    (R_size, commitment.row_commitments.len()),
+                    (L_size, self.vector_matrix_product.len()),
+                ] {
+                    if expected != actual {
+                        return Err(ProofVerifyError::InvalidInputLength(expected, actual));
+                    }
+                }
+                if pedersen_generators.generators.len() < L_size {
+                    return Err(ProofVerifyError::InvalidInputLength(
+                        L_size,
+                        pedersen_generators.generators.len(),
+                    ));
+                }
+
+                // compute L and R
+                let eq: EqPolynomial<_> = EqPolynomial::new(opening_point.to_vec());
+                let (L, R) = eq.compute_factored_evals(L_size);
+
+                // Verifier-derived commitment to Z * R = \prod Com(column_j)^{R_j}
+                let homomorphically_derived_commitment: G =
+                    VariableBaseMSM::msm(&G::normalize_batch(&commitment.row_commitments), &R)
+                        .unwrap();
+
+                let product_commitment = VariableBaseMSM::msm(
+                    &G::normalize_batch(&pedersen_generators.generators[..L_size]),
+                    &self.vector_matrix_product,
+                )
+                .unwrap();
+
+                let dot_product = compute_dotproduct(&self.vector_matrix_product, &L);
+
+                if (homomorphically_derived_commitment == product_commitment)
+                    && (dot_product == *opening)
+                {
+                    Ok(())
+                } else {
+                    Err(ProofVerifyError::InternalError)
+                }
+            }
+        }
+    }
+
     pub fn verify(
         &self,
         pedersen_generators: &PedersenGenerators<G>,
@@ -266,16 +599,44 @@ impl<F: JoltField, G: CurveGroup<ScalarField = F>> HyraxOpeningProof<G> {
         commitment: &HyraxCommitment<G>,
         ratio: usize,
     ) -> Result<(), ProofVerifyError> {
+        let (L_size, _R_size) = matrix_dimensions(opening_point.len(), ratio);
+        let eq = EqPolynomial::precompute_bound(opening_point.to_vec(), L_size);
+        self.verify_with_precomputed_eq(
+            pedersen_generators,
+            transcript,
+            &eq,
+            opening,
+            commitment,
+            ratio,
+        )
+    }
+
+    /// Same as `verify`, but takes the eq polynomial already bound to the opening point
+    /// (see `EqPolynomial::precompute_bound`), e.g. when many proofs are checked against the
+    /// same point.
+    pub fn verify_with_precomputed_eq(
+        &self,
+        pedersen_generators: &PedersenGenerators<G>,
+        transcript: &mut ProofTranscript,
+        eq: &PrecomputedEq<G::ScalarField>, // eq bound to the point at which the polynomial is evaluated
+        opening: &G::ScalarField,           // evaluation \widetilde{Z}(r)
+        commitment: &HyraxCommitment<G>,
+        ratio: usize,
+    ) -> Result<(), ProofVerifyError> {
+        if commitment.layout != HyraxLayout::RowMajor {
+            return Err(ProofVerifyError::InternalError);
+        }
         transcript.append_protocol_name(Self::protocol_name());
 
-        // compute L and R
-        let (L_size, R_size) = matrix_dimensions(opening_point.len(), ratio);
-        let eq: EqPolynomial<_> = EqPolynomial::new(opening_point.to_vec());
-        let (L, R) = eq.compute_factored_evals(L_size);
+        let (L_size, R_size) = matrix_dimensions(eq.point.len(), ratio);
+        if eq.L.len() != L_size {
+            return Err(ProofVerifyError::InvalidInputLength(L_size, eq.L.len()));
+        }
+        let (L, R) = (&eq.L, &eq.R);
 
         // Verifier-derived commitment to u * a = \prod Com(u_j)^{a_j}
         let homomorphically_derived_commitment: G =
-            VariableBaseMSM::msm(&G::normalize_batch(&commitment.row_commitments), &L).unwrap();
+            VariableBaseMSM::msm(&G::normalize_batch(&commitment.row_commitments), L).unwrap();
 
         let product_commitment = VariableBaseMSM::msm(
             &G::normalize_batch(&pedersen_generators.generators[..R_size]),
@@ -283,7 +644,7 @@ impl<F: JoltField, G: CurveGroup<ScalarField = F>> HyraxOpeningProof<G> {
         )
         .unwrap();
 
-        let dot_product = compute_dotproduct(&self.vector_matrix_product, &R);
+        let dot_product = compute_dotproduct(&self.vector_matrix_product, R);
 
         if (homomorphically_derived_commitment == product_commitment) && (dot_product == *opening) {
             Ok(())
@@ -318,6 +679,187 @@ impl<F: JoltField, G: CurveGroup<ScalarField = F>> HyraxOpeningProof<G> {
     }
 }
 
+/// Opening proof of a Hyrax commitment at a point `r` over an extension field `E`. Writing
+/// `f(r) = L^T Z R` with `L` and `R` in `E`, each coordinate `L_j` of `L` (over the base field)
+/// gets its own vector-matrix product `L_j^T Z`, checked against the commitment as in
+/// `HyraxOpeningProof`. The verifier then recombines them into `L^T Z` over `E` and takes the
+/// dot product with `R`. Proof size and the verifier's MSMs are `[E : F]` times those of
+/// `HyraxOpeningProof`.
+#[derive(Debug, CanonicalSerialize, CanonicalDeserialize)]
+pub struct HyraxExtOpeningProof<G: CurveGroup> {
+    /// `L_j^T Z` for each coordinate `j` of `E` over the base field
+    pub vector_matrix_products: Vec<Vec<G::ScalarField>>,
+}
+
+impl<F: JoltField, G: CurveGroup<ScalarField = F>> HyraxExtOpeningProof<G> {
+    fn protocol_name() -> &'static [u8] {
+        b"Hyrax extension opening proof"
+    }
+
+    #[tracing::instrument(skip_all, name = "HyraxExtOpeningProof::prove")]
+    pub fn prove<E: ExtensionField<F>>(
+        poly: &DensePolynomial<F>,
+        opening_point: &[E], // point at which the polynomial is evaluated
+        transcript: &mut ProofTranscript,
+    ) -> HyraxExtOpeningProof<G> {
+        transcript.append_protocol_name(Self::protocol_name());
+
+        // assert vectors are of the right size
+        assert_eq!(poly.get_num_vars(), opening_point.len());
+
+        // compute the L vector over E, and split it into its coordinates over F
+        let (L_size, _R_size) = matrix_dimensions(poly.get_num_vars(), 1);
+        let eq = EqPolynomial::new(opening_point.to_vec());
+        let (L, _R) = eq.compute_factored_evals(L_size);
+        let L_coeffs: Vec<Vec<F>> = L.iter().map(|l| l.to_base_coeffs()).collect();
+
+        let vector_matrix_products = (0..E::DEGREE)
+            .map(|j| {
+                let L_j: Vec<F> = L_coeffs.iter().map(|coeffs| coeffs[j]).collect();
+                HyraxOpeningProof::<G>::vector_matrix_product(poly, &L_j, 1)
+            })
+            .collect();
+
+        HyraxExtOpeningProof {
+            vector_matrix_products,
+        }
+    }
+
+    pub fn verify<E: ExtensionField<F>>(
+        &self,
+        pedersen_generators: &PedersenGenerators<G>,
+        transcript: &mut ProofTranscript,
+        opening_point: &[E], // point at which the polynomial is evaluated
+        opening: &E,         // evaluation \widetilde{Z}(r), lifted into E
+        commitment: &HyraxCommitment<G>,
+    ) -> Result<(), ProofVerifyError> {
+        if commitment.layout != HyraxLayout::RowMajor {
+            return Err(ProofVerifyError::InternalError);
+        }
+        transcript.append_protocol_name(Self::protocol_name());
+
+        let (L_size, R_size) = matrix_dimensions(opening_point.len(), 1);
+        if self.vector_matrix_products.len() != E::DEGREE {
+            return Err(ProofVerifyError::InvalidInputLength(
+                E::DEGREE,
+                self.vector_matrix_products.len(),
+            ));
+        }
+        if let Some(product) = self
+            .vector_matrix_products
+            .iter()
+            .find(|product| product.len() != R_size)
+        {
+            return Err(ProofVerifyError::InvalidInputLength(R_size, product.len()));
+        }
+
+        let eq = EqPolynomial::new(opening_point.to_vec());
+        let (L, R) = eq.compute_factored_evals(L_size);
+        let L_coeffs: Vec<Vec<F>> = L.iter().map(|l| l.to_base_coeffs()).collect();
+
+        let row_commitments = G::normalize_batch(&commitment.row_commitments);
+        let generators = G::normalize_batch(&pedersen_generators.generators[..R_size]);
+        for (j, product) in self.vector_matrix_products.iter().enumerate() {
+            let L_j: Vec<F> = L_coeffs.iter().map(|coeffs| coeffs[j]).collect();
+
+            // Verifier-derived commitment to L_j * Z = \prod Com(row_i)^{L_j[i]}
+            let homomorphically_derived_commitment: G =
+                VariableBaseMSM::msm(&row_commitments, &L_j).unwrap();
+            let product_commitment: G = VariableBaseMSM::msm(&generators, product).unwrap();
+            if homomorphically_derived_commitment != product_commitment {
+                return Err(ProofVerifyError::InternalError);
+            }
+        }
+
+        // Recombine L * Z over E and take its dot product with R
+        let dot_product: E = (0..R_size)
+            .map(|k| {
+                let coeffs: Vec<F> = self
+                    .vector_matrix_products
+                    .iter()
+                    .map(|product| product[k])
+                    .collect();
+                E::from_base_coeffs(&coeffs) * R[k]
+            })
+            .sum();
+
+        if dot_product == *opening {
+            Ok(())
+        } else {
+            Err(ProofVerifyError::InternalError)
+        }
+    }
+}
+
+/// Opening proof for a commitment produced by `HyraxCommitment::commit_hiding`. Note that
+/// while the commitment is hiding, this proof reveals the vector-matrix product L * Z; full
+/// zero-knowledge would additionally require replacing it with a dot-product proof.
+#[derive(Debug, CanonicalSerialize, CanonicalDeserialize)]
+pub struct HyraxHidingOpeningProof<G: CurveGroup> {
+    pub opening_proof: HyraxOpeningProof<G>,
+    /// The blind of the homomorphically derived commitment to L * Z, i.e. <L, row_blinds>.
+    pub combined_blind: G::ScalarField,
+}
+
+impl<F: JoltField, G: CurveGroup<ScalarField = F>> HyraxHidingOpeningProof<G> {
+    #[tracing::instrument(skip_all, name = "HyraxHidingOpeningProof::prove")]
+    pub fn prove(
+        poly: &DensePolynomial<G::ScalarField>,
+        blind: &HyraxBlind<G::ScalarField>,
+        opening_point: &[G::ScalarField], // point at which the polynomial is evaluated
+        transcript: &mut ProofTranscript,
+    ) -> Self {
+        // `commit_hiding` commits with a ratio of 1
+        let opening_proof = HyraxOpeningProof::prove(poly, opening_point, 1, transcript);
+
+        let (L_size, _R_size) = matrix_dimensions(poly.get_num_vars(), 1);
+        let (L, _R) = EqPolynomial::new(opening_point.to_vec()).compute_factored_evals(L_size);
+        let combined_blind = compute_dotproduct(&L, &blind.row_blinds);
+
+        Self {
+            opening_proof,
+            combined_blind,
+        }
+    }
+
+    pub fn verify(
+        &self,
+        pedersen_generators: &PedersenGenerators<G>,
+        blind_gen: &G,
+        transcript: &mut ProofTranscript,
+        opening_point: &[G::ScalarField], // point at which the polynomial is evaluated
+        opening: &G::ScalarField,         // evaluation \widetilde{Z}(r)
+        commitment: &HyraxCommitment<G>,
+    ) -> Result<(), ProofVerifyError> {
+        if commitment.layout != HyraxLayout::RowMajor {
+            return Err(ProofVerifyError::InternalError);
+        }
+        transcript.append_protocol_name(HyraxOpeningProof::<G>::protocol_name());
+
+        let (L_size, R_size) = matrix_dimensions(opening_point.len(), 1);
+        let (L, R) = EqPolynomial::new(opening_point.to_vec()).compute_factored_evals(L_size);
+
+        let homomorphically_derived_commitment: G =
+            VariableBaseMSM::msm(&G::normalize_batch(&commitment.row_commitments), &L).unwrap();
+
+        let vector_matrix_product = &self.opening_proof.vector_matrix_product;
+        let product_commitment = VariableBaseMSM::msm(
+            &G::normalize_batch(&pedersen_generators.generators[..R_size]),
+            vector_matrix_product,
+        )
+        .unwrap()
+            + *blind_gen * self.combined_blind;
+
+        let dot_product = compute_dotproduct(vector_matrix_product, &R);
+
+        if (homomorphically_derived_commitment == product_commitment) && (dot_product == *opening) {
+            Ok(())
+        } else {
+            Err(ProofVerifyError::InternalError)
+        }
+    }
+}
+
 #[derive(Debug, CanonicalSerialize, CanonicalDeserialize)]
 pub struct BatchedHyraxOpeningProof<G: CurveGroup> {
     pub joint_proof: HyraxOpeningProof<G>,
@@ -333,6 +875,26 @@ impl<F: JoltField, G: CurveGroup<ScalarField = F>> BatchedHyraxOpeningProof<G> {
         openings: &[G::ScalarField],
         batch_type: BatchType,
         transcript: &mut ProofTranscript,
+    ) -> Self {
+        Self::prove_with_layout(
+            polynomials,
+            opening_point,
+            openings,
+            batch_type,
+            HyraxLayout::RowMajor,
+            transcript,
+        )
+    }
+
+    /// Same as `prove`, but for polynomials committed to with the given matrix `layout`.
+    #[tracing::instrument(skip_all, name = "BatchedHyraxOpeningProof::prove_with_layout")]
+    pub fn prove_with_layout(
+        polynomials: &[&DensePolynomial<G::ScalarField>],
+        opening_point: &[G::ScalarField],
+        openings: &[G::ScalarField],
+        batch_type: BatchType,
+        layout: HyraxLayout,
+        transcript: &mut ProofTranscript,
     ) -> Self {
         transcript.append_protocol_name(Self::protocol_name());
 
@@ -388,10 +950,11 @@ impl<F: JoltField, G: CurveGroup<ScalarField = F>> BatchedHyraxOpeningProof<G> {
         drop(_span);
 
         let ratio = batch_type_to_ratio(&batch_type);
-        let joint_proof = HyraxOpeningProof::prove(
+        let joint_proof = HyraxOpeningProof::prove_with_layout(
             &DensePolynomial::new(rlc_poly),
             opening_point,
             ratio,
+            layout,
             transcript,
         );
 
@@ -406,10 +969,24 @@ impl<F: JoltField, G: CurveGroup<ScalarField = F>> BatchedHyraxOpeningProof<G> {
         commitments: &[&HyraxCommitment<G>],
         transcript: &mut ProofTranscript,
     ) -> Result<(), ProofVerifyError> {
-        let (L_size, _R_size) = matrix_dimensions(opening_point.len(), self.ratio);
+        // The joint proof is verified with the (common) layout of `commitments`
+        let layout = commitments
+            .first()
+            .map_or(HyraxLayout::RowMajor, |commitment| commitment.layout);
+        if commitments
+            .iter()
+            .any(|commitment| commitment.layout != layout)
+        {
+            return Err(ProofVerifyError::InternalError);
+        }
+        let (L_size, R_size) = matrix_dimensions(opening_point.len(), self.ratio);
+        let num_commitments = match layout {
+            HyraxLayout::RowMajor => L_size,
+            HyraxLayout::ColumnMajor => R_size,
+        };
         commitments.iter().enumerate().for_each(|(i, commitment)| {
             assert_eq!(
-                L_size,
+                num_commitments,
                 commitment.row_commitments.len(),
                 "Row commitment {}/{} wrong length.",
                 i,
@@ -438,7 +1015,7 @@ impl<F: JoltField, G: CurveGroup<ScalarField = F>> BatchedHyraxOpeningProof<G> {
                     .collect()
             })
             .reduce(
-                || vec![G::zero(); L_size],
+                || vec![G::zero(); num_commitments],
                 |running, new| {
                     debug_assert_eq!(running.len(), new.len());
                     running
@@ -449,13 +1026,14 @@ impl<F: JoltField, G: CurveGroup<ScalarField = F>> BatchedHyraxOpeningProof<G> {
                 },
             );
 
-        self.joint_proof.verify(
+        self.joint_proof.verify_with_layout(
             pedersen_generators,
             transcript,
             opening_point,
             &rlc_eval,
             &HyraxCommitment {
                 row_commitments: rlc_commitment,
+                layout,
             },
             self.ratio,
         )
@@ -469,6 +1047,8 @@ impl<F: JoltField, G: CurveGroup<ScalarField = F>> BatchedHyraxOpeningProof<G> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::poly::commitment::commitment_scheme::CommitmentGens;
+    use crate::poly::field::Fr2;
     use ark_bn254::{Fr, G1Projective};
 
     #[test]
@@ -517,4 +1097,515 @@ mod tests {
             )
             .is_ok());
     }
+
+    #[test]
+    fn commitment_gens_round_trip() {
+        type Hyrax = HyraxScheme<G1Projective>;
+
+        let num_vars: usize = 6;
+        let shapes = [CommitShape::new(num_vars.pow2(), BatchType::Small)];
+        let gens = Hyrax::gens(&shapes);
+        let mut bytes = vec![];
+        gens.save(&mut bytes).unwrap();
+        let loaded = CommitmentGens::<Hyrax>::load(&bytes[..]).unwrap();
+        assert_eq!(gens.setup().generators, loaded.setup().generators);
+
+        let seeded = CommitmentGens::<Hyrax>::from_seed(&shapes, b"Jolt v1 Hyrax generators");
+        assert_eq!(gens.setup().generators, seeded.setup().generators);
+        let other = CommitmentGens::<Hyrax>::from_seed(&shapes, b"other seed");
+        assert_ne!(gens.setup().generators, other.setup().generators);
+    }
+
+    #[test]
+    fn commitment_with_loaded_gens_verifies() {
+        type Hyrax = HyraxScheme<G1Projective>;
+
+        let mut rng = ark_std::test_rng();
+        let num_vars: usize = 6;
+        let prover_gens = Hyrax::gens(&[CommitShape::new(num_vars.pow2(), BatchType::Small)]);
+        let mut bytes = vec![];
+        prover_gens.save(&mut bytes).unwrap();
+
+        let poly =
+            DensePolynomial::new((0..num_vars.pow2()).map(|_| Fr::random(&mut rng)).collect());
+        let r: Vec<Fr> = (0..num_vars).map(|_| Fr::random(&mut rng)).collect();
+        let eval = poly.evaluate(&r);
+        let commitment = Hyrax::commit(&poly, prover_gens.setup());
+        let mut prover_transcript = ProofTranscript::new(b"example");
+        let proof = Hyrax::prove(&poly, &r, &mut prover_transcript);
+
+        let verifier_gens = CommitmentGens::<Hyrax>::load(&bytes[..]).unwrap();
+        let mut verifier_transcript = ProofTranscript::new(b"example");
+        assert!(Hyrax::verify(
+            &proof,
+            verifier_gens.setup(),
+            &mut verifier_transcript,
+            &r,
+            &eval,
+            &commitment,
+        )
+        .is_ok());
+
+        // Committing with the loaded generators yields the same commitment
+        let mut expected = vec![];
+        commitment.serialize_compressed(&mut expected).unwrap();
+        let mut actual = vec![];
+        Hyrax::commit(&poly, verifier_gens.setup())
+            .serialize_compressed(&mut actual)
+            .unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn verify_eval_raw_rejects_non_canonical() {
+        use ark_ff::{BigInteger, PrimeField};
+        type Hyrax = HyraxScheme<G1Projective>;
+
+        let to_bytes = |x: &Fr| -> [u8; 32] {
+            let mut bytes = vec![];
+            x.serialize_compressed(&mut bytes).unwrap();
+            bytes.try_into().unwrap()
+        };
+
+        let mut rng = ark_std::test_rng();
+        let num_vars: usize = 6;
+        let generators: PedersenGenerators<G1Projective> =
+            PedersenGenerators::new(1 << 8, b"test-two");
+        let poly =
+            DensePolynomial::new((0..num_vars.pow2()).map(|_| Fr::random(&mut rng)).collect());
+        let r: Vec<Fr> = (0..num_vars).map(|_| Fr::random(&mut rng)).collect();
+        let eval = poly.evaluate(&r);
+        let commitment = Hyrax::commit(&poly, &generators);
+        let mut prover_transcript = ProofTranscript::new(b"example");
+        let proof = Hyrax::prove(&poly, &r, &mut prover_transcript);
+
+        let point_bytes: Vec<[u8; 32]> = r.iter().map(to_bytes).collect();
+        let mut verifier_transcript = ProofTranscript::new(b"example");
+        let (point, value) = Hyrax::verify_eval_raw(
+            &proof,
+            &generators,
+            &mut verifier_transcript,
+            &point_bytes,
+            &to_bytes(&eval),
+            &commitment,
+        )
+        .unwrap();
+        assert_eq!(point, r);
+        assert_eq!(value, eval);
+
+        // `eval + modulus` encodes the same residue, but is not reduced
+        let mut unreduced = eval.into_bigint();
+        assert!(!unreduced.add_with_carry(&Fr::MODULUS));
+        let value_bytes: [u8; 32] = unreduced.to_bytes_le().try_into().unwrap();
+        let mut verifier_transcript = ProofTranscript::new(b"example");
+        assert!(matches!(
+            Hyrax::verify_eval_raw(
+                &proof,
+                &generators,
+                &mut verifier_transcript,
+                &point_bytes,
+                &value_bytes,
+                &commitment,
+            ),
+            Err(ProofVerifyError::NonCanonicalFieldElement(_))
+        ));
+    }
+
+    #[test]
+    fn verify_eval_returns_claim() {
+        type Hyrax = HyraxScheme<G1Projective>;
+
+        let mut rng = ark_std::test_rng();
+        let num_vars: usize = 6;
+        let generators: PedersenGenerators<G1Projective> =
+            PedersenGenerators::new(1 << 8, b"test-two");
+        let poly =
+            DensePolynomial::new((0..num_vars.pow2()).map(|_| Fr::random(&mut rng)).collect());
+        let r: Vec<Fr> = (0..num_vars).map(|_| Fr::random(&mut rng)).collect();
+        let eval = poly.evaluate(&r);
+
+        let mut prover_transcript = ProofTranscript::new(b"example");
+        let proof = Hyrax::prove(&poly, &r, &mut prover_transcript);
+        let mut verifier_transcript = ProofTranscript::new(b"example");
+        let (point, value) = Hyrax::verify_eval(
+            &proof,
+            &generators,
+            &mut verifier_transcript,
+            &r,
+            &eval,
+            &Hyrax::commit(&poly, &generators),
+        )
+        .unwrap();
+        assert_eq!(point, r);
+        assert_eq!(value, eval);
+
+        // Feed the validated claim into an opening of a scaled copy of `poly`
+        let two = Fr::from_u64(2).unwrap();
+        let scaled_poly = DensePolynomial::new(poly.evals_ref().iter().map(|x| two * x).collect());
+        let mut prover_transcript = ProofTranscript::new(b"example");
+        let scaled_proof = Hyrax::prove(&scaled_poly, &point, &mut prover_transcript);
+        let mut verifier_transcript = ProofTranscript::new(b"example");
+        assert!(Hyrax::verify(
+            &scaled_proof,
+            &generators,
+            &mut verifier_transcript,
+            &point,
+            &(two * value),
+            &Hyrax::commit(&scaled_poly, &generators),
+        )
+        .is_ok());
+
+        let mut verifier_transcript = ProofTranscript::new(b"example");
+        assert!(Hyrax::verify_eval(
+            &proof,
+            &generators,
+            &mut verifier_transcript,
+            &r,
+            &(eval + Fr::one()),
+            &Hyrax::commit(&poly, &generators),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn verify_with_precomputed_eq() {
+        let mut rng = ark_std::test_rng();
+        let num_vars: usize = 6;
+        // `HyraxCommitment::commit` uses a ratio of 1
+        let ratio = 1;
+        let r: Vec<Fr> = (0..num_vars).map(|_| Fr::random(&mut rng)).collect();
+        let (L_size, _) = matrix_dimensions(num_vars, ratio);
+        let eq = EqPolynomial::precompute_bound(r.clone(), L_size);
+
+        let generators: PedersenGenerators<G1Projective> =
+            PedersenGenerators::new(1 << 8, b"test-two");
+        for _ in 0..3 {
+            let poly =
+                DensePolynomial::new((0..num_vars.pow2()).map(|_| Fr::random(&mut rng)).collect());
+            let eval = poly.evaluate(&r);
+            let poly_commitment = HyraxCommitment::commit(&poly, &generators);
+
+            let mut prover_transcript = ProofTranscript::new(b"example");
+            let proof = HyraxOpeningProof::prove(&poly, &r, ratio, &mut prover_transcript);
+
+            for opening in [eval, eval + Fr::one()] {
+                let mut verifier_transcript = ProofTranscript::new(b"example");
+                let standard = proof.verify(
+                    &generators,
+                    &mut verifier_transcript,
+                    &r,
+                    &opening,
+                    &poly_commitment,
+                    ratio,
+                );
+                let mut precomputed_transcript = ProofTranscript::new(b"example");
+                let precomputed = proof.verify_with_precomputed_eq(
+                    &generators,
+                    &mut precomputed_transcript,
+                    &eq,
+                    &opening,
+                    &poly_commitment,
+                    ratio,
+                );
+                assert_eq!(standard.is_ok(), opening == eval);
+                assert_eq!(standard.is_ok(), precomputed.is_ok());
+            }
+        }
+
+        // Factored for a different matrix shape
+        let mismatched_eq = EqPolynomial::precompute_bound(r.clone(), L_size * 2);
+        let poly = DensePolynomial::new(vec![Fr::one(); num_vars.pow2()]);
+        let mut prover_transcript = ProofTranscript::new(b"example");
+        let proof = HyraxOpeningProof::prove(&poly, &r, ratio, &mut prover_transcript);
+        let mut verifier_transcript = ProofTranscript::new(b"example");
+        assert!(proof
+            .verify_with_precomputed_eq(
+                &generators,
+                &mut verifier_transcript,
+                &mismatched_eq,
+                &poly.evaluate(&r),
+                &HyraxCommitment::commit(&poly, &generators),
+                ratio
+            )
+            .is_err());
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn blind_zeroize() {
+        let mut rng = ark_std::test_rng();
+        let poly = DensePolynomial::new((0..1 << 6).map(|_| Fr::random(&mut rng)).collect());
+        let generators: PedersenGenerators<G1Projective> =
+            PedersenGenerators::new(1 << 8, b"test-two");
+        let blind_gen = PedersenGenerators::<G1Projective>::new(1, b"test-blind").generators[0];
+        let (_, mut blind) =
+            HyraxCommitment::commit_hiding(&poly, &generators, &blind_gen, &mut rng);
+        assert!(blind
+            .row_blinds
+            .iter()
+            .any(|row_blind| !row_blind.is_zero()));
+
+        // Reading the blinds after drop would be undefined behavior, so this checks the wipe
+        // that `Drop` performs
+        blind.zeroize();
+        assert!(!blind.row_blinds.is_empty());
+        assert!(blind.row_blinds.iter().all(|row_blind| row_blind.is_zero()));
+    }
+
+    #[test]
+    fn commit_hiding() {
+        let mut rng = ark_std::test_rng();
+        let num_vars: usize = 6;
+        let poly =
+            DensePolynomial::new((0..num_vars.pow2()).map(|_| Fr::random(&mut rng)).collect());
+        let r: Vec<Fr> = (0..num_vars).map(|_| Fr::random(&mut rng)).collect();
+        let eval = poly.evaluate(&r);
+
+        let generators: PedersenGenerators<G1Projective> =
+            PedersenGenerators::new(1 << 8, b"test-two");
+        let blind_gen = PedersenGenerators::<G1Projective>::new(1, b"test-blind").generators[0];
+
+        let (commitment, blind) =
+            HyraxCommitment::commit_hiding(&poly, &generators, &blind_gen, &mut rng);
+        let (other_commitment, _) =
+            HyraxCommitment::commit_hiding(&poly, &generators, &blind_gen, &mut rng);
+        assert_ne!(commitment.row_commitments, other_commitment.row_commitments);
+        assert_ne!(
+            commitment.row_commitments,
+            HyraxCommitment::commit(&poly, &generators).row_commitments
+        );
+
+        let mut prover_transcript = ProofTranscript::new(b"example");
+        let proof = HyraxHidingOpeningProof::prove(&poly, &blind, &r, &mut prover_transcript);
+
+        let mut verifier_transcript = ProofTranscript::new(b"example");
+        assert!(proof
+            .verify(
+                &generators,
+                &blind_gen,
+                &mut verifier_transcript,
+                &r,
+                &eval,
+                &commitment
+            )
+            .is_ok());
+
+        let mut verifier_transcript = ProofTranscript::new(b"example");
+        assert!(proof
+            .verify(
+                &generators,
+                &blind_gen,
+                &mut verifier_transcript,
+                &r,
+                &(eval + Fr::one()),
+                &commitment
+            )
+            .is_err());
+
+        // The unblinded opening proof does not verify against the hiding commitment
+        let mut verifier_transcript = ProofTranscript::new(b"example");
+        assert!(proof
+            .opening_proof
+            .verify(
+                &generators,
+                &mut verifier_transcript,
+                &r,
+                &eval,
+                &commitment,
+                1
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn check_polynomial_commit_layouts() {
+        check_polynomial_commit_layout_helper::<false>();
+        check_polynomial_commit_layout_helper::<true>();
+    }
+
+    fn check_polynomial_commit_layout_helper<const COLUMN_MAJOR: bool>() {
+        let mut rng = ark_std::test_rng();
+        // With an odd number of variables, the matrix has twice as many rows as columns
+        let num_vars: usize = 5;
+        let polys: Vec<DensePolynomial<Fr>> = (0..2)
+            .map(|_| {
+                DensePolynomial::new((0..num_vars.pow2()).map(|_| Fr::random(&mut rng)).collect())
+            })
+            .collect();
+        let r: Vec<Fr> = (0..num_vars).map(|_| Fr::random(&mut rng)).collect();
+        let evals: Vec<Fr> = polys.iter().map(|poly| poly.evaluate(&r)).collect();
+
+        let generators = HyraxScheme::<G1Projective, COLUMN_MAJOR>::setup(&[CommitShape::new(
+            num_vars.pow2(),
+            BatchType::Small,
+        )]);
+        let (L_size, R_size) = matrix_dimensions(num_vars, 1);
+        assert_eq!(
+            generators.generators.len(),
+            if COLUMN_MAJOR { L_size } else { R_size }
+        );
+
+        let commitment = HyraxScheme::<G1Projective, COLUMN_MAJOR>::commit(&polys[0], &generators);
+        assert_eq!(
+            commitment.layout,
+            HyraxScheme::<G1Projective, COLUMN_MAJOR>::LAYOUT
+        );
+        let mut prover_transcript = ProofTranscript::new(b"example");
+        let proof =
+            HyraxScheme::<G1Projective, COLUMN_MAJOR>::prove(&polys[0], &r, &mut prover_transcript);
+        let verify = |eval: &Fr, commitment: &HyraxCommitment<G1Projective>| {
+            let mut verifier_transcript = ProofTranscript::new(b"example");
+            HyraxScheme::<G1Projective, COLUMN_MAJOR>::verify(
+                &proof,
+                &generators,
+                &mut verifier_transcript,
+                &r,
+                eval,
+                commitment,
+            )
+        };
+        assert!(verify(&evals[0], &commitment).is_ok());
+        assert!(verify(&(evals[0] + Fr::one()), &commitment).is_err());
+
+        // A commitment claiming the other layout is rejected
+        let mut mislabeled = commitment.clone();
+        mislabeled.layout = match commitment.layout {
+            HyraxLayout::RowMajor => HyraxLayout::ColumnMajor,
+            HyraxLayout::ColumnMajor => HyraxLayout::RowMajor,
+        };
+        assert!(verify(&evals[0], &mislabeled).is_err());
+
+        let poly_refs: Vec<&DensePolynomial<Fr>> = polys.iter().collect();
+        let commitments = HyraxScheme::<G1Projective, COLUMN_MAJOR>::batch_commit_polys_ref(
+            &poly_refs,
+            &generators,
+            BatchType::Small,
+        );
+        let mut prover_transcript = ProofTranscript::new(b"example");
+        let batch_proof = HyraxScheme::<G1Projective, COLUMN_MAJOR>::batch_prove(
+            &poly_refs,
+            &r,
+            &evals,
+            BatchType::Small,
+            &mut prover_transcript,
+        );
+        let mut verifier_transcript = ProofTranscript::new(b"example");
+        assert!(HyraxScheme::<G1Projective, COLUMN_MAJOR>::batch_verify(
+            &batch_proof,
+            &generators,
+            &r,
+            &evals,
+            &commitments.iter().collect::<Vec<_>>(),
+            &mut verifier_transcript,
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn poly_equality() {
+        type Hyrax = HyraxScheme<G1Projective>;
+
+        let mut rng = ark_std::test_rng();
+        let num_vars: usize = 6;
+        let generators: PedersenGenerators<G1Projective> =
+            PedersenGenerators::new(1 << 8, b"test-two");
+        let a = DensePolynomial::new((0..num_vars.pow2()).map(|_| Fr::random(&mut rng)).collect());
+        let comm_a = Hyrax::commit(&a, &generators);
+
+        // Equal polynomials, committed separately
+        let b = a.clone();
+        let comm_b = Hyrax::commit(&b, &generators);
+        let mut prover_transcript = ProofTranscript::new(b"example");
+        prover_transcript.enable_trace();
+        let proof = Hyrax::prove_poly_equality(&comm_a, &comm_b, &a, &b, &mut prover_transcript);
+        let mut verifier_transcript = ProofTranscript::new(b"example");
+        assert!(Hyrax::verify_poly_equality(
+            &proof,
+            &generators,
+            &comm_a,
+            &comm_b,
+            num_vars,
+            &mut verifier_transcript
+        )
+        .is_ok());
+
+        // A single challenge point is drawn, at which both polynomials are opened
+        let challenges = prover_transcript.trace().unwrap().challenges::<Fr>();
+        assert_eq!(challenges.len(), 1);
+        assert_eq!(challenges[0].0, b"equality_point");
+        assert_eq!(challenges[0].1.len(), num_vars);
+
+        // Polynomials differing in a single evaluation
+        let mut c_evals = a.evals();
+        c_evals[3] += Fr::one();
+        let c = DensePolynomial::new(c_evals);
+        let comm_c = Hyrax::commit(&c, &generators);
+        let mut prover_transcript = ProofTranscript::new(b"example");
+        let proof = Hyrax::prove_poly_equality(&comm_a, &comm_c, &a, &c, &mut prover_transcript);
+        let mut verifier_transcript = ProofTranscript::new(b"example");
+        assert!(Hyrax::verify_poly_equality(
+            &proof,
+            &generators,
+            &comm_a,
+            &comm_c,
+            num_vars,
+            &mut verifier_transcript
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn prove_eval_ext() {
+        type Hyrax = HyraxScheme<G1Projective>;
+
+        let mut rng = ark_std::test_rng();
+        let num_vars: usize = 6;
+        let generators: PedersenGenerators<G1Projective> =
+            PedersenGenerators::new(1 << 8, b"test-two");
+        let poly =
+            DensePolynomial::new((0..num_vars.pow2()).map(|_| Fr::random(&mut rng)).collect());
+        let commitment = Hyrax::commit(&poly, &generators);
+        let lifted = DensePolynomial::new(
+            poly.evals_ref()
+                .iter()
+                .map(|&coeff| Fr2::from_base(coeff))
+                .collect(),
+        );
+
+        // Lifting agrees with the base field at base field points
+        let base_point: Vec<Fr> = (0..num_vars).map(|_| Fr::random(&mut rng)).collect();
+        let embedded_point: Vec<Fr2> = base_point.iter().map(|&r| Fr2::from_base(r)).collect();
+        assert_eq!(
+            lifted.evaluate(&embedded_point),
+            Fr2::from_base(poly.evaluate(&base_point))
+        );
+
+        let point: Vec<Fr2> = (0..num_vars).map(|_| Fr2::random(&mut rng)).collect();
+        let opening = lifted.evaluate(&point);
+        let mut prover_transcript = ProofTranscript::new(b"example");
+        let proof = Hyrax::prove_eval_ext(&poly, &point, &mut prover_transcript);
+        assert_eq!(proof.vector_matrix_products.len(), 2);
+
+        let mut verifier_transcript = ProofTranscript::new(b"example");
+        assert!(Hyrax::verify_eval_ext(
+            &proof,
+            &generators,
+            &mut verifier_transcript,
+            &point,
+            &opening,
+            &commitment
+        )
+        .is_ok());
+
+        let mut verifier_transcript = ProofTranscript::new(b"example");
+        assert!(Hyrax::verify_eval_ext(
+            &proof,
+            &generators,
+            &mut verifier_transcript,
+            &point,
+            &(opening + Fr2::one()),
+            &commitment
+        )
+        .is_err());
+    }
 }
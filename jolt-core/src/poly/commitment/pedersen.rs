@@ -7,7 +7,10 @@ use sha3::Shake256;
 use std::io::Read;
 
 use crate::msm::VariableBaseMSM;
+use crate::utils::errors::ProofVerifyError;
 
+/// Generators are sampled deterministically from `label`, and their order is canonical:
+/// the i-th input of a vector commitment is always paired with `generators[i]`.
 #[derive(Clone, CanonicalSerialize, CanonicalDeserialize)]
 pub struct PedersenGenerators<G: CurveGroup> {
     pub generators: Vec<G>,
@@ -52,6 +55,9 @@ impl<G: CurveGroup> PedersenGenerators<G> {
 pub trait PedersenCommitment<G: CurveGroup>: Sized {
     fn commit(&self, gens: &PedersenGenerators<G>) -> G;
     fn commit_vector(inputs: &[Self], bases: &[G::Affine]) -> G;
+    /// Same as `commit_vector`, but returns an error if the number of `bases` does not match
+    /// the number of `inputs`.
+    fn try_commit_vector(inputs: &[Self], bases: &[G::Affine]) -> Result<G, ProofVerifyError>;
 }
 
 impl<G: CurveGroup> PedersenCommitment<G> for G::ScalarField {
@@ -65,4 +71,71 @@ impl<G: CurveGroup> PedersenCommitment<G> for G::ScalarField {
         assert_eq!(bases.len(), inputs.len());
         VariableBaseMSM::msm(bases, inputs).unwrap()
     }
+
+    fn try_commit_vector(inputs: &[Self], bases: &[G::Affine]) -> Result<G, ProofVerifyError> {
+        VariableBaseMSM::msm(bases, inputs)
+            .map_err(|_| ProofVerifyError::InvalidInputLength(bases.len(), inputs.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::{Fr, G1Projective};
+    use ark_std::{test_rng, UniformRand};
+
+    /// Commits to `inputs` using `generators` as given, and again using `generators` under
+    /// `permutation`.
+    fn commit_with_permuted_generators<G: CurveGroup>(
+        inputs: &[G::ScalarField],
+        generators: &PedersenGenerators<G>,
+        permutation: &[usize],
+    ) -> (G, G) {
+        let bases = G::normalize_batch(&generators.generators);
+        let permuted_bases: Vec<G::Affine> = permutation.iter().map(|&i| bases[i]).collect();
+        (
+            PedersenCommitment::commit_vector(inputs, &bases),
+            PedersenCommitment::commit_vector(inputs, &permuted_bases),
+        )
+    }
+
+    #[test]
+    fn generator_order_matters() {
+        let mut rng = test_rng();
+        let inputs: Vec<Fr> = (0..4).map(|_| Fr::rand(&mut rng)).collect();
+        let generators: PedersenGenerators<G1Projective> = PedersenGenerators::new(4, b"test");
+
+        let (commitment, permuted_commitment) =
+            commit_with_permuted_generators(&inputs, &generators, &[1, 0, 3, 2]);
+        assert_ne!(commitment, permuted_commitment);
+
+        // The identity permutation is the canonical ordering
+        let (commitment, identity_commitment) =
+            commit_with_permuted_generators(&inputs, &generators, &[0, 1, 2, 3]);
+        assert_eq!(commitment, identity_commitment);
+    }
+
+    #[test]
+    fn commit_generator_count_mismatch() {
+        let mut rng = test_rng();
+        let inputs: Vec<Fr> = (0..4).map(|_| Fr::rand(&mut rng)).collect();
+        let generators: PedersenGenerators<G1Projective> = PedersenGenerators::new(4, b"test");
+        let bases = G1Projective::normalize_batch(&generators.generators);
+
+        assert!(matches!(
+            <Fr as PedersenCommitment<G1Projective>>::try_commit_vector(&inputs, &bases[..3]),
+            Err(ProofVerifyError::InvalidInputLength(3, 4))
+        ));
+        assert!(matches!(
+            <Fr as PedersenCommitment<G1Projective>>::try_commit_vector(&inputs[..3], &bases),
+            Err(ProofVerifyError::InvalidInputLength(4, 3))
+        ));
+
+        let commitment =
+            <Fr as PedersenCommitment<G1Projective>>::try_commit_vector(&inputs, &bases).unwrap();
+        assert_eq!(
+            commitment,
+            <Fr as PedersenCommitment<G1Projective>>::commit_vector(&inputs, &bases)
+        );
+    }
 }
@@ -5,6 +5,7 @@ use crate::utils::{self, compute_dotproduct, compute_dotproduct_low_optimized};
 
 use crate::poly::field::JoltField;
 use crate::utils::math::Math;
+use ark_poly::DenseMultilinearExtension;
 use core::ops::Index;
 use rayon::prelude::*;
 use std::ops::AddAssign;
@@ -217,6 +218,93 @@ impl<F: JoltField> DensePolynomial<F> {
         compute_dotproduct(&self.Z, &chis)
     }
 
+    /// Returns the product of `polys` evaluated at `r`, computing the eq table for `r` once and
+    /// sharing it across all factors, which must therefore have the same number of variables.
+    pub fn evaluate_product(polys: &[&DensePolynomial<F>], r: &[F]) -> F {
+        let chis = EqPolynomial::new(r.to_vec()).evals();
+        polys
+            .par_iter()
+            .map(|poly| {
+                assert_eq!(poly.get_num_vars(), r.len());
+                poly.evaluate_at_chi(&chis)
+            })
+            .product()
+    }
+
+    /// Sums the polynomial's evaluations over the Boolean hypercube.
+    pub fn hypercube_sum(&self) -> F {
+        self.Z[..self.len].par_iter().sum()
+    }
+
+    /// Computes the inner product `sum_i a_i * b_i` of the two polynomials' evaluation vectors,
+    /// i.e. the sum over the Boolean hypercube of the pointwise product of `self` and `other`.
+    pub fn inner_product(&self, other: &Self) -> F {
+        assert_eq!(self.len(), other.len());
+        compute_dotproduct(&self.Z[..self.len], &other.Z[..other.len])
+    }
+
+    /// Sums out every variable but `var` over the Boolean hypercube, returning the resulting
+    /// degree-1 univariate polynomial in `var` as its evaluations at 0 and 1. Variable 0 is
+    /// the most significant, i.e. the one bound by `bound_poly_var_top`, so for `var = 0` this
+    /// is the first sumcheck round polynomial of `self`.
+    pub fn to_univariate_on_var(&self, var: usize) -> Vec<F> {
+        assert!(var < self.num_vars);
+        let shift = self.num_vars - 1 - var;
+        self.Z[..self.len]
+            .par_iter()
+            .enumerate()
+            .fold(
+                || vec![F::zero(); 2],
+                |mut evals, (i, z)| {
+                    evals[(i >> shift) & 1] += *z;
+                    evals
+                },
+            )
+            .reduce(|| vec![F::zero(); 2], |a, b| vec![a[0] + b[0], a[1] + b[1]])
+    }
+
+    /// Splits the polynomial into `num_workers` sub-polynomials for a distributed sumcheck.
+    /// With `k = log2(num_workers)`, chunk `w` holds the evaluations whose `k` least
+    /// significant index bits equal `w`, i.e. the restriction of `self` to the sub-cube whose
+    /// last `k` variables are the bits of `w`.
+    ///
+    /// Sumcheck binds variables from the top (see `bound_poly_var_top`), so its first
+    /// `num_vars - k` rounds only bind variables that every chunk retains. In each of those
+    /// rounds, worker `w` computes the round polynomial over its chunks (of every polynomial in
+    /// the sumcheck, chunked the same way); the round polynomial of the whole sumcheck is the
+    /// sum of the workers' polynomials, and each worker binds its chunks to the round's
+    /// challenge. Once each chunk is bound to a single evaluation, `from_distributed_chunks`
+    /// recombines them into the `k`-variate polynomial over which the remaining rounds run.
+    pub fn chunk_for_distribution(&self, num_workers: usize) -> Vec<Self> {
+        assert!(utils::is_power_of_two(num_workers));
+        assert!(num_workers <= self.len);
+        (0..num_workers)
+            .map(|w| {
+                Self::new(
+                    self.Z[..self.len]
+                        .iter()
+                        .skip(w)
+                        .step_by(num_workers)
+                        .cloned()
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+
+    /// Inverse of `chunk_for_distribution`: interleaves the (equally sized, possibly partially
+    /// bound) chunks back into a single polynomial, with chunk `w` supplying the evaluations
+    /// whose least significant index bits equal `w`.
+    pub fn from_distributed_chunks(chunks: &[Self]) -> Self {
+        let chunk_len = chunks[0].len();
+        assert!(chunks.iter().all(|chunk| chunk.len() == chunk_len));
+        Self::new(
+            (0..chunk_len)
+                .flat_map(|i| chunks.iter().map(move |chunk| chunk.Z[i]))
+                .collect(),
+        )
+    }
+
     pub fn evaluate_at_chi(&self, chis: &[F]) -> F {
         compute_dotproduct(&self.Z, chis)
     }
@@ -274,6 +362,24 @@ impl<F: JoltField> DensePolynomial<F> {
     }
 }
 
+/// Conversions to and from `ark_poly`'s multilinear extensions.
+///
+/// Both representations store the evaluations over the Boolean hypercube in the same order,
+/// but they disagree on which variable the index bits correspond to: `DensePolynomial` treats
+/// the first coordinate of an evaluation point as the most significant bit of the index, while
+/// `DenseMultilinearExtension` treats it as the least significant bit. The evaluations are
+/// therefore copied as-is and the evaluation point must be reversed, i.e.
+/// `poly.evaluate(r) == poly.to_ark_mle().evaluate(&r.iter().rev().cloned().collect())`.
+impl<F: JoltField + ark_ff::Field> DensePolynomial<F> {
+    pub fn to_ark_mle(&self) -> DenseMultilinearExtension<F> {
+        DenseMultilinearExtension::from_evaluations_slice(self.num_vars, &self.Z[..self.len])
+    }
+
+    pub fn from_ark_mle(mle: &DenseMultilinearExtension<F>) -> Self {
+        Self::new(mle.evaluations.clone())
+    }
+}
+
 impl<F: JoltField> Clone for DensePolynomial<F> {
     fn clone(&self) -> Self {
         Self::new(self.Z[0..self.len].to_vec())
@@ -312,6 +418,7 @@ impl<F: JoltField> AddAssign<&DensePolynomial<F>> for DensePolynomial<F> {
 #[cfg(test)]
 mod tests {
     use crate::poly::commitment::hyrax::matrix_dimensions;
+    use crate::utils::test_lib::assert_field_vecs_eq;
 
     use super::*;
     use ark_bn254::Fr;
@@ -339,6 +446,148 @@ mod tests {
         compute_dotproduct(&LZ, &R)
     }
 
+    /// Binds all variables of copies of `poly` to `point` one at a time, using each of the
+    /// variable-binding methods, and asserts that the resulting scalar matches
+    /// `poly.evaluate(point)`.
+    fn assert_bind_matches_eval<F: JoltField>(poly: &DensePolynomial<F>, point: &[F]) {
+        assert_eq!(point.len(), poly.get_num_vars());
+        let expected = poly.evaluate(point);
+
+        let bind_top: [(&str, fn(&mut DensePolynomial<F>, &F)); 4] = [
+            ("bound_poly_var_top", DensePolynomial::bound_poly_var_top),
+            (
+                "bound_poly_var_top_par",
+                DensePolynomial::bound_poly_var_top_par,
+            ),
+            (
+                "bound_poly_var_top_many_ones",
+                DensePolynomial::bound_poly_var_top_many_ones,
+            ),
+            (
+                "bound_poly_var_top_zero_optimized",
+                DensePolynomial::bound_poly_var_top_zero_optimized,
+            ),
+        ];
+        for (name, bind) in bind_top {
+            let mut bound = poly.clone();
+            let mut reference = poly.clone();
+            for r in point {
+                bind(&mut bound, r);
+                // Every method must fold the evaluations the same way as the sequential one
+                reference.bound_poly_var_top(r);
+                assert_field_vecs_eq(
+                    &bound.evals_ref()[..bound.len()],
+                    &reference.evals_ref()[..reference.len()],
+                );
+            }
+            assert_eq!(bound.len(), 1, "{} left unbound variables", name);
+            assert_eq!(
+                bound.evals_ref()[0],
+                expected,
+                "{} disagrees with evaluate",
+                name
+            );
+        }
+
+        let mut bound = poly.clone();
+        for r in point {
+            bound = bound.new_poly_from_bound_poly_var_top(r);
+        }
+        assert_eq!(
+            bound.evals_ref()[0],
+            expected,
+            "new_poly_from_bound_poly_var_top disagrees with evaluate"
+        );
+
+        // The bottom variable is the last coordinate of `point`
+        let mut bound = poly.clone();
+        for r in point.iter().rev() {
+            bound.bound_poly_var_bot(r);
+        }
+        assert_eq!(
+            bound.evals_ref()[0],
+            expected,
+            "bound_poly_var_bot disagrees with evaluate"
+        );
+    }
+
+    #[test]
+    fn to_univariate_on_var() {
+        use crate::subprotocols::sumcheck::SumcheckInstanceProof;
+        use crate::utils::transcript::ProofTranscript;
+
+        let mut rng = test_rng();
+        let num_vars = 5;
+        let poly = DensePolynomial::new((0..1 << num_vars).map(|_| Fr::random(&mut rng)).collect());
+        let sum = poly.hypercube_sum();
+
+        for var in 0..num_vars {
+            let univariate = poly.to_univariate_on_var(var);
+            assert_eq!(univariate.len(), 2);
+            assert_eq!(univariate[0] + univariate[1], sum);
+
+            // Fixing `var` to 0 or 1 and summing the rest directly
+            let expected_zero: Fr = (0..1 << num_vars)
+                .filter(|i| (i >> (num_vars - 1 - var)) & 1 == 0)
+                .map(|i| poly[i])
+                .sum();
+            assert_eq!(univariate[0], expected_zero);
+        }
+
+        // Run only the first sumcheck round: the verifier's resulting claim is the first round
+        // polynomial evaluated at the round's challenge, which must agree with the linear
+        // interpolation of `to_univariate_on_var(0)`.
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let (proof, _, _) = SumcheckInstanceProof::prove_arbitrary(
+            &sum,
+            1,
+            &mut vec![poly.clone()],
+            |vals: &[Fr]| vals[0],
+            1,
+            &mut transcript,
+        );
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let (round_eval, r) = proof.verify(sum, 1, 1, &mut transcript).unwrap();
+        let univariate = poly.to_univariate_on_var(0);
+        assert_eq!(
+            round_eval,
+            (Fr::one() - r[0]) * univariate[0] + r[0] * univariate[1]
+        );
+    }
+
+    #[test]
+    fn inner_product() {
+        let mut rng = test_rng();
+        let num_vars = 5;
+        let a = DensePolynomial::new((0..1 << num_vars).map(|_| Fr::random(&mut rng)).collect());
+        let b = DensePolynomial::new((0..1 << num_vars).map(|_| Fr::random(&mut rng)).collect());
+
+        let expected: Fr = (0..1 << num_vars)
+            .map(|i| {
+                DensePolynomial::evaluate_product(
+                    &[&a, &b],
+                    &utils::index_to_field_bitvector(i, num_vars),
+                )
+            })
+            .sum();
+        assert_eq!(a.inner_product(&b), expected);
+        assert_eq!(b.inner_product(&a), expected);
+    }
+
+    #[test]
+    fn bind_matches_eval() {
+        let mut rng = test_rng();
+        for num_vars in 1..=8 {
+            for _ in 0..4 {
+                let poly = DensePolynomial::new(
+                    (0..1 << num_vars).map(|_| Fr::random(&mut rng)).collect(),
+                );
+                let point: Vec<Fr> = (0..num_vars).map(|_| Fr::random(&mut rng)).collect();
+                assert_bind_matches_eval(&poly, &point);
+            }
+        }
+    }
+
     #[test]
     fn check_polynomial_evaluation() {
         check_polynomial_evaluation_helper::<Fr>()
@@ -513,4 +762,117 @@ mod tests {
             Fr::from(8)
         );
     }
+
+    #[test]
+    fn ark_mle_round_trip() {
+        let mut rng = test_rng();
+        let poly = DensePolynomial::new((0..64).map(|_| Fr::random(&mut rng)).collect());
+        let mle = poly.to_ark_mle();
+        assert_eq!(mle.num_vars, poly.get_num_vars());
+        assert_eq!(DensePolynomial::from_ark_mle(&mle), poly);
+    }
+
+    #[test]
+    fn ark_mle_evaluation_agreement() {
+        use ark_poly::Polynomial;
+
+        let mut rng = test_rng();
+        let num_vars = 6;
+        let poly = DensePolynomial::new((0..1 << num_vars).map(|_| Fr::random(&mut rng)).collect());
+        let mle = poly.to_ark_mle();
+        for _ in 0..10 {
+            let r: Vec<Fr> = (0..num_vars).map(|_| Fr::random(&mut rng)).collect();
+            let reversed: Vec<Fr> = r.iter().rev().cloned().collect();
+            assert_eq!(poly.evaluate(&r), mle.evaluate(&reversed));
+        }
+    }
+
+    #[test]
+    fn evaluate_product() {
+        let mut rng = test_rng();
+        let num_vars = 5;
+        let polys: Vec<DensePolynomial<Fr>> = (0..3)
+            .map(|_| {
+                DensePolynomial::new((0..1 << num_vars).map(|_| Fr::random(&mut rng)).collect())
+            })
+            .collect();
+        let r: Vec<Fr> = (0..num_vars).map(|_| Fr::random(&mut rng)).collect();
+
+        let expected: Fr = polys.iter().map(|poly| poly.evaluate(&r)).product();
+        assert_eq!(
+            DensePolynomial::evaluate_product(&polys.iter().collect::<Vec<_>>(), &r),
+            expected
+        );
+    }
+
+    #[test]
+    fn distributed_sumcheck() {
+        // Evaluations at 0, 1 and 2 of the round polynomial of sum_x p(x) * q(x)
+        fn round_evals(p: &DensePolynomial<Fr>, q: &DensePolynomial<Fr>) -> [Fr; 3] {
+            let half = p.len() / 2;
+            let mut evals = [Fr::zero(); 3];
+            for i in 0..half {
+                let (p_0, p_1) = (p[i], p[half + i]);
+                let (q_0, q_1) = (q[i], q[half + i]);
+                evals[0] += p_0 * q_0;
+                evals[1] += p_1 * q_1;
+                evals[2] += (p_1 + p_1 - p_0) * (q_1 + q_1 - q_0);
+            }
+            evals
+        }
+
+        let mut rng = test_rng();
+        let num_vars = 6;
+        let num_workers = 4;
+        let mut p =
+            DensePolynomial::new((0..1 << num_vars).map(|_| Fr::random(&mut rng)).collect());
+        let mut q =
+            DensePolynomial::new((0..1 << num_vars).map(|_| Fr::random(&mut rng)).collect());
+        let r: Vec<Fr> = (0..num_vars).map(|_| Fr::random(&mut rng)).collect();
+        let expected_evals = (p.evaluate(&r), q.evaluate(&r));
+
+        let mut p_chunks = p.chunk_for_distribution(num_workers);
+        let mut q_chunks = q.chunk_for_distribution(num_workers);
+        assert_eq!(p_chunks.len(), num_workers);
+        assert_eq!(DensePolynomial::from_distributed_chunks(&p_chunks), p);
+
+        // Distributed rounds: the workers' round polynomials sum to the single-machine one
+        let num_distributed_rounds = num_vars - num_workers.log_2();
+        for r_j in r[..num_distributed_rounds].iter() {
+            let worker_evals: Vec<[Fr; 3]> = p_chunks
+                .iter()
+                .zip(q_chunks.iter())
+                .map(|(p_chunk, q_chunk)| round_evals(p_chunk, q_chunk))
+                .collect();
+            let combined: Vec<Fr> = (0..3)
+                .map(|k| worker_evals.iter().map(|evals| evals[k]).sum())
+                .collect();
+            assert_eq!(combined, round_evals(&p, &q).to_vec());
+
+            p.bound_poly_var_top(r_j);
+            q.bound_poly_var_top(r_j);
+            p_chunks
+                .iter_mut()
+                .for_each(|chunk| chunk.bound_poly_var_top(r_j));
+            q_chunks
+                .iter_mut()
+                .for_each(|chunk| chunk.bound_poly_var_top(r_j));
+        }
+
+        // Recombine the single evaluation each worker holds and run the remaining rounds
+        let mut p_rest = DensePolynomial::from_distributed_chunks(&p_chunks);
+        let mut q_rest = DensePolynomial::from_distributed_chunks(&q_chunks);
+        assert_eq!(p_rest.len(), num_workers);
+        for i in 0..num_workers {
+            assert_eq!((p_rest[i], q_rest[i]), (p[i], q[i]));
+        }
+        for r_j in r[num_distributed_rounds..].iter() {
+            assert_eq!(round_evals(&p_rest, &q_rest), round_evals(&p, &q));
+            p_rest.bound_poly_var_top(r_j);
+            q_rest.bound_poly_var_top(r_j);
+            p.bound_poly_var_top(r_j);
+            q.bound_poly_var_top(r_j);
+        }
+        assert_eq!((p_rest[0], q_rest[0]), expected_evals);
+    }
 }
@@ -7,18 +7,35 @@ pub struct EqPolynomial<F> {
     r: Vec<F>,
 }
 
+/// The factored evaluations (see `EqPolynomial::compute_factored_evals`) of eq(r, x) for a
+/// fixed point r. Built once with `EqPolynomial::precompute_bound` and reused across
+/// verifications against the same point.
+#[derive(Clone, Debug)]
+pub struct PrecomputedEq<F> {
+    pub point: Vec<F>,
+    pub L: Vec<F>,
+    pub R: Vec<F>,
+}
+
 const PARALLEL_THRESHOLD: usize = 16;
 
+/// Computes eq(r, x) = \prod_i (r_i * x_i + (1 - r_i) * (1 - x_i)) directly, in O(n) time,
+/// without building the 2^n table of `EqPolynomial::evals`.
+pub fn eq_eval<F: JoltField>(r: &[F], x: &[F]) -> F {
+    assert_eq!(r.len(), x.len());
+    r.iter()
+        .zip(x.iter())
+        .map(|(r_i, x_i)| *r_i * x_i + (F::one() - r_i) * (F::one() - x_i))
+        .product()
+}
+
 impl<F: JoltField> EqPolynomial<F> {
     pub fn new(r: Vec<F>) -> Self {
         EqPolynomial { r }
     }
 
     pub fn evaluate(&self, rx: &[F]) -> F {
-        assert_eq!(self.r.len(), rx.len());
-        (0..rx.len())
-            .map(|i| self.r[i] * rx[i] + (F::one() - self.r[i]) * (F::one() - rx[i]))
-            .product()
+        eq_eval(&self.r, rx)
     }
 
     #[tracing::instrument(skip_all, name = "EqPolynomial::evals")]
@@ -85,4 +102,32 @@ impl<F: JoltField> EqPolynomial<F> {
 
         (L, R)
     }
+
+    /// Binds eq to `point` ahead of time, caching its factored evaluations with an
+    /// `L_size`-length left factor.
+    pub fn precompute_bound(point: Vec<F>, L_size: usize) -> PrecomputedEq<F> {
+        let (L, R) = EqPolynomial::new(point.clone()).compute_factored_evals(L_size);
+        PrecomputedEq { point, L, R }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::index_to_field_bitvector;
+    use ark_bn254::Fr;
+    use ark_std::test_rng;
+
+    #[test]
+    fn eq_eval_matches_evals() {
+        let mut rng = test_rng();
+        for num_vars in 0..6 {
+            let r: Vec<Fr> = (0..num_vars).map(|_| Fr::random(&mut rng)).collect();
+            let evals = EqPolynomial::new(r.clone()).evals();
+            for (i, eval) in evals.iter().enumerate() {
+                let x: Vec<Fr> = index_to_field_bitvector(i, num_vars);
+                assert_eq!(eq_eval(&r, &x), *eval);
+            }
+        }
+    }
 }
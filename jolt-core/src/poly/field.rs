@@ -1,7 +1,7 @@
 use std::fmt::Debug;
 use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
 
-use ark_ff::PrimeField;
+use ark_ff::{Fp2, Fp2Config, MontFp, PrimeField};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ark_std::UniformRand;
 
@@ -89,3 +89,103 @@ impl JoltField for ark_bn254::Fr {
         ark_bn254::Fr::from_le_bytes_mod_order(bytes)
     }
 }
+
+/// A finite extension of the field `F`, whose elements are represented by their `DEGREE`
+/// coordinates in a fixed basis over `F`. Multiplying by an element of `F` (lifted using
+/// `from_base`) scales each coordinate independently.
+pub trait ExtensionField<F: JoltField>: JoltField {
+    const DEGREE: usize;
+
+    fn from_base(value: F) -> Self;
+    fn from_base_coeffs(coeffs: &[F]) -> Self;
+    fn to_base_coeffs(&self) -> Vec<F>;
+}
+
+impl ExtensionField<ark_bn254::Fr> for ark_bn254::Fr {
+    const DEGREE: usize = 1;
+
+    fn from_base(value: ark_bn254::Fr) -> Self {
+        value
+    }
+
+    fn from_base_coeffs(coeffs: &[ark_bn254::Fr]) -> Self {
+        assert_eq!(coeffs.len(), 1);
+        coeffs[0]
+    }
+
+    fn to_base_coeffs(&self) -> Vec<ark_bn254::Fr> {
+        vec![*self]
+    }
+}
+
+pub struct Fr2Config;
+
+impl Fp2Config for Fr2Config {
+    type Fp = ark_bn254::Fr;
+
+    // 5 generates the multiplicative group of Fr, so it is a quadratic non-residue.
+    const NONRESIDUE: ark_bn254::Fr = MontFp!("5");
+
+    const FROBENIUS_COEFF_FP2_C1: &'static [ark_bn254::Fr] = &[MontFp!("1"), MontFp!("-1")];
+}
+
+/// The quadratic extension `Fr[u] / (u^2 - 5)` of BN254's scalar field, with basis `(1, u)`.
+pub type Fr2 = Fp2<Fr2Config>;
+
+impl JoltField for Fr2 {
+    const NUM_BYTES: usize = 2 * <ark_bn254::Fr as JoltField>::NUM_BYTES;
+
+    fn random<R: rand_core::RngCore>(rng: &mut R) -> Self {
+        <Self as UniformRand>::rand(rng)
+    }
+
+    fn is_zero(&self) -> bool {
+        <Self as ark_std::Zero>::is_zero(self)
+    }
+
+    fn is_one(&self) -> bool {
+        <Self as ark_std::One>::is_one(self)
+    }
+
+    fn zero() -> Self {
+        <Self as ark_std::Zero>::zero()
+    }
+
+    fn one() -> Self {
+        <Self as ark_std::One>::one()
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Self::from(n))
+    }
+
+    fn square(&self) -> Self {
+        <Self as ark_ff::Field>::square(self)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), Self::NUM_BYTES);
+        let (c0, c1) = bytes.split_at(<ark_bn254::Fr as JoltField>::NUM_BYTES);
+        Self::new(
+            <ark_bn254::Fr as JoltField>::from_bytes(c0),
+            <ark_bn254::Fr as JoltField>::from_bytes(c1),
+        )
+    }
+}
+
+impl ExtensionField<ark_bn254::Fr> for Fr2 {
+    const DEGREE: usize = 2;
+
+    fn from_base(value: ark_bn254::Fr) -> Self {
+        Self::new(value, <ark_bn254::Fr as JoltField>::zero())
+    }
+
+    fn from_base_coeffs(coeffs: &[ark_bn254::Fr]) -> Self {
+        assert_eq!(coeffs.len(), 2);
+        Self::new(coeffs[0], coeffs[1])
+    }
+
+    fn to_base_coeffs(&self) -> Vec<ark_bn254::Fr> {
+        vec![self.c0, self.c1]
+    }
+}
@@ -1,5 +1,6 @@
 #![allow(dead_code)]
 use crate::poly::field::JoltField;
+use crate::utils::eval_uni_horner;
 use crate::utils::gaussian_elimination::gaussian_elimination;
 use crate::utils::transcript::{AppendToTranscript, ProofTranscript};
 use ark_serialize::*;
@@ -67,13 +68,7 @@ impl<F: JoltField> UniPoly<F> {
     }
 
     pub fn evaluate(&self, r: &F) -> F {
-        let mut eval = self.coeffs[0];
-        let mut power = *r;
-        for i in 1..self.coeffs.len() {
-            eval += power * self.coeffs[i];
-            power *= r;
-        }
-        eval
+        eval_uni_horner(&self.coeffs, r)
     }
 
     pub fn compress(&self) -> CompressedUniPoly<F> {
@@ -0,0 +1,210 @@
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+use crate::poly::commitment::commitment_scheme::{BatchType, CommitmentScheme};
+use crate::poly::dense_mlpoly::DensePolynomial;
+use crate::poly::field::JoltField;
+use crate::subprotocols::sumcheck::SumcheckInstanceProof;
+use crate::utils::errors::ProofVerifyError;
+use crate::utils::math::Math;
+use crate::utils::transcript::{AppendToTranscript, ProofTranscript};
+
+/// Proof that two committed vectors `a` and `b` have inner product `c`.
+///
+/// Viewing `a` and `b` as the evaluation tables of multilinear polynomials, `<a, b>` is the
+/// sum of `a(x) * b(x)` over the Boolean hypercube. The prover runs sumcheck on this product,
+/// reducing the claim to the evaluations `a(r)` and `b(r)` at the random point `r`, which are
+/// then proven with a single batched opening proof. Both commitments are absorbed into the
+/// transcript before any challenge is drawn, so the proof is bound to them.
+///
+/// The commitments to `a` and `b` are expected to be computed with
+/// `CS::batch_commit_polys(.., BatchType::Big)`, matching the batched opening proof.
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+pub struct DotProductProof<F: JoltField, CS: CommitmentScheme<Field = F>> {
+    sumcheck_proof: SumcheckInstanceProof<F>,
+    a_eval: F,
+    b_eval: F,
+    opening_proof: CS::BatchedProof,
+}
+
+impl<F: JoltField, CS: CommitmentScheme<Field = F>> DotProductProof<F, CS> {
+    /// Proves the inner product of `a` and `b`, committed to as `commitment_a` and
+    /// `commitment_b`, which must have the same power-of-two length.
+    /// Returns the proof along with the inner product `c`.
+    #[tracing::instrument(skip_all, name = "DotProductProof::prove")]
+    pub fn prove(
+        commitment_a: &CS::Commitment,
+        commitment_b: &CS::Commitment,
+        a: &DensePolynomial<F>,
+        b: &DensePolynomial<F>,
+        transcript: &mut ProofTranscript,
+    ) -> (Self, F) {
+        assert_eq!(a.len(), b.len());
+        transcript.append_protocol_name(Self::protocol_name());
+        commitment_a.append_to_transcript(b"commitment_a", transcript);
+        commitment_b.append_to_transcript(b"commitment_b", transcript);
+
+        let c = a.inner_product(b);
+        transcript.append_scalar(b"claim", &c);
+
+        let num_rounds = a.len().log_2();
+        let (sumcheck_proof, r, evals) = SumcheckInstanceProof::prove_arbitrary(
+            &c,
+            num_rounds,
+            &mut vec![a.clone(), b.clone()],
+            |vals: &[F]| vals[0] * vals[1],
+            2,
+            transcript,
+        );
+        let (a_eval, b_eval) = (evals[0], evals[1]);
+        transcript.append_scalars(b"evals", &[a_eval, b_eval]);
+
+        let opening_proof =
+            CS::batch_prove(&[a, b], &r, &[a_eval, b_eval], BatchType::Big, transcript);
+
+        (
+            Self {
+                sumcheck_proof,
+                a_eval,
+                b_eval,
+                opening_proof,
+            },
+            c,
+        )
+    }
+
+    /// Verifies that the vectors committed to by `commitment_a` and `commitment_b`, of length
+    /// `2^num_vars`, have inner product `c`.
+    pub fn verify(
+        generators: &CS::Setup,
+        commitment_a: &CS::Commitment,
+        commitment_b: &CS::Commitment,
+        num_vars: usize,
+        c: F,
+        proof: &Self,
+        transcript: &mut ProofTranscript,
+    ) -> Result<(), ProofVerifyError> {
+        transcript.append_protocol_name(Self::protocol_name());
+        commitment_a.append_to_transcript(b"commitment_a", transcript);
+        commitment_b.append_to_transcript(b"commitment_b", transcript);
+        transcript.append_scalar(b"claim", &c);
+
+        let (claim_last, r) = proof.sumcheck_proof.verify(c, num_vars, 2, transcript)?;
+        if proof.a_eval * proof.b_eval != claim_last {
+            return Err(ProofVerifyError::InternalError);
+        }
+        transcript.append_scalars(b"evals", &[proof.a_eval, proof.b_eval]);
+
+        CS::batch_verify(
+            &proof.opening_proof,
+            generators,
+            &r,
+            &[proof.a_eval, proof.b_eval],
+            &[commitment_a, commitment_b],
+            transcript,
+        )
+    }
+
+    fn protocol_name() -> &'static [u8] {
+        b"dot product proof"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::poly::commitment::commitment_scheme::CommitShape;
+    use crate::poly::commitment::hyrax::HyraxScheme;
+    use ark_bn254::{Fr, G1Projective};
+    use ark_std::test_rng;
+
+    type Hyrax = HyraxScheme<G1Projective>;
+
+    #[test]
+    fn dot_product_e2e() {
+        let mut rng = test_rng();
+        let num_vars = 6;
+        let a = DensePolynomial::new((0..1 << num_vars).map(|_| Fr::random(&mut rng)).collect());
+        let b = DensePolynomial::new((0..1 << num_vars).map(|_| Fr::random(&mut rng)).collect());
+
+        let generators = Hyrax::setup(&[CommitShape::new(1 << num_vars, BatchType::Big)]);
+        let commitments = Hyrax::batch_commit_polys_ref(&[&a, &b], &generators, BatchType::Big);
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let (proof, c) = DotProductProof::<Fr, Hyrax>::prove(
+            &commitments[0],
+            &commitments[1],
+            &a,
+            &b,
+            &mut transcript,
+        );
+        let expected: Fr = (0..1 << num_vars).map(|i| a[i] * b[i]).sum();
+        assert_eq!(c, expected);
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let result = DotProductProof::verify(
+            &generators,
+            &commitments[0],
+            &commitments[1],
+            num_vars,
+            c,
+            &proof,
+            &mut transcript,
+        );
+        assert!(result.is_ok(), "Verification failed: {:?}", result.err());
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        assert!(DotProductProof::verify(
+            &generators,
+            &commitments[0],
+            &commitments[1],
+            num_vars,
+            c + Fr::from(1u64),
+            &proof,
+            &mut transcript,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn dot_product_rejects_substituted_commitments() {
+        let mut rng = test_rng();
+        let num_vars = 4;
+        let polys: Vec<DensePolynomial<Fr>> = (0..3)
+            .map(|_| {
+                DensePolynomial::new((0..1 << num_vars).map(|_| Fr::random(&mut rng)).collect())
+            })
+            .collect();
+        let (a, b, other) = (&polys[0], &polys[1], &polys[2]);
+
+        let generators = Hyrax::setup(&[CommitShape::new(1 << num_vars, BatchType::Big)]);
+        let commitments =
+            Hyrax::batch_commit_polys_ref(&[a, b, other], &generators, BatchType::Big);
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let (proof, c) = DotProductProof::<Fr, Hyrax>::prove(
+            &commitments[0],
+            &commitments[1],
+            a,
+            b,
+            &mut transcript,
+        );
+
+        for (commitment_a, commitment_b) in [
+            (&commitments[2], &commitments[1]),
+            (&commitments[0], &commitments[2]),
+            (&commitments[1], &commitments[0]),
+        ] {
+            let mut transcript = ProofTranscript::new(b"test_transcript");
+            assert!(DotProductProof::verify(
+                &generators,
+                commitment_a,
+                commitment_b,
+                num_vars,
+                c,
+                &proof,
+                &mut transcript,
+            )
+            .is_err());
+        }
+    }
+}
@@ -3,6 +3,8 @@ use crate::poly::dense_mlpoly::DensePolynomial;
 use crate::poly::eq_poly::EqPolynomial;
 use crate::poly::field::JoltField;
 use crate::subprotocols::sumcheck::CubicSumcheckType;
+use crate::utils::errors::ProofVerifyError;
+use crate::utils::is_power_of_two;
 use crate::utils::math::Math;
 use crate::utils::mul_0_1_optimized;
 use crate::utils::transcript::ProofTranscript;
@@ -61,6 +63,27 @@ impl<F: JoltField> GrandProductCircuit<F> {
         }
     }
 
+    /// Same as `new`, but returns an error rather than panicking if the number of leaves is
+    /// not a power of two (and at least 2).
+    pub fn try_new(leaves: Vec<F>) -> Result<Self, ProofVerifyError> {
+        let num_leaves = leaves.len();
+        if num_leaves < 2 || !is_power_of_two(num_leaves) {
+            return Err(ProofVerifyError::InvalidInputLength(
+                std::cmp::max(num_leaves.next_power_of_two(), 2),
+                num_leaves,
+            ));
+        }
+        Ok(Self::new(&DensePolynomial::new(leaves)))
+    }
+
+    /// Same as `new`, but first pads the leaves with ones up to the next power of two, which
+    /// leaves the grand product unchanged.
+    pub fn new_padded(mut leaves: Vec<F>) -> Self {
+        let padded_len = std::cmp::max(leaves.len().next_power_of_two(), 2);
+        leaves.resize(padded_len, F::one());
+        Self::new(&DensePolynomial::new(leaves))
+    }
+
     #[tracing::instrument(skip_all, name = "GrandProductCircuit::new_split")]
     pub fn new_split(left_leaves: DensePolynomial<F>, right_leaves: DensePolynomial<F>) -> Self {
         let num_layers = left_leaves.len().log_2() + 1;
@@ -298,6 +321,11 @@ impl<F: JoltField> BatchedGrandProductArgument<F> {
         )
     }
 
+    /// Number of layers of the batched circuits, i.e. the log of the number of leaves.
+    pub fn num_layers(&self) -> usize {
+        self.proof.len()
+    }
+
     pub fn verify(
         &self,
         claims_prod_vec: &Vec<F>,
@@ -394,6 +422,278 @@ impl<F: JoltField> BatchedGrandProductArgument<F> {
     }
 }
 
+/// The function a layered circuit applies to combine two sibling nodes into their parent. Each
+/// node carries `WIDTH` field elements, and each layer of the circuit is proven with a sumcheck
+/// over `eq(r, x) * combine(left(x), right(x))`, so `combine` must be a polynomial of total
+/// degree at most `DEGREE` in the child values.
+pub trait LayerCombiner<F: JoltField>: Sync {
+    /// Number of field elements carried by each node.
+    const WIDTH: usize;
+    /// Total degree of `combine` in the (`2 * WIDTH`) child values.
+    const DEGREE: usize;
+
+    /// Combines the `WIDTH` values of two sibling nodes into the `WIDTH` values of their parent.
+    fn combine(&self, left: &[F], right: &[F]) -> Vec<F>;
+}
+
+/// The multiset product used by memory checking: each node is the product of its children, so
+/// the root is the grand product of the leaves.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProductCombiner;
+
+impl<F: JoltField> LayerCombiner<F> for ProductCombiner {
+    const WIDTH: usize = 1;
+    const DEGREE: usize = 2;
+
+    fn combine(&self, left: &[F], right: &[F]) -> Vec<F> {
+        vec![mul_0_1_optimized(&left[0], &right[0])]
+    }
+}
+
+/// A logUp-style fractional sum: each node is a fraction `(numerator, denominator)`, and
+/// `n_l / d_l + n_r / d_r = (n_l * d_r + n_r * d_l) / (d_l * d_r)`, so the root is the sum of the
+/// leaf fractions. Two multisets of fingerprints `a_i` are equal iff (w.h.p.) the sums of
+/// `1 / a_i` are, which yields an alternative to the grand product without any inversions.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FractionalSumCombiner;
+
+impl<F: JoltField> LayerCombiner<F> for FractionalSumCombiner {
+    const WIDTH: usize = 2;
+    const DEGREE: usize = 2;
+
+    fn combine(&self, left: &[F], right: &[F]) -> Vec<F> {
+        vec![left[0] * right[1] + right[0] * left[1], left[1] * right[1]]
+    }
+}
+
+/// Same as `GrandProductCircuit`, but with sibling nodes combined by `L` rather than
+/// multiplied. With `ProductCombiner`, this is the same circuit as `GrandProductCircuit`.
+#[derive(Debug, Clone)]
+pub struct LayeredCircuit<F: JoltField, L: LayerCombiner<F>> {
+    /// `left_vec[layer][k]` holds the `k`-th value of the left half of the nodes in `layer`.
+    left_vec: Vec<Vec<DensePolynomial<F>>>,
+    right_vec: Vec<Vec<DensePolynomial<F>>>,
+    combiner: L,
+}
+
+impl<F: JoltField, L: LayerCombiner<F>> LayeredCircuit<F, L> {
+    fn compute_layer(
+        combiner: &L,
+        inp_left: &[DensePolynomial<F>],
+        inp_right: &[DensePolynomial<F>],
+    ) -> (Vec<DensePolynomial<F>>, Vec<DensePolynomial<F>>) {
+        let len = inp_left[0].len() + inp_right[0].len();
+        let parents: Vec<Vec<F>> = (0..len / 2)
+            .map(|i| {
+                let left: Vec<F> = inp_left.iter().map(|poly| poly[i]).collect();
+                let right: Vec<F> = inp_right.iter().map(|poly| poly[i]).collect();
+                combiner.combine(&left, &right)
+            })
+            .collect();
+
+        (0..L::WIDTH)
+            .map(|k| {
+                (
+                    DensePolynomial::new(parents[..len / 4].iter().map(|p| p[k]).collect()),
+                    DensePolynomial::new(parents[len / 4..].iter().map(|p| p[k]).collect()),
+                )
+            })
+            .unzip()
+    }
+
+    /// Builds the circuit over `leaves`, which holds the `WIDTH` values of every leaf as one
+    /// polynomial per value.
+    pub fn new(leaves: &[DensePolynomial<F>], combiner: L) -> Self {
+        assert_eq!(leaves.len(), L::WIDTH);
+        let num_layers = leaves[0].len().log_2();
+        let mut left_vec: Vec<Vec<DensePolynomial<F>>> = Vec::with_capacity(num_layers);
+        let mut right_vec: Vec<Vec<DensePolynomial<F>>> = Vec::with_capacity(num_layers);
+
+        let (outp_left, outp_right): (Vec<DensePolynomial<F>>, Vec<DensePolynomial<F>>) =
+            leaves.iter().map(|poly| poly.split(poly.len() / 2)).unzip();
+        left_vec.push(outp_left);
+        right_vec.push(outp_right);
+
+        for i in 0..num_layers - 1 {
+            let (outp_left, outp_right) =
+                Self::compute_layer(&combiner, &left_vec[i], &right_vec[i]);
+            left_vec.push(outp_left);
+            right_vec.push(outp_right);
+        }
+
+        LayeredCircuit {
+            left_vec,
+            right_vec,
+            combiner,
+        }
+    }
+
+    /// The `WIDTH` values of the root.
+    pub fn evaluate(&self) -> Vec<F> {
+        let top = self.left_vec.len() - 1;
+        let left: Vec<F> = self.left_vec[top].iter().map(|poly| poly[0]).collect();
+        let right: Vec<F> = self.right_vec[top].iter().map(|poly| poly[0]).collect();
+        self.combiner.combine(&left, &right)
+    }
+}
+
+impl<F: JoltField> BatchedGrandProductArgument<F> {
+    /// Same as `prove`, but for circuits whose layers are combined by `L`. Claims about the `k`-th
+    /// value of circuit `i` are flattened to index `i * L::WIDTH + k`, both in the proof and in
+    /// the claims reduced at each layer. With `ProductCombiner`, this produces exactly the same
+    /// proof and transcript as `prove`.
+    #[tracing::instrument(skip_all, name = "BatchedGrandProductArgument.prove_layered")]
+    pub fn prove_layered<L: LayerCombiner<F>>(
+        circuits: Vec<LayeredCircuit<F, L>>,
+        transcript: &mut ProofTranscript,
+    ) -> (Self, Vec<F>) {
+        let width = L::WIDTH;
+        let combiner = &circuits[0].combiner;
+        let mut proof_layers: Vec<LayerProofBatched<F>> = Vec::new();
+        let mut claims_to_verify: Vec<F> = circuits
+            .iter()
+            .flat_map(|circuit| circuit.evaluate())
+            .collect();
+
+        let mut rand = Vec::new();
+        for layer_id in (0..circuits[0].left_vec.len()).rev() {
+            let coeff_vec: Vec<F> =
+                transcript.challenge_vector(b"rand_coeffs_next_layer", claims_to_verify.len());
+            let claim = (0..claims_to_verify.len())
+                .map(|i| claims_to_verify[i] * coeff_vec[i])
+                .sum();
+
+            // Sumcheck over [eq, lefts..., rights...], each flattened by circuit then value
+            let eq = DensePolynomial::new(EqPolynomial::<F>::new(rand.clone()).evals());
+            let num_rounds = eq.get_num_vars();
+            let mut polys = vec![eq];
+            for circuit in circuits.iter() {
+                polys.extend(circuit.left_vec[layer_id].iter().cloned());
+            }
+            for circuit in circuits.iter() {
+                polys.extend(circuit.right_vec[layer_id].iter().cloned());
+            }
+            let num_claims = claims_to_verify.len();
+            let comb_func = |vals: &[F]| -> F {
+                let (lefts, rights) = vals[1..].split_at(num_claims);
+                let combined: F = (0..num_claims / width)
+                    .map(|i| {
+                        let values = combiner.combine(
+                            &lefts[i * width..(i + 1) * width],
+                            &rights[i * width..(i + 1) * width],
+                        );
+                        (0..width)
+                            .map(|k| coeff_vec[i * width + k] * values[k])
+                            .sum::<F>()
+                    })
+                    .sum();
+                vals[0] * combined
+            };
+            let (proof, rand_prod, final_evals) = SumcheckInstanceProof::prove_arbitrary(
+                &claim,
+                num_rounds,
+                &mut polys,
+                comb_func,
+                L::DEGREE + 1,
+                transcript,
+            );
+
+            let claims_poly_A = final_evals[1..1 + num_claims].to_vec();
+            let claims_poly_B = final_evals[1 + num_claims..].to_vec();
+            for i in 0..num_claims {
+                transcript.append_scalar(b"claim_prod_left", &claims_poly_A[i]);
+                transcript.append_scalar(b"claim_prod_right", &claims_poly_B[i]);
+            }
+
+            let r_layer = transcript.challenge_scalar(b"challenge_r_layer");
+            claims_to_verify = (0..num_claims)
+                .map(|i| claims_poly_A[i] + r_layer * (claims_poly_B[i] - claims_poly_A[i]))
+                .collect::<Vec<F>>();
+
+            let mut ext = vec![r_layer];
+            ext.extend(rand_prod);
+            rand = ext;
+
+            proof_layers.push(LayerProofBatched {
+                proof,
+                claims_poly_A,
+                claims_poly_B,
+                combine_prod: true,
+            });
+        }
+
+        (
+            BatchedGrandProductArgument {
+                proof: proof_layers,
+            },
+            rand,
+        )
+    }
+
+    /// Verifies a proof produced by `prove_layered`. `root_claims` holds the `WIDTH` root values
+    /// of every circuit, flattened by circuit then value. Returns the claimed evaluations of the
+    /// leaf polynomials (in the same order) and the point at which they are evaluated.
+    pub fn verify_layered<L: LayerCombiner<F>>(
+        &self,
+        root_claims: &[F],
+        combiner: &L,
+        transcript: &mut ProofTranscript,
+    ) -> (Vec<F>, Vec<F>) {
+        let width = L::WIDTH;
+        let num_claims = root_claims.len();
+        assert_eq!(num_claims % width, 0);
+        let mut rand: Vec<F> = Vec::new();
+
+        let mut claims_to_verify = root_claims.to_vec();
+        for (num_rounds, layer) in self.proof.iter().enumerate() {
+            let coeff_vec = transcript.challenge_vector::<F>(b"rand_coeffs_next_layer", num_claims);
+            let claim = (0..num_claims)
+                .map(|i| claims_to_verify[i] * coeff_vec[i])
+                .sum();
+
+            let (claim_last, rand_prod) =
+                layer.verify(claim, num_rounds, L::DEGREE + 1, transcript);
+
+            let claims_left = &layer.claims_poly_A;
+            let claims_right = &layer.claims_poly_B;
+            assert_eq!(claims_left.len(), num_claims);
+            assert_eq!(claims_right.len(), num_claims);
+            for i in 0..num_claims {
+                transcript.append_scalar(b"claim_prod_left", &claims_left[i]);
+                transcript.append_scalar(b"claim_prod_right", &claims_right[i]);
+            }
+
+            assert_eq!(rand.len(), rand_prod.len());
+            let eq: F = (0..rand.len())
+                .map(|i| rand[i] * rand_prod[i] + (F::one() - rand[i]) * (F::one() - rand_prod[i]))
+                .product();
+            let claim_expected: F = (0..num_claims / width)
+                .map(|i| {
+                    let values = combiner.combine(
+                        &claims_left[i * width..(i + 1) * width],
+                        &claims_right[i * width..(i + 1) * width],
+                    );
+                    (0..width)
+                        .map(|k| coeff_vec[i * width + k] * values[k])
+                        .sum::<F>()
+                })
+                .sum::<F>()
+                * eq;
+            assert_eq!(claim_expected, claim_last);
+
+            let r_layer = transcript.challenge_scalar(b"challenge_r_layer");
+            claims_to_verify = (0..num_claims)
+                .map(|i| claims_left[i] + r_layer * (claims_right[i] - claims_left[i]))
+                .collect::<Vec<F>>();
+
+            let mut ext = vec![r_layer];
+            ext.extend(rand_prod);
+            rand = ext;
+        }
+        (claims_to_verify, rand)
+    }
+}
+
 #[cfg(test)]
 mod grand_product_circuit_tests {
     use super::*;
@@ -416,6 +716,47 @@ mod grand_product_circuit_tests {
         proof.verify(&expected_eval, &mut transcript);
     }
 
+    #[test]
+    fn try_new_non_power_of_two() {
+        let leaves = vec![Fr::from(1), Fr::from(2), Fr::from(3)];
+        assert!(matches!(
+            GrandProductCircuit::try_new(leaves),
+            Err(ProofVerifyError::InvalidInputLength(4, 3))
+        ));
+        assert!(matches!(
+            GrandProductCircuit::<Fr>::try_new(vec![Fr::from(5)]),
+            Err(ProofVerifyError::InvalidInputLength(2, 1))
+        ));
+
+        let circuit = GrandProductCircuit::try_new(vec![Fr::from(3), Fr::from(4)]).unwrap();
+        assert_eq!(circuit.evaluate(), Fr::from(12));
+    }
+
+    #[test]
+    fn new_padded() {
+        let leaves = vec![
+            Fr::from(1),
+            Fr::from(2),
+            Fr::from(3),
+            Fr::from(4),
+            Fr::from(5),
+        ];
+        let circuit = GrandProductCircuit::new_padded(leaves);
+        assert_eq!(circuit.evaluate(), Fr::from(120));
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let batch = BatchedGrandProductCircuit::new_batch(vec![circuit]);
+        let (proof, _) = BatchedGrandProductArgument::prove(batch, &mut transcript);
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        proof.verify(&vec![Fr::from(120)], &mut transcript);
+
+        assert_eq!(
+            GrandProductCircuit::new_padded(vec![Fr::from(7)]).evaluate(),
+            Fr::from(7)
+        );
+    }
+
     #[test]
     fn gp_unflagged() {
         // Fundamentally grand products performs a multi-set check, so skip fingerprinting and all that, construct GP circuits directly
@@ -527,4 +868,96 @@ mod grand_product_circuit_tests {
         assert_eq!(verify_claims[0], verifier_read_eval);
         assert_eq!(verify_claims[1], verifier_write_eval);
     }
+
+    #[test]
+    fn layered_product_matches_grand_product() {
+        let leaves: Vec<Fr> = (1..=8).map(Fr::from).collect();
+        let other_leaves: Vec<Fr> = (11..=18).map(Fr::from).collect();
+        let leaf_polys = [
+            DensePolynomial::new(leaves.clone()),
+            DensePolynomial::new(other_leaves.clone()),
+        ];
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let batch = BatchedGrandProductCircuit::new_batch(
+            leaf_polys.iter().map(GrandProductCircuit::new).collect(),
+        );
+        let (expected_proof, expected_rand) =
+            BatchedGrandProductArgument::<Fr>::prove(batch, &mut transcript);
+
+        let layered: Vec<_> = leaf_polys
+            .iter()
+            .map(|poly| LayeredCircuit::new(std::slice::from_ref(poly), ProductCombiner))
+            .collect();
+        let roots: Vec<Fr> = layered.iter().flat_map(|c| c.evaluate()).collect();
+        assert_eq!(roots[0], leaves.iter().product::<Fr>());
+        assert_eq!(roots[1], other_leaves.iter().product::<Fr>());
+
+        let mut layered_transcript = ProofTranscript::new(b"test_transcript");
+        let (proof, rand) =
+            BatchedGrandProductArgument::prove_layered(layered, &mut layered_transcript);
+        assert_eq!(rand, expected_rand);
+        let mut expected_bytes = vec![];
+        expected_proof
+            .serialize_compressed(&mut expected_bytes)
+            .unwrap();
+        let mut bytes = vec![];
+        proof.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes, expected_bytes);
+        assert_eq!(
+            layered_transcript.challenge_scalar::<Fr>(b"next"),
+            transcript.challenge_scalar::<Fr>(b"next")
+        );
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let (claims, verify_rand) = proof.verify_layered(&roots, &ProductCombiner, &mut transcript);
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let (expected_claims, _) = expected_proof.verify(&roots, &mut transcript);
+        assert_eq!(verify_rand, rand);
+        assert_eq!(claims, expected_claims);
+    }
+
+    #[test]
+    fn layered_fractional_sum() {
+        // The same multiset, in a different order, as read and write fingerprints
+        let read_fingerprints: Vec<Fr> = [3, 5, 7, 5].into_iter().map(Fr::from).collect();
+        let write_fingerprints: Vec<Fr> = [5, 7, 5, 3].into_iter().map(Fr::from).collect();
+
+        let circuits: Vec<_> = [&read_fingerprints, &write_fingerprints]
+            .into_iter()
+            .map(|fingerprints| {
+                let numerators = DensePolynomial::new(vec![Fr::one(); fingerprints.len()]);
+                let denominators = DensePolynomial::new(fingerprints.clone());
+                LayeredCircuit::new(&[numerators, denominators], FractionalSumCombiner)
+            })
+            .collect();
+        let roots: Vec<Fr> = circuits.iter().flat_map(|c| c.evaluate()).collect();
+        // n_read / d_read == n_write / d_write
+        assert_eq!(roots[0] * roots[3], roots[2] * roots[1]);
+        let expected_sum: Fr = read_fingerprints
+            .iter()
+            .map(|f| ark_ff::Field::inverse(f).unwrap())
+            .sum();
+        assert_eq!(roots[0] / roots[1], expected_sum);
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let (proof, prove_rand) =
+            BatchedGrandProductArgument::prove_layered(circuits, &mut transcript);
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let (claims, verify_rand) =
+            proof.verify_layered(&roots, &FractionalSumCombiner, &mut transcript);
+        assert_eq!(prove_rand, verify_rand);
+        assert_eq!(claims.len(), 4);
+        assert_eq!(claims[0], Fr::one());
+        assert_eq!(
+            claims[1],
+            DensePolynomial::new(read_fingerprints).evaluate(&verify_rand)
+        );
+        assert_eq!(claims[2], Fr::one());
+        assert_eq!(
+            claims[3],
+            DensePolynomial::new(write_fingerprints).evaluate(&verify_rand)
+        );
+    }
 }
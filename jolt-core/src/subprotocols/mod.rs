@@ -1,4 +1,5 @@
 #![allow(clippy::too_many_arguments)]
 
+pub mod dot_product;
 pub mod grand_product;
 pub mod sumcheck;
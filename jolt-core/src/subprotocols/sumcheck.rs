@@ -12,6 +12,7 @@ use crate::utils::transcript::{AppendToTranscript, ProofTranscript};
 use ark_serialize::*;
 use itertools::multizip;
 use rayon::prelude::*;
+use std::time::{Duration, Instant};
 use tracing::trace_span;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -163,10 +164,106 @@ impl<F: JoltField> SumcheckInstanceProof<F> {
     where
         Func: Fn(&[F]) -> F + std::marker::Sync,
     {
+        Self::prove_arbitrary_inner(
+            None,
+            num_rounds,
+            polys,
+            comb_func,
+            combined_degree,
+            transcript,
+            None,
+        )
+    }
+
+    /// Same as `prove_arbitrary`, but proves the supplied `claim`, e.g. an opening produced by an
+    /// outer argument, rather than the hypercube sum: each round polynomial's evaluation at 1 is
+    /// derived from the running claim, as the verifier does when decompressing it. In debug
+    /// builds, asserts that `claim` is the hypercube sum of `comb_func` over `polys`; otherwise
+    /// an incorrect claim yields a proof that fails the verifier's final oracle check.
+    #[tracing::instrument(skip_all, name = "Sumcheck.prove_with_claim")]
+    pub fn prove_with_claim<Func>(
+        claim: &F,
+        num_rounds: usize,
+        polys: &mut Vec<DensePolynomial<F>>,
+        comb_func: Func,
+        combined_degree: usize,
+        transcript: &mut ProofTranscript,
+    ) -> (Self, Vec<F>, Vec<F>)
+    where
+        Func: Fn(&[F]) -> F + std::marker::Sync,
+    {
+        #[cfg(debug_assertions)]
+        {
+            let hypercube_sum: F = (0..polys[0].len())
+                .into_par_iter()
+                .map(|i| comb_func(&polys.iter().map(|poly| poly[i]).collect::<Vec<F>>()))
+                .sum();
+            debug_assert_eq!(
+                *claim, hypercube_sum,
+                "claim does not equal the hypercube sum"
+            );
+        }
+
+        Self::prove_arbitrary_inner(
+            Some(*claim),
+            num_rounds,
+            polys,
+            comb_func,
+            combined_degree,
+            transcript,
+            None,
+        )
+    }
+
+    /// Same as `prove_arbitrary`, but additionally returns the wall-clock time the prover spent
+    /// in each round. Early rounds operate on the largest tables and typically dominate.
+    #[cfg(feature = "profiling")]
+    #[tracing::instrument(skip_all, name = "Sumcheck.prove_timed")]
+    pub fn prove_arbitrary_timed<Func>(
+        _claim: &F,
+        num_rounds: usize,
+        polys: &mut Vec<DensePolynomial<F>>,
+        comb_func: Func,
+        combined_degree: usize,
+        transcript: &mut ProofTranscript,
+    ) -> (Self, Vec<F>, Vec<F>, Vec<Duration>)
+    where
+        Func: Fn(&[F]) -> F + std::marker::Sync,
+    {
+        let mut round_durations = Vec::with_capacity(num_rounds);
+        let (proof, r, final_evals) = Self::prove_arbitrary_inner(
+            None,
+            num_rounds,
+            polys,
+            comb_func,
+            combined_degree,
+            transcript,
+            Some(&mut round_durations),
+        );
+        (proof, r, final_evals, round_durations)
+    }
+
+    /// If `claim` is given, each round polynomial's evaluation at 1 is derived from the running
+    /// claim rather than computed.
+    fn prove_arbitrary_inner<Func>(
+        claim: Option<F>,
+        num_rounds: usize,
+        polys: &mut Vec<DensePolynomial<F>>,
+        comb_func: Func,
+        combined_degree: usize,
+        transcript: &mut ProofTranscript,
+        mut round_durations: Option<&mut Vec<Duration>>,
+    ) -> (Self, Vec<F>, Vec<F>)
+    where
+        Func: Fn(&[F]) -> F + std::marker::Sync,
+    {
+        let mut e = claim;
         let mut r: Vec<F> = Vec::new();
         let mut compressed_polys: Vec<CompressedUniPoly<F>> = Vec::new();
 
         for _round in 0..num_rounds {
+            let round_start = round_durations.is_some().then(Instant::now);
+
             // Vector storing evaluations of combined polynomials g(x) = P_0(x) * ... P_{num_polys} (x)
             // for points {0, ..., |g(x)|}
             let mut eval_points = vec![F::zero(); combined_degree + 1];
@@ -230,18 +327,28 @@ impl<F: JoltField> SumcheckInstanceProof<F> {
                         .sum::<F>();
                 });
 
+            if let Some(e) = e {
+                eval_points[1] = e - eval_points[0];
+            }
             let round_uni_poly = UniPoly::from_evals(&eval_points);
 
             // append the prover's message to the transcript
             round_uni_poly.append_to_transcript(b"poly", transcript);
             let r_j = transcript.challenge_scalar(b"challenge_nextround");
             r.push(r_j);
+            e = e.map(|_| round_uni_poly.evaluate(&r_j));
 
             // bound all tables to the verifier's challenege
             polys
                 .par_iter_mut()
                 .for_each(|poly| poly.bound_poly_var_top(&r_j));
             compressed_polys.push(round_uni_poly.compress());
+
+            if let (Some(round_durations), Some(round_start)) =
+                (round_durations.as_mut(), round_start)
+            {
+                round_durations.push(round_start.elapsed());
+            }
         }
 
         let final_evals = polys.iter().map(|poly| poly[0]).collect();
@@ -1080,6 +1187,19 @@ impl<F: JoltField> SumcheckInstanceProof<F> {
         num_rounds: usize,
         degree_bound: usize,
         transcript: &mut ProofTranscript,
+    ) -> Result<(F, Vec<F>), ProofVerifyError> {
+        self.verify_with_degree_check(claim, num_rounds, degree_bound, true, transcript)
+    }
+
+    /// Same as `verify`, but if `strict_degree_bound` is false, round polynomials of degree
+    /// below `degree_bound` are also accepted.
+    pub fn verify_with_degree_check(
+        &self,
+        claim: F,
+        num_rounds: usize,
+        degree_bound: usize,
+        strict_degree_bound: bool,
+        transcript: &mut ProofTranscript,
     ) -> Result<(F, Vec<F>), ProofVerifyError> {
         let mut e = claim;
         let mut r: Vec<F> = Vec::new();
@@ -1090,7 +1210,9 @@ impl<F: JoltField> SumcheckInstanceProof<F> {
             let poly = self.compressed_polys[i].decompress(&e);
 
             // verify degree bound
-            if poly.degree() != degree_bound {
+            if poly.degree() > degree_bound
+                || (strict_degree_bound && poly.degree() != degree_bound)
+            {
                 return Err(ProofVerifyError::InvalidInputLength(
                     degree_bound,
                     poly.degree(),
@@ -1098,7 +1220,9 @@ impl<F: JoltField> SumcheckInstanceProof<F> {
             }
 
             // check if G_k(0) + G_k(1) = e
-            assert_eq!(poly.eval_at_zero() + poly.eval_at_one(), e);
+            if poly.eval_at_zero() + poly.eval_at_one() != e {
+                return Err(ProofVerifyError::SumcheckRoundError(i));
+            }
 
             // append the prover's message to the transcript
             poly.append_to_transcript(b"poly", transcript);
@@ -1114,6 +1238,48 @@ impl<F: JoltField> SumcheckInstanceProof<F> {
 
         Ok((e, r))
     }
+
+    /// Same as `verify`, but for uncompressed round polynomials, e.g. as produced by a prover
+    /// before compression. Since decompression derives each round polynomial's linear term from
+    /// the running claim, a compressed proof always passes the `p(0) + p(1) == claim` check;
+    /// uncompressed polynomials can instead be checked as each round is processed, rejecting
+    /// with `SumcheckRoundError(round)` at the first inconsistent round rather than only at
+    /// the caller's final oracle check.
+    pub fn verify_round_polys(
+        round_polys: &[UniPoly<F>],
+        claim: F,
+        num_rounds: usize,
+        degree_bound: usize,
+        transcript: &mut ProofTranscript,
+    ) -> Result<(F, Vec<F>), ProofVerifyError> {
+        if round_polys.len() != num_rounds {
+            return Err(ProofVerifyError::InvalidInputLength(
+                num_rounds,
+                round_polys.len(),
+            ));
+        }
+
+        let mut e = claim;
+        let mut r: Vec<F> = Vec::with_capacity(num_rounds);
+        for (round, poly) in round_polys.iter().enumerate() {
+            if poly.degree() != degree_bound {
+                return Err(ProofVerifyError::InvalidInputLength(
+                    degree_bound,
+                    poly.degree(),
+                ));
+            }
+            if poly.eval_at_zero() + poly.eval_at_one() != e {
+                return Err(ProofVerifyError::SumcheckRoundError(round));
+            }
+
+            poly.append_to_transcript(b"poly", transcript);
+            let r_i = transcript.challenge_scalar(b"challenge_nextround");
+            r.push(r_i);
+            e = poly.evaluate(&r_i);
+        }
+
+        Ok((e, r))
+    }
 }
 
 pub mod bench {
@@ -1269,6 +1435,8 @@ mod test {
     use super::*;
     use crate::poly::eq_poly::EqPolynomial;
     use ark_bn254::Fr;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+    use ark_std::UniformRand;
 
     #[test]
     fn flags_special_trivial() {
@@ -1387,4 +1555,327 @@ mod test {
         let fingerprint_oracle_query = flag_eval * h_eval + Fr::one() - flag_eval;
         assert_eq!(prove_fingerprint_eval, fingerprint_oracle_query);
     }
+
+    /// Runs `prove_arbitrary` over the product of `num_polys` random multilinear polynomials in
+    /// `num_vars` variables, then checks that the honest proof verifies and that a proof with a
+    /// single perturbed round-polynomial coefficient is rejected.
+    fn sumcheck_fuzz_case(seed: u64, num_vars: usize, num_polys: usize) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let polys: Vec<DensePolynomial<Fr>> = (0..num_polys)
+            .map(|_| DensePolynomial::new((0..1 << num_vars).map(|_| Fr::rand(&mut rng)).collect()))
+            .collect();
+        let comb_func = |vals: &[Fr]| -> Fr { vals.iter().product() };
+        let claim: Fr = (0..1 << num_vars)
+            .map(|i| comb_func(&polys.iter().map(|poly| poly[i]).collect::<Vec<_>>()))
+            .sum();
+        // The final sumcheck check that `verify` leaves to the caller
+        let oracle = |r: &[Fr]| -> Fr {
+            comb_func(
+                &polys
+                    .iter()
+                    .map(|poly| poly.evaluate(r))
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let (proof, prove_randomness, _) = SumcheckInstanceProof::prove_arbitrary(
+            &claim,
+            num_vars,
+            &mut polys.clone(),
+            comb_func,
+            num_polys,
+            &mut transcript,
+        );
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let (verify_evaluation, verify_randomness) = proof
+            .verify(claim, num_vars, num_polys, &mut transcript)
+            .unwrap();
+        assert_eq!(prove_randomness, verify_randomness);
+        assert_eq!(verify_evaluation, oracle(&verify_randomness));
+
+        // Perturb the constant coefficient of one round polynomial. Decompression recomputes
+        // the linear term from the running claim, so the round check itself still passes; the
+        // tampering must be caught by the final oracle check.
+        let round = seed as usize % num_vars;
+        let mut tampered_proof = proof;
+        let mut coeffs = tampered_proof.compressed_polys[round]
+            .decompress(&Fr::zero())
+            .as_vec();
+        coeffs[0] += Fr::one();
+        tampered_proof.compressed_polys[round] = UniPoly::from_coeff(coeffs).compress();
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let (tampered_evaluation, tampered_randomness) = tampered_proof
+            .verify(claim, num_vars, num_polys, &mut transcript)
+            .unwrap();
+        assert_ne!(tampered_evaluation, oracle(&tampered_randomness));
+    }
+
+    #[test]
+    fn verify_round_polys_early_rejection() {
+        let num_vars = 6;
+        let mut rng = StdRng::seed_from_u64(0);
+        let polys: Vec<DensePolynomial<Fr>> = (0..2)
+            .map(|_| DensePolynomial::new((0..1 << num_vars).map(|_| Fr::rand(&mut rng)).collect()))
+            .collect();
+        let comb_func = |vals: &[Fr]| -> Fr { vals[0] * vals[1] };
+        let claim: Fr = (0..1 << num_vars).map(|i| polys[0][i] * polys[1][i]).sum();
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let (proof, r, _) = SumcheckInstanceProof::prove_arbitrary(
+            &claim,
+            num_vars,
+            &mut polys.clone(),
+            comb_func,
+            2,
+            &mut transcript,
+        );
+
+        // Recover the uncompressed round polynomials
+        let mut e = claim;
+        let mut round_polys: Vec<UniPoly<Fr>> = Vec::with_capacity(num_vars);
+        for (compressed_poly, r_i) in proof.compressed_polys.iter().zip(r.iter()) {
+            let poly = compressed_poly.decompress(&e);
+            e = poly.evaluate(r_i);
+            round_polys.push(poly);
+        }
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let expected = proof.verify(claim, num_vars, 2, &mut transcript).unwrap();
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let (e, r_verify) = SumcheckInstanceProof::verify_round_polys(
+            &round_polys,
+            claim,
+            num_vars,
+            2,
+            &mut transcript,
+        )
+        .unwrap();
+        assert_eq!((e, r_verify), expected);
+
+        let mut coeffs = round_polys[3].as_vec();
+        coeffs[0] += Fr::one();
+        round_polys[3] = UniPoly::from_coeff(coeffs);
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        assert!(matches!(
+            SumcheckInstanceProof::verify_round_polys(
+                &round_polys,
+                claim,
+                num_vars,
+                2,
+                &mut transcript
+            ),
+            Err(ProofVerifyError::SumcheckRoundError(3))
+        ));
+    }
+
+    /// Sum of `comb_func` over the Boolean hypercube, split into the halves where the top
+    /// variable is 0 and 1 respectively.
+    fn hypercube_sum_halves<Func>(polys: &[DensePolynomial<Fr>], comb_func: &Func) -> (Fr, Fr)
+    where
+        Func: Fn(&[Fr]) -> Fr,
+    {
+        let half = polys[0].len() / 2;
+        let sum = |range: std::ops::Range<usize>| -> Fr {
+            range
+                .map(|i| comb_func(&polys.iter().map(|poly| poly[i]).collect::<Vec<_>>()))
+                .sum()
+        };
+        if half == 0 {
+            (sum(0..1), Fr::zero())
+        } else {
+            (sum(0..half), sum(half..2 * half))
+        }
+    }
+
+    /// Runs the sumcheck prover for the product of `polys` one round at a time, running the
+    /// corresponding verifier step immediately after each prover round. Panics at the first
+    /// round where the round polynomial disagrees with the remaining hypercube sum, i.e.
+    /// where `p(0) + p(1) != claim`, or where prover and verifier diverge.
+    fn sumcheck_lockstep(mut polys: Vec<DensePolynomial<Fr>>) {
+        let num_rounds = polys[0].get_num_vars();
+        let degree = polys.len();
+        let comb_func = |vals: &[Fr]| -> Fr { vals.iter().product() };
+
+        let (low, high) = hypercube_sum_halves(&polys, &comb_func);
+        let mut claim = low + high;
+
+        let mut prover_transcript = ProofTranscript::new(b"test_transcript");
+        let mut verifier_transcript = ProofTranscript::new(b"test_transcript");
+        for round in 0..num_rounds {
+            let (low, high) = hypercube_sum_halves(&polys, &comb_func);
+            assert_eq!(low + high, claim, "round {}: hypercube sum != claim", round);
+
+            let (round_proof, r_prover, _) = SumcheckInstanceProof::prove_arbitrary(
+                &claim,
+                1,
+                &mut polys,
+                comb_func,
+                degree,
+                &mut prover_transcript,
+            );
+            let round_poly = round_proof.compressed_polys[0].decompress(&claim);
+            assert_eq!(
+                round_poly.eval_at_zero(),
+                low,
+                "round {}: p(0) is wrong",
+                round
+            );
+            assert_eq!(
+                round_poly.eval_at_one(),
+                high,
+                "round {}: p(1) is wrong",
+                round
+            );
+            assert_eq!(
+                round_poly.eval_at_zero() + round_poly.eval_at_one(),
+                claim,
+                "round {}: p(0) + p(1) != claim",
+                round
+            );
+
+            let (next_claim, r_verifier) = round_proof
+                .verify(claim, 1, degree, &mut verifier_transcript)
+                .unwrap_or_else(|err| panic!("round {}: verifier rejected: {:?}", round, err));
+            assert_eq!(r_prover, r_verifier, "round {}: challenges diverged", round);
+            claim = next_claim;
+        }
+
+        // Final oracle check against the fully bound polynomials
+        let (final_eval, _) = hypercube_sum_halves(&polys, &comb_func);
+        assert_eq!(final_eval, claim, "final evaluation != claim");
+    }
+
+    #[test]
+    fn sumcheck_lockstep_random_instances() {
+        let mut rng = StdRng::seed_from_u64(250);
+        for degree in 1..=4 {
+            for num_vars in 1..=6 {
+                let polys: Vec<DensePolynomial<Fr>> = (0..degree)
+                    .map(|_| {
+                        DensePolynomial::new(
+                            (0..1 << num_vars).map(|_| Fr::rand(&mut rng)).collect(),
+                        )
+                    })
+                    .collect();
+                sumcheck_lockstep(polys);
+            }
+        }
+    }
+
+    #[test]
+    fn sumcheck_fuzz() {
+        let mut seed = 0;
+        for num_polys in 1..=3 {
+            for num_vars in 1..=6 {
+                sumcheck_fuzz_case(seed, num_vars, num_polys);
+                seed += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn prove_with_claim() {
+        let mut rng = test_rng();
+        let num_vars = 5;
+        let polys: Vec<DensePolynomial<Fr>> = (0..2)
+            .map(|_| DensePolynomial::new((0..1 << num_vars).map(|_| Fr::rand(&mut rng)).collect()))
+            .collect();
+        let comb_func = |vals: &[Fr]| -> Fr { vals[0] * vals[1] };
+        // The claim, as if it were an opening supplied by an outer argument
+        let claim = polys[0].inner_product(&polys[1]);
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let (proof, prove_randomness, final_evals) = SumcheckInstanceProof::prove_with_claim(
+            &claim,
+            num_vars,
+            &mut polys.clone(),
+            comb_func,
+            2,
+            &mut transcript,
+        );
+
+        // Same proof as when the claim is left implicit
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let (arbitrary_proof, _, _) = SumcheckInstanceProof::prove_arbitrary(
+            &claim,
+            num_vars,
+            &mut polys.clone(),
+            comb_func,
+            2,
+            &mut transcript,
+        );
+        let mut bytes = vec![];
+        proof.serialize_compressed(&mut bytes).unwrap();
+        let mut arbitrary_bytes = vec![];
+        arbitrary_proof
+            .serialize_compressed(&mut arbitrary_bytes)
+            .unwrap();
+        assert_eq!(bytes, arbitrary_bytes);
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let (verify_evaluation, verify_randomness) =
+            proof.verify(claim, num_vars, 2, &mut transcript).unwrap();
+        assert_eq!(prove_randomness, verify_randomness);
+        assert_eq!(verify_evaluation, comb_func(&final_evals));
+        assert_eq!(
+            verify_evaluation,
+            polys[0].evaluate(&verify_randomness) * polys[1].evaluate(&verify_randomness)
+        );
+
+        // Verifying against an incorrect claim fails the final oracle check
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let (wrong_evaluation, wrong_randomness) = proof
+            .verify(claim + Fr::one(), num_vars, 2, &mut transcript)
+            .unwrap();
+        assert_ne!(
+            wrong_evaluation,
+            polys[0].evaluate(&wrong_randomness) * polys[1].evaluate(&wrong_randomness)
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "claim does not equal the hypercube sum")]
+    fn prove_with_incorrect_claim() {
+        let poly = DensePolynomial::new(vec![Fr::from(1), Fr::from(2), Fr::from(3), Fr::from(4)]);
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        SumcheckInstanceProof::prove_with_claim(
+            &Fr::from(11),
+            2,
+            &mut vec![poly],
+            |vals: &[Fr]| vals[0],
+            1,
+            &mut transcript,
+        );
+    }
+
+    #[cfg(feature = "profiling")]
+    #[test]
+    fn prove_arbitrary_timed() {
+        let num_vars = 6;
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut polys: Vec<DensePolynomial<Fr>> = (0..2)
+            .map(|_| DensePolynomial::new((0..1 << num_vars).map(|_| Fr::rand(&mut rng)).collect()))
+            .collect();
+        let comb_func = |vals: &[Fr]| -> Fr { vals[0] * vals[1] };
+        let claim: Fr = (0..1 << num_vars).map(|i| polys[0][i] * polys[1][i]).sum();
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        let (proof, _, _, round_durations) = SumcheckInstanceProof::prove_arbitrary_timed(
+            &claim,
+            num_vars,
+            &mut polys,
+            comb_func,
+            2,
+            &mut transcript,
+        );
+        assert_eq!(round_durations.len(), num_vars);
+        assert!(round_durations.iter().all(|duration| !duration.is_zero()));
+
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        assert!(proof.verify(claim, num_vars, 2, &mut transcript).is_ok());
+    }
 }
@@ -0,0 +1,294 @@
+//! A field that counts its multiplications, and a trivial commitment scheme over it, used to
+//! guard the number of field operations performed by verifiers.
+
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use ark_bn254::Fr;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+use crate::poly::commitment::commitment_scheme::{BatchType, CommitShape, CommitmentScheme};
+use crate::poly::dense_mlpoly::DensePolynomial;
+use crate::poly::field::JoltField;
+use crate::utils::errors::ProofVerifyError;
+use crate::utils::transcript::{AppendToTranscript, ProofTranscript};
+
+static MULTIPLICATIONS: AtomicUsize = AtomicUsize::new(0);
+static COUNT_MULTIPLICATIONS: AtomicBool = AtomicBool::new(false);
+
+fn record_mult() {
+    if COUNT_MULTIPLICATIONS.load(Ordering::Relaxed) {
+        MULTIPLICATIONS.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Runs `f` and returns its result along with the number of `CountingField` multiplications
+/// (including squarings and divisions) it performed, across all threads. Counting is global,
+/// so `f` must not overlap with any other use of `CountingField`.
+pub fn count_mults<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    MULTIPLICATIONS.store(0, Ordering::SeqCst);
+    COUNT_MULTIPLICATIONS.store(true, Ordering::SeqCst);
+    let result = f();
+    COUNT_MULTIPLICATIONS.store(false, Ordering::SeqCst);
+    (result, MULTIPLICATIONS.load(Ordering::SeqCst))
+}
+
+/// Runs `f` without counting its multiplications, e.g. to exclude work that stands in for an
+/// external component.
+fn uncounted<T>(f: impl FnOnce() -> T) -> T {
+    let counting = COUNT_MULTIPLICATIONS.swap(false, Ordering::SeqCst);
+    let result = f();
+    COUNT_MULTIPLICATIONS.store(counting, Ordering::SeqCst);
+    result
+}
+
+/// BN254's scalar field, counting multiplications while inside `count_mults`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct CountingField(pub Fr);
+
+fn fr_add(a: Fr, b: Fr) -> Fr {
+    a + b
+}
+
+fn fr_sub(a: Fr, b: Fr) -> Fr {
+    a - b
+}
+
+fn fr_mul(a: Fr, b: Fr) -> Fr {
+    record_mult();
+    a * b
+}
+
+fn fr_div(a: Fr, b: Fr) -> Fr {
+    record_mult();
+    a / b
+}
+
+macro_rules! impl_binary_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:ident) => {
+        impl $trait for CountingField {
+            type Output = Self;
+            fn $method(self, rhs: Self) -> Self {
+                CountingField($op(self.0, rhs.0))
+            }
+        }
+
+        impl<'a> $trait<&'a CountingField> for CountingField {
+            type Output = Self;
+            fn $method(self, rhs: &'a CountingField) -> Self {
+                CountingField($op(self.0, rhs.0))
+            }
+        }
+
+        impl<'a> $trait<&'a mut CountingField> for CountingField {
+            type Output = Self;
+            fn $method(self, rhs: &'a mut CountingField) -> Self {
+                CountingField($op(self.0, rhs.0))
+            }
+        }
+
+        impl $assign_trait for CountingField {
+            fn $assign_method(&mut self, rhs: Self) {
+                self.0 = $op(self.0, rhs.0);
+            }
+        }
+
+        impl<'a> $assign_trait<&'a CountingField> for CountingField {
+            fn $assign_method(&mut self, rhs: &'a CountingField) {
+                self.0 = $op(self.0, rhs.0);
+            }
+        }
+
+        impl<'a> $assign_trait<&'a mut CountingField> for CountingField {
+            fn $assign_method(&mut self, rhs: &'a mut CountingField) {
+                self.0 = $op(self.0, rhs.0);
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, AddAssign, add_assign, fr_add);
+impl_binary_op!(Sub, sub, SubAssign, sub_assign, fr_sub);
+impl_binary_op!(Mul, mul, MulAssign, mul_assign, fr_mul);
+
+impl Div for CountingField {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        CountingField(fr_div(self.0, rhs.0))
+    }
+}
+
+impl Neg for CountingField {
+    type Output = Self;
+    fn neg(self) -> Self {
+        CountingField(-self.0)
+    }
+}
+
+impl core::iter::Sum<Self> for CountingField {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + x)
+    }
+}
+
+impl<'a> core::iter::Sum<&'a Self> for CountingField {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + x)
+    }
+}
+
+impl core::iter::Product<Self> for CountingField {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, x| acc * x)
+    }
+}
+
+impl<'a> core::iter::Product<&'a Self> for CountingField {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, x| acc * x)
+    }
+}
+
+impl JoltField for CountingField {
+    const NUM_BYTES: usize = <Fr as JoltField>::NUM_BYTES;
+
+    fn random<R: rand_core::RngCore>(rng: &mut R) -> Self {
+        CountingField(<Fr as JoltField>::random(rng))
+    }
+
+    fn is_zero(&self) -> bool {
+        <Fr as JoltField>::is_zero(&self.0)
+    }
+
+    fn is_one(&self) -> bool {
+        <Fr as JoltField>::is_one(&self.0)
+    }
+
+    fn zero() -> Self {
+        CountingField(<Fr as JoltField>::zero())
+    }
+
+    fn one() -> Self {
+        CountingField(<Fr as JoltField>::one())
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        <Fr as JoltField>::from_u64(n).map(CountingField)
+    }
+
+    fn square(&self) -> Self {
+        record_mult();
+        CountingField(<Fr as JoltField>::square(&self.0))
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Self {
+        CountingField(<Fr as JoltField>::from_bytes(bytes))
+    }
+}
+
+/// A "commitment scheme" whose commitments are the committed evaluations themselves and whose
+/// openings are checked by direct evaluation. It is not binding in any useful sense; it lets
+/// protocols run over fields without a matching curve, such as `CountingField`. The opening
+/// checks are not counted by `count_mults`, so that counts reflect the protocol itself rather
+/// than this stand-in.
+#[derive(Clone)]
+pub struct MockCommitmentScheme<F: JoltField> {
+    _marker: PhantomData<F>,
+}
+
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+pub struct MockCommitment<F: JoltField> {
+    pub evals: Vec<F>,
+}
+
+impl<F: JoltField> AppendToTranscript for MockCommitment<F> {
+    fn append_to_transcript(&self, label: &'static [u8], transcript: &mut ProofTranscript) {
+        transcript.append_scalars(label, &self.evals);
+    }
+}
+
+impl<F: JoltField> CommitmentScheme for MockCommitmentScheme<F> {
+    type Field = F;
+    type Setup = ();
+    type Commitment = MockCommitment<F>;
+    type Proof = ();
+    type BatchedProof = ();
+
+    fn setup(_shapes: &[CommitShape]) -> Self::Setup {}
+    fn setup_from_seed(_shapes: &[CommitShape], _seed: &[u8]) -> Self::Setup {}
+    fn commit(poly: &DensePolynomial<Self::Field>, _setup: &Self::Setup) -> Self::Commitment {
+        MockCommitment {
+            evals: poly.evals_ref().to_vec(),
+        }
+    }
+    fn batch_commit(
+        evals: &[&[Self::Field]],
+        _setup: &Self::Setup,
+        _batch_type: BatchType,
+    ) -> Vec<Self::Commitment> {
+        evals
+            .iter()
+            .map(|evals| MockCommitment {
+                evals: evals.to_vec(),
+            })
+            .collect()
+    }
+    fn commit_slice(evals: &[Self::Field], _setup: &Self::Setup) -> Self::Commitment {
+        MockCommitment {
+            evals: evals.to_vec(),
+        }
+    }
+    fn prove(
+        _poly: &DensePolynomial<Self::Field>,
+        _opening_point: &[Self::Field],
+        _transcript: &mut ProofTranscript,
+    ) -> Self::Proof {
+    }
+    fn batch_prove(
+        _polynomials: &[&DensePolynomial<Self::Field>],
+        _opening_point: &[Self::Field],
+        _openings: &[Self::Field],
+        _batch_type: BatchType,
+        _transcript: &mut ProofTranscript,
+    ) -> Self::BatchedProof {
+    }
+
+    fn verify(
+        _proof: &Self::Proof,
+        _setup: &Self::Setup,
+        _transcript: &mut ProofTranscript,
+        opening_point: &[Self::Field],
+        opening: &Self::Field,
+        commitment: &Self::Commitment,
+    ) -> Result<(), ProofVerifyError> {
+        let evaluation =
+            uncounted(|| DensePolynomial::new(commitment.evals.clone()).evaluate(opening_point));
+        if evaluation == *opening {
+            Ok(())
+        } else {
+            Err(ProofVerifyError::InternalError)
+        }
+    }
+
+    fn batch_verify(
+        _batch_proof: &Self::BatchedProof,
+        setup: &Self::Setup,
+        opening_point: &[Self::Field],
+        openings: &[Self::Field],
+        commitments: &[&Self::Commitment],
+        transcript: &mut ProofTranscript,
+    ) -> Result<(), ProofVerifyError> {
+        if openings.len() != commitments.len() {
+            return Err(ProofVerifyError::InternalError);
+        }
+        for (opening, commitment) in openings.iter().zip(commitments.iter()) {
+            Self::verify(&(), setup, transcript, opening_point, opening, commitment)?;
+        }
+        Ok(())
+    }
+
+    fn protocol_name() -> &'static [u8] {
+        b"mock_commit"
+    }
+}
@@ -14,4 +14,55 @@ pub enum ProofVerifyError {
     DecompressionError([u8; 32]),
     #[error("R1CS proof verification failed: {0}")]
     SpartanError(String),
+    #[error("Memory checking failed for chunk {0} at the {1:?} check")]
+    MemoryCheckingError(usize, MemoryCheckingStage),
+    #[error("Multiset hashes of memory {0} are not equal: init * write != final * read")]
+    MultisetHashMismatch(usize),
+    #[error("Sumcheck round {0} failed: p(0) + p(1) does not match the running claim")]
+    SumcheckRoundError(usize),
+    #[error("Field element encoding is not canonical: {0:?}")]
+    NonCanonicalFieldElement(Vec<u8>),
+    #[error("Proof was not produced for the given public inputs")]
+    PublicInputsMismatch,
+    #[error("Proof looks up into different tables than the verifier's")]
+    TableHandleMismatch,
+    #[error("Lookup outputs differ from the externally committed outputs")]
+    OutputBindingMismatch,
+    #[error("Proof looks up into a table committed to differently than the verifier's")]
+    TableCommitmentMismatch,
+    #[error("Address {0} does not index a table of size {1}, which must be a power of two")]
+    InvalidTableSize(usize, usize),
+}
+
+/// The multiset whose fingerprint failed to match its grand product claim during memory
+/// checking.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MemoryCheckingStage {
+    Read,
+    Write,
+    Init,
+    Final,
+}
+
+/// Reason `SurgeProof::assemble` rejected a set of independently proven components.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum AssembleError {
+    #[error("{0} has {2} elements, expected {1}")]
+    ShapeMismatch(&'static str, usize, usize),
+    #[error("Primary sumcheck has {0} rounds but the read/write grand product has {1} layers")]
+    NumRoundsMismatch(usize, usize),
+}
+
+/// Inconsistency in a prover's witness, found before committing to it (see
+/// `memory_checking::verify_timestamps`).
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum WitnessError {
+    #[error("{0} has {2} elements, expected {1}")]
+    LengthMismatch(&'static str, usize, usize),
+    #[error("Access {0} addresses cell {1}, but the memory has {2} cells")]
+    AddressOutOfBounds(usize, usize, usize),
+    #[error("Read timestamp of access {0} is {2}, expected {1}")]
+    ReadTimestampMismatch(usize, usize, usize),
+    #[error("Final timestamp of cell {0} is {2}, expected {1}")]
+    FinalTimestampMismatch(usize, usize, usize),
 }
@@ -43,6 +43,24 @@ pub fn chunk_operand_usize(x: u64, C: usize, chunk_len: usize) -> Vec<usize> {
         .collect()
 }
 
+/// Lazily yields the `c` chunks of a 64-bit operand, most significant first, without
+/// allocating. Equivalent to `chunk_operand_usize(x, c, 64 / c)`. Errors if `c` does not
+/// divide 64.
+pub fn decompose_operand_stream(
+    x: u64,
+    c: usize,
+) -> Result<impl Iterator<Item = usize>, &'static str> {
+    if c == 0 || 64 % c != 0 {
+        return Err("number of chunks must divide 64");
+    }
+    let chunk_len = 64 / c;
+    let bit_mask = u64::MAX >> (64 - chunk_len);
+    Ok((0..c).map(move |i| {
+        let shift = ((c - i - 1) * chunk_len) as u32;
+        ((x >> shift) & bit_mask) as usize
+    }))
+}
+
 /// Chunks `x` || `y` into `C` chunks bitwise.
 /// `log_M` is the number of bits of each of the `C` expected results.
 /// `log_M = num_bits(x || y) / C`
@@ -110,6 +128,8 @@ pub fn chunk_and_concatenate_for_shift(x: u64, y: u64, C: usize, log_M: usize) -
 mod tests {
     use super::*;
     use ark_bn254::Fr;
+    use ark_std::test_rng;
+    use rand_chacha::rand_core::RngCore;
 
     #[test]
     fn concatenate_lookups_test() {
@@ -193,4 +213,28 @@ mod tests {
         // x_2 | y = 0b11 | 0b01
         assert_eq!(chunks[2], 0b11_01);
     }
+
+    #[test]
+    fn decompose_operand_stream_matches_chunk_operand() {
+        let mut rng = test_rng();
+        for _ in 0..64 {
+            let x = rng.next_u64();
+            for c in [2, 4, 8, 16, 32, 64] {
+                let streamed: Vec<usize> = decompose_operand_stream(x, c).unwrap().collect();
+                let chunked: Vec<usize> = chunk_operand(x, c, 64 / c)
+                    .into_iter()
+                    .map(|chunk| chunk as usize)
+                    .collect();
+                assert_eq!(streamed, chunked, "x = {:#x}, c = {}", x, c);
+            }
+            assert_eq!(
+                decompose_operand_stream(x, 1).unwrap().collect::<Vec<_>>(),
+                vec![x as usize]
+            );
+        }
+
+        for c in [0, 3, 5, 7, 65] {
+            assert!(decompose_operand_stream(0, c).is_err());
+        }
+    }
 }
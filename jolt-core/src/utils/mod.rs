@@ -7,11 +7,16 @@ use ark_std::test_rng;
 use rayon::prelude::*;
 
 use crate::poly::dense_mlpoly::DensePolynomial;
+use crate::utils::errors::ProofVerifyError;
 
+#[cfg(test)]
+pub mod counting_field;
 pub mod errors;
 pub mod gaussian_elimination;
 pub mod instruction_utils;
 pub mod math;
+#[cfg(test)]
+pub mod test_lib;
 pub mod thread;
 pub mod transcript;
 
@@ -78,6 +83,54 @@ pub fn compute_dotproduct_low_optimized<F: JoltField>(a: &[F], b: &[F]) -> F {
         .sum()
 }
 
+/// Computes the random linear combination `sum_i coeffs[i] * items[i]`.
+#[tracing::instrument(skip_all)]
+pub fn random_linear_combination<F: JoltField>(items: &[F], coeffs: &[F]) -> F {
+    assert_eq!(
+        items.len(),
+        coeffs.len(),
+        "random_linear_combination: {} items but {} coefficients",
+        items.len(),
+        coeffs.len()
+    );
+    items
+        .par_iter()
+        .zip(coeffs.par_iter())
+        .map(|(item, coeff)| mul_0_1_optimized(item, coeff))
+        .sum()
+}
+
+/// Computes the polynomial `sum_i coeffs[i] * polys[i]`, whose evaluation at any point is the
+/// random linear combination of the `polys`' evaluations at that point.
+#[tracing::instrument(skip_all)]
+pub fn rlc_polys<F: JoltField>(polys: &[&DensePolynomial<F>], coeffs: &[F]) -> DensePolynomial<F> {
+    assert_eq!(
+        polys.len(),
+        coeffs.len(),
+        "rlc_polys: {} polynomials but {} coefficients",
+        polys.len(),
+        coeffs.len()
+    );
+    assert!(!polys.is_empty(), "rlc_polys: no polynomials to combine");
+    let len = polys[0].len();
+    assert!(
+        polys.iter().all(|poly| poly.len() == len),
+        "rlc_polys: polynomials must have equal lengths"
+    );
+
+    let evals: Vec<F> = (0..len)
+        .into_par_iter()
+        .map(|i| {
+            polys
+                .iter()
+                .zip(coeffs.iter())
+                .map(|(poly, coeff)| mul_0_1_optimized(&poly[i], coeff))
+                .sum::<F>()
+        })
+        .collect();
+    DensePolynomial::new(evals)
+}
+
 #[inline(always)]
 pub fn mul_0_1_optimized<F: JoltField>(a: &F, b: &F) -> F {
     if a.is_zero() || b.is_zero() {
@@ -100,6 +153,24 @@ pub fn mul_0_optimized<F: JoltField>(likely_zero: &F, x: &F) -> F {
     }
 }
 
+/// Evaluates the univariate polynomial with coefficients `coeffs` (constant term first) at `x`
+/// using Horner's method, i.e. with `coeffs.len() - 1` multiplications.
+pub fn eval_uni_horner<F: JoltField>(coeffs: &[F], x: &F) -> F {
+    match coeffs.split_last() {
+        Some((leading, rest)) => rest
+            .iter()
+            .rev()
+            .fold(*leading, |eval, coeff| eval * x + coeff),
+        None => F::zero(),
+    }
+}
+
+/// Iterates over `1, 2, 4, ..., 2^(n - 1)` as field elements, computed by doubling in the field
+/// so that exponents of 64 and beyond do not overflow.
+pub fn powers_of_two<F: JoltField>(n: usize) -> impl Iterator<Item = F> {
+    std::iter::successors(Some(F::one()), |power| Some(*power + power)).take(n)
+}
+
 /// Checks if `num` is a power of 2.
 pub fn is_power_of_two(num: usize) -> bool {
     num != 0 && (num & (num - 1)) == 0
@@ -116,6 +187,42 @@ pub fn split_bits(item: usize, num_bits: usize) -> (usize, usize) {
     (high_chunk, low_chunk)
 }
 
+/// Flattens the `C` coordinates of `idx`, each in [0, memory_size), into a single index in
+/// [0, memory_size^C). Coordinate 0 is the most significant, matching the ordering of
+/// `JoltInstruction::to_indices`.
+/// Ex: flatten_index([1, 2], 4) -> 1 * 4 + 2 = 6
+pub fn flatten_index<const C: usize>(
+    idx: [usize; C],
+    memory_size: usize,
+) -> Result<usize, ProofVerifyError> {
+    idx.iter().try_fold(0usize, |flat, &coordinate| {
+        if coordinate >= memory_size {
+            return Err(ProofVerifyError::InputTooLarge);
+        }
+        flat.checked_mul(memory_size)
+            .and_then(|flat| flat.checked_add(coordinate))
+            .ok_or(ProofVerifyError::InputTooLarge)
+    })
+}
+
+/// Inverse of `flatten_index`: splits `flat_index` in [0, memory_size^C) into `C` coordinates,
+/// each in [0, memory_size).
+pub fn unflatten_index<const C: usize>(
+    mut flat_index: usize,
+    memory_size: usize,
+) -> Result<[usize; C], ProofVerifyError> {
+    assert!(memory_size > 0);
+    let mut idx = [0; C];
+    for coordinate in idx.iter_mut().rev() {
+        *coordinate = flat_index % memory_size;
+        flat_index /= memory_size;
+    }
+    if flat_index != 0 {
+        return Err(ProofVerifyError::InputTooLarge);
+    }
+    Ok(idx)
+}
+
 pub fn gen_random_point<F: JoltField>(memory_bits: usize) -> Vec<F> {
     let mut rng = test_rng();
     let mut r_i: Vec<F> = Vec::with_capacity(memory_bits);
@@ -125,6 +232,36 @@ pub fn gen_random_point<F: JoltField>(memory_bits: usize) -> Vec<F> {
     r_i
 }
 
+/// Samples `count` distinct indices in `0..memory_size`, deterministically from `seed`, via a
+/// partial Fisher-Yates shuffle. Only the swapped positions are stored, so this takes
+/// O(`count`) time and space regardless of `memory_size`. Errors if `count > memory_size`.
+pub fn gen_distinct_indices(
+    count: usize,
+    memory_size: usize,
+    seed: u64,
+) -> Result<Vec<usize>, ProofVerifyError> {
+    use ark_std::rand::{rngs::StdRng, Rng, SeedableRng};
+    use std::collections::HashMap;
+
+    if count > memory_size {
+        return Err(ProofVerifyError::InvalidInputLength(memory_size, count));
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    // Sparse representation of the permuted array: position -> value, for positions that
+    // have been swapped. Unswapped positions hold their own index.
+    let mut swapped: HashMap<usize, usize> = HashMap::with_capacity(2 * count);
+    let mut indices = Vec::with_capacity(count);
+    for i in 0..count {
+        let j = rng.gen_range(i..memory_size);
+        let value_j = *swapped.get(&j).unwrap_or(&j);
+        let value_i = *swapped.get(&i).unwrap_or(&i);
+        swapped.insert(j, value_i);
+        indices.push(value_j);
+    }
+    Ok(indices)
+}
+
 #[inline]
 #[tracing::instrument(skip_all, name = "split_poly_flagged")]
 pub fn split_poly_flagged<F: JoltField>(
@@ -167,9 +304,163 @@ pub fn count_poly_zeros<F: JoltField>(poly: &DensePolynomial<F>) -> usize {
 mod tests {
     use super::*;
 
+    #[test]
+    fn distinct_indices() {
+        use std::collections::HashSet;
+
+        for (count, memory_size) in [(0, 1), (1, 1), (16, 16), (100, 1 << 10), (1000, 1 << 30)] {
+            let indices = gen_distinct_indices(count, memory_size, 254).unwrap();
+            assert_eq!(indices.len(), count);
+            assert!(indices.iter().all(|&index| index < memory_size));
+            assert_eq!(indices.iter().collect::<HashSet<_>>().len(), count);
+            assert_eq!(
+                gen_distinct_indices(count, memory_size, 254).unwrap(),
+                indices
+            );
+        }
+
+        // Sampling every cell yields a permutation
+        let mut indices = gen_distinct_indices(64, 64, 0).unwrap();
+        indices.sort();
+        assert_eq!(indices, (0..64).collect::<Vec<_>>());
+
+        assert_ne!(
+            gen_distinct_indices(16, 1 << 20, 0).unwrap(),
+            gen_distinct_indices(16, 1 << 20, 1).unwrap()
+        );
+        assert!(matches!(
+            gen_distinct_indices(17, 16, 0),
+            Err(ProofVerifyError::InvalidInputLength(16, 17))
+        ));
+    }
+
+    #[test]
+    fn powers_of_two_beyond_u64() {
+        use ark_bn254::Fr;
+        use ark_ff::Field;
+
+        let powers: Vec<Fr> = powers_of_two(200).collect();
+        assert_eq!(powers.len(), 200);
+        for (i, power) in powers.iter().enumerate() {
+            assert_eq!(*power, Fr::from(2u64).pow([i as u64]));
+        }
+        assert_eq!(powers_of_two::<Fr>(0).count(), 0);
+    }
+
     #[test]
     fn split() {
         assert_eq!(split_bits(0b00_01, 2), (0, 1));
         assert_eq!(split_bits(0b10_01, 2), (2, 1));
     }
+
+    #[test]
+    fn horner_matches_naive() {
+        use ark_bn254::Fr;
+
+        let mut rng = test_rng();
+        for num_coeffs in 0..8 {
+            let coeffs: Vec<Fr> = (0..num_coeffs).map(|_| Fr::random(&mut rng)).collect();
+            let x = Fr::random(&mut rng);
+
+            let mut naive = Fr::zero();
+            let mut power = Fr::one();
+            for coeff in coeffs.iter() {
+                naive += *coeff * power;
+                power *= x;
+            }
+            assert_eq!(eval_uni_horner(&coeffs, &x), naive);
+        }
+    }
+
+    #[test]
+    fn flatten_index_round_trip() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+
+        assert_eq!(flatten_index([1, 2], 4).unwrap(), 6);
+        assert_eq!(unflatten_index::<2>(6, 4).unwrap(), [1, 2]);
+
+        let mut rng = StdRng::seed_from_u64(0);
+        for memory_size in [1, 2, 3, 16, 1 << 8] {
+            for _ in 0..64 {
+                let idx: [usize; 4] = std::array::from_fn(|_| rng.gen_range(0, memory_size));
+                let flat = flatten_index(idx, memory_size).unwrap();
+                assert!(flat < memory_size.pow(4));
+                assert_eq!(unflatten_index::<4>(flat, memory_size).unwrap(), idx);
+
+                let flat = rng.gen_range(0, memory_size.pow(4));
+                let idx: [usize; 4] = unflatten_index(flat, memory_size).unwrap();
+                assert_eq!(flatten_index(idx, memory_size).unwrap(), flat);
+            }
+        }
+    }
+
+    #[test]
+    fn flatten_index_out_of_range() {
+        assert!(matches!(
+            flatten_index([0, 4, 0], 4),
+            Err(ProofVerifyError::InputTooLarge)
+        ));
+        assert!(matches!(flatten_index([3, 3, 3], 4), Ok(63)));
+        assert!(matches!(
+            flatten_index([usize::MAX - 1; 2], usize::MAX),
+            Err(ProofVerifyError::InputTooLarge)
+        ));
+        assert!(matches!(
+            unflatten_index::<3>(64, 4),
+            Err(ProofVerifyError::InputTooLarge)
+        ));
+    }
+
+    #[test]
+    fn random_linear_combination_matches_manual() {
+        use ark_bn254::Fr;
+
+        let mut rng = test_rng();
+        let items: Vec<Fr> = (0..5).map(|_| Fr::random(&mut rng)).collect();
+        let coeffs: Vec<Fr> = (0..5).map(|_| Fr::random(&mut rng)).collect();
+
+        let mut manual = Fr::zero();
+        for (item, coeff) in items.iter().zip(coeffs.iter()) {
+            manual += *coeff * item;
+        }
+        assert_eq!(random_linear_combination(&items, &coeffs), manual);
+        assert_eq!(random_linear_combination::<Fr>(&[], &[]), Fr::zero());
+    }
+
+    #[test]
+    fn rlc_polys_matches_manual() {
+        use ark_bn254::Fr;
+
+        let mut rng = test_rng();
+        let polys: Vec<DensePolynomial<Fr>> = (0..3)
+            .map(|_| DensePolynomial::new((0..8).map(|_| Fr::random(&mut rng)).collect()))
+            .collect();
+        let coeffs: Vec<Fr> = (0..3).map(|_| Fr::random(&mut rng)).collect();
+        let rlc = rlc_polys(&polys.iter().collect::<Vec<_>>(), &coeffs);
+
+        for i in 0..8 {
+            let items: Vec<Fr> = polys.iter().map(|poly| poly[i]).collect();
+            assert_eq!(rlc[i], random_linear_combination(&items, &coeffs));
+        }
+
+        // Evaluation commutes with the linear combination
+        let point = gen_random_point::<Fr>(3);
+        let evals: Vec<Fr> = polys.iter().map(|poly| poly.evaluate(&point)).collect();
+        assert_eq!(
+            rlc.evaluate(&point),
+            random_linear_combination(&evals, &coeffs)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn rlc_polys_length_mismatch() {
+        use ark_bn254::Fr;
+
+        let polys = vec![
+            DensePolynomial::new(vec![Fr::one(); 4]),
+            DensePolynomial::new(vec![Fr::one(); 8]),
+        ];
+        rlc_polys(&polys.iter().collect::<Vec<_>>(), &[Fr::one(), Fr::one()]);
+    }
 }
@@ -0,0 +1,89 @@
+use crate::poly::field::JoltField;
+
+/// Maximum number of differing indices listed by `assert_field_vecs_eq`.
+const MAX_REPORTED_MISMATCHES: usize = 8;
+
+/// Describes how `left` and `right` differ: their lengths if those differ, and otherwise the
+/// number of differing indices along with the first `MAX_REPORTED_MISMATCHES` of them and
+/// their values. Returns `None` if the vectors are equal.
+pub fn field_vecs_mismatch<F: JoltField>(left: &[F], right: &[F]) -> Option<String> {
+    if left.len() != right.len() {
+        return Some(format!(
+            "field vectors differ in length: left has {}, right has {}",
+            left.len(),
+            right.len()
+        ));
+    }
+
+    let mismatches: Vec<usize> = (0..left.len()).filter(|&i| left[i] != right[i]).collect();
+    if mismatches.is_empty() {
+        return None;
+    }
+
+    let mut report = format!(
+        "field vectors differ at {} of {} indices; first mismatches:",
+        mismatches.len(),
+        left.len()
+    );
+    for &i in mismatches.iter().take(MAX_REPORTED_MISMATCHES) {
+        report.push_str(&format!(
+            "\n  index {}: left = {:?}, right = {:?}",
+            i, left[i], right[i]
+        ));
+    }
+    Some(report)
+}
+
+/// Asserts that `left` and `right` are equal, reporting the differing indices on failure
+/// instead of printing both vectors in full.
+#[track_caller]
+pub fn assert_field_vecs_eq<F: JoltField>(left: &[F], right: &[F]) {
+    if let Some(report) = field_vecs_mismatch(left, right) {
+        panic!("{}", report);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+
+    #[test]
+    fn reports_single_mismatch() {
+        let left: Vec<Fr> = (0..100u64).map(|i| Fr::from_u64(i).unwrap()).collect();
+        let mut right = left.clone();
+        assert!(field_vecs_mismatch(&left, &right).is_none());
+        assert_field_vecs_eq(&left, &right);
+
+        right[37] += Fr::from_u64(1).unwrap();
+        let report = field_vecs_mismatch(&left, &right).unwrap();
+        assert!(report.starts_with("field vectors differ at 1 of 100 indices"));
+        assert!(report.contains("index 37:"));
+        assert_eq!(report.matches("index ").count(), 1);
+    }
+
+    #[test]
+    fn reports_at_most_max_mismatches() {
+        let left: Vec<Fr> = vec![Fr::from_u64(0).unwrap(); 32];
+        let right: Vec<Fr> = vec![Fr::from_u64(1).unwrap(); 32];
+        let report = field_vecs_mismatch(&left, &right).unwrap();
+        assert!(report.starts_with("field vectors differ at 32 of 32 indices"));
+        assert_eq!(report.matches("index ").count(), MAX_REPORTED_MISMATCHES);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3:")]
+    fn assert_panics_at_differing_index() {
+        let left: Vec<Fr> = (0..8u64).map(|i| Fr::from_u64(i).unwrap()).collect();
+        let mut right = left.clone();
+        right[3] = Fr::from_u64(100).unwrap();
+        assert_field_vecs_eq(&left, &right);
+    }
+
+    #[test]
+    #[should_panic(expected = "differ in length")]
+    fn assert_panics_on_length_mismatch() {
+        let left: Vec<Fr> = vec![Fr::from_u64(0).unwrap(); 4];
+        assert_field_vecs_eq(&left, &left[..3]);
+    }
+}
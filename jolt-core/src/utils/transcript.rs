@@ -2,26 +2,278 @@ use crate::poly::field::JoltField;
 use ark_ec::CurveGroup;
 use merlin::Transcript;
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TranscriptEventKind {
+    /// Bytes absorbed into the transcript.
+    Append,
+    /// Bytes squeezed out of the transcript.
+    Challenge,
+}
+
+impl TranscriptEventKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TranscriptEventKind::Append => "append",
+            TranscriptEventKind::Challenge => "challenge",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TranscriptEvent {
+    pub kind: TranscriptEventKind,
+    pub label: &'static [u8],
+    pub payload: Vec<u8>,
+}
+
+impl TranscriptEvent {
+    /// Formats the event as `<kind> <label> <hex payload>`.
+    fn to_line(&self) -> String {
+        let mut line = String::new();
+        line.push_str(self.kind.as_str());
+        line.push(' ');
+        line.push_str(&String::from_utf8_lossy(self.label));
+        line.push(' ');
+        for byte in self.payload.iter() {
+            line.push_str(&format!("{:02x}", byte));
+        }
+        line
+    }
+}
+
+/// Ordered record of every message absorbed into and challenge squeezed out of a
+/// `ProofTranscript`, at the level of the underlying Merlin operations. Two transcripts that
+/// agree on their traces derive the same challenges, so a trace can be compared byte-for-byte
+/// against one emitted by another implementation of the protocol.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TranscriptTrace {
+    pub events: Vec<TranscriptEvent>,
+}
+
+impl TranscriptTrace {
+    /// Serializes the trace as one `<kind> <label> <hex payload>` line per event. Labels are
+    /// written as UTF-8 (lossily) and payloads as lowercase hex.
+    pub fn serialize(&self) -> String {
+        let mut out = String::new();
+        for event in self.events.iter() {
+            out.push_str(&event.to_line());
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Returns the challenges squeezed in this trace, in order, as `(label, values)` pairs.
+    /// Consecutive challenges under the same label (e.g. from `challenge_vector`) are grouped
+    /// into a single pair; an append or a change of label starts a new one.
+    pub fn challenges<F: JoltField>(&self) -> Vec<(&'static [u8], Vec<F>)> {
+        let mut challenges: Vec<(&'static [u8], Vec<F>)> = vec![];
+        let mut extend_last = false;
+        for event in self.events.iter() {
+            if event.kind == TranscriptEventKind::Append {
+                extend_last = false;
+                continue;
+            }
+            let value = F::from_bytes(&event.payload);
+            match challenges.last_mut() {
+                Some((label, values)) if extend_last && *label == event.label => values.push(value),
+                _ => challenges.push((event.label, vec![value])),
+            }
+            extend_last = true;
+        }
+        challenges
+    }
+}
+
+/// How a transcript compared against an expected trace (see `ProofTranscript::compare_to`)
+/// handles the first event that diverges from it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TestTranscriptMode {
+    /// Panic at the first divergence.
+    Strict,
+    /// Log each divergence and keep going; all divergences are reported together by
+    /// `ProofTranscript::assert_end`. Useful when diffing against a reference implementation
+    /// that orders a few appends differently. Note that once an append diverges, the
+    /// transcript state differs and every subsequent challenge diverges too.
+    Relaxed,
+}
+
+/// An event of a compared transcript that does not match the expected trace.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TranscriptDivergence {
+    /// Position of the event in the trace.
+    pub index: usize,
+    /// The expected event, or `None` if the expected trace has no event at `index`.
+    pub expected: Option<TranscriptEvent>,
+    /// The actual event, or `None` if the transcript ended before `index`.
+    pub actual: Option<TranscriptEvent>,
+}
+
+impl TranscriptDivergence {
+    fn describe(&self) -> String {
+        let line = |event: &Option<TranscriptEvent>| match event {
+            Some(event) => event.to_line(),
+            None => "<none>".to_string(),
+        };
+        format!(
+            "event {}: expected `{}`, got `{}`",
+            self.index,
+            line(&self.expected),
+            line(&self.actual)
+        )
+    }
+}
+
+/// Expected trace a `ProofTranscript` is checked against, along with the divergences found
+/// so far.
+struct TranscriptComparison {
+    expected: TranscriptTrace,
+    mode: TestTranscriptMode,
+    divergences: Vec<TranscriptDivergence>,
+}
+
+/// How `ProofTranscript::append_scalars` and `append_points` frame a vector of elements, each
+/// of which is appended as its own message under the vector's label.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VectorAppendMode {
+    /// The elements are preceded by a `begin_append_vector` message and followed by an
+    /// `end_append_vector` message.
+    #[default]
+    Delimited,
+    /// The elements are preceded by the number of elements, appended as a u64.
+    LengthPrefixed,
+    /// Only the elements are appended. Vectors of different lengths may then be ambiguous, so
+    /// this should only be used to match an external transcript format.
+    Raw,
+}
+
 pub struct ProofTranscript {
     inner: Transcript,
+    trace: Option<TranscriptTrace>,
+    comparison: Option<TranscriptComparison>,
+    vector_append_mode: VectorAppendMode,
 }
 
 impl ProofTranscript {
     pub fn new(label: &'static [u8]) -> Self {
         Self {
             inner: Transcript::new(label),
+            trace: None,
+            comparison: None,
+            vector_append_mode: VectorAppendMode::default(),
         }
     }
 
+    pub fn set_vector_append_mode(&mut self, mode: VectorAppendMode) {
+        self.vector_append_mode = mode;
+    }
+
+    /// Starts recording a `TranscriptTrace` of all subsequent operations on this transcript.
+    pub fn enable_trace(&mut self) {
+        self.trace.get_or_insert_with(TranscriptTrace::default);
+    }
+
+    /// Returns the trace recorded since `enable_trace` was called, if any.
+    pub fn trace(&self) -> Option<&TranscriptTrace> {
+        self.trace.as_ref()
+    }
+
+    /// Checks every subsequent operation on this transcript against `expected`, e.g. a trace
+    /// recorded from a reference implementation. Also restarts the transcript's trace, so
+    /// `expected` should begin at the current point of the protocol. Divergences are handled
+    /// according to `mode`; call `assert_end` once the protocol is done.
+    pub fn compare_to(&mut self, expected: TranscriptTrace, mode: TestTranscriptMode) {
+        self.trace = Some(TranscriptTrace::default());
+        self.comparison = Some(TranscriptComparison {
+            expected,
+            mode,
+            divergences: vec![],
+        });
+    }
+
+    /// Returns the divergences from the trace passed to `compare_to` found so far.
+    pub fn divergences(&self) -> &[TranscriptDivergence] {
+        self.comparison
+            .as_ref()
+            .map_or(&[][..], |comparison| comparison.divergences.as_slice())
+    }
+
+    /// Panics, listing every divergence, if this transcript did not exactly reproduce the trace
+    /// passed to `compare_to`, including if either trace has events the other lacks.
+    pub fn assert_end(&self) {
+        let comparison = self
+            .comparison
+            .as_ref()
+            .expect("assert_end called without compare_to");
+        let actual = &self.trace.as_ref().unwrap().events;
+
+        let mut divergences: Vec<String> = comparison
+            .divergences
+            .iter()
+            .map(TranscriptDivergence::describe)
+            .collect();
+        for index in actual.len()..comparison.expected.events.len() {
+            let missing = TranscriptDivergence {
+                index,
+                expected: Some(comparison.expected.events[index].clone()),
+                actual: None,
+            };
+            divergences.push(missing.describe());
+        }
+
+        assert!(
+            divergences.is_empty(),
+            "Transcript diverged from the expected trace at {} event(s):\n{}",
+            divergences.len(),
+            divergences.join("\n")
+        );
+    }
+
+    fn record(&mut self, kind: TranscriptEventKind, label: &'static [u8], payload: &[u8]) {
+        if let Some(trace) = self.trace.as_mut() {
+            let event = TranscriptEvent {
+                kind,
+                label,
+                payload: payload.to_vec(),
+            };
+            if let Some(comparison) = self.comparison.as_mut() {
+                let index = trace.events.len();
+                let expected = comparison.expected.events.get(index);
+                if expected != Some(&event) {
+                    let divergence = TranscriptDivergence {
+                        index,
+                        expected: expected.cloned(),
+                        actual: Some(event.clone()),
+                    };
+                    match comparison.mode {
+                        TestTranscriptMode::Strict => {
+                            panic!("Transcript diverged: {}", divergence.describe())
+                        }
+                        TestTranscriptMode::Relaxed => {
+                            tracing::warn!("Transcript diverged: {}", divergence.describe());
+                            comparison.divergences.push(divergence);
+                        }
+                    }
+                }
+            }
+            trace.events.push(event);
+        }
+    }
+
+    fn append_raw(&mut self, label: &'static [u8], bytes: &[u8]) {
+        self.record(TranscriptEventKind::Append, label, bytes);
+        self.inner.append_message(label, bytes);
+    }
+
     pub fn append_message(&mut self, label: &'static [u8], msg: &'static [u8]) {
-        self.inner.append_message(label, msg);
+        self.append_raw(label, msg);
     }
 
     pub fn append_bytes(&mut self, label: &'static [u8], bytes: &[u8]) {
-        self.inner.append_message(label, bytes);
+        self.append_raw(label, bytes);
     }
 
     pub fn append_u64(&mut self, label: &'static [u8], x: u64) {
+        self.record(TranscriptEventKind::Append, label, &x.to_le_bytes());
         self.inner.append_u64(label, x);
     }
 
@@ -32,34 +284,49 @@ impl ProofTranscript {
     pub fn append_scalar<F: JoltField>(&mut self, label: &'static [u8], scalar: &F) {
         let mut buf = vec![];
         scalar.serialize_compressed(&mut buf).unwrap();
-        self.inner.append_message(label, &buf);
+        self.append_raw(label, &buf);
     }
 
     pub fn append_scalars<F: JoltField>(&mut self, label: &'static [u8], scalars: &[F]) {
-        self.append_message(label, b"begin_append_vector");
+        self.begin_append_vector(label, scalars.len());
         for item in scalars.iter() {
             self.append_scalar(label, item);
         }
-        self.inner.append_message(label, b"end_append_vector");
+        self.end_append_vector(label);
     }
 
     pub fn append_point<G: CurveGroup>(&mut self, label: &'static [u8], point: &G) {
         let mut buf = vec![];
         point.serialize_compressed(&mut buf).unwrap();
-        self.inner.append_message(label, &buf);
+        self.append_raw(label, &buf);
     }
 
     pub fn append_points<G: CurveGroup>(&mut self, label: &'static [u8], points: &[G]) {
-        self.append_message(label, b"begin_append_vector");
+        self.begin_append_vector(label, points.len());
         for item in points.iter() {
             self.append_point(label, item);
         }
-        self.inner.append_message(label, b"end_append_vector");
+        self.end_append_vector(label);
+    }
+
+    fn begin_append_vector(&mut self, label: &'static [u8], len: usize) {
+        match self.vector_append_mode {
+            VectorAppendMode::Delimited => self.append_message(label, b"begin_append_vector"),
+            VectorAppendMode::LengthPrefixed => self.append_u64(label, len as u64),
+            VectorAppendMode::Raw => {}
+        }
+    }
+
+    fn end_append_vector(&mut self, label: &'static [u8]) {
+        if self.vector_append_mode == VectorAppendMode::Delimited {
+            self.append_message(label, b"end_append_vector");
+        }
     }
 
     pub fn challenge_scalar<F: JoltField>(&mut self, label: &'static [u8]) -> F {
         let mut buf = vec![0u8; F::NUM_BYTES];
         self.inner.challenge_bytes(label, &mut buf);
+        self.record(TranscriptEventKind::Challenge, label, &buf);
         F::from_bytes(&buf)
     }
 
@@ -68,8 +335,169 @@ impl ProofTranscript {
             .map(|_i| self.challenge_scalar(label))
             .collect::<Vec<F>>()
     }
+
+    /// Squeezes a scalar challenge followed by a vector of `len` challenges, in that order.
+    /// Equivalent to calling `challenge_scalar` and then `challenge_vector`.
+    pub fn challenge_scalar_and_vector<F: JoltField>(
+        &mut self,
+        scalar_label: &'static [u8],
+        vector_label: &'static [u8],
+        len: usize,
+    ) -> (F, Vec<F>) {
+        let scalar = self.challenge_scalar(scalar_label);
+        let vector = self.challenge_vector(vector_label, len);
+        (scalar, vector)
+    }
 }
 
 pub trait AppendToTranscript {
     fn append_to_transcript(&self, label: &'static [u8], transcript: &mut ProofTranscript);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+
+    #[test]
+    fn challenge_scalar_and_vector_matches_sequential() {
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        transcript.append_u64(b"x", 7);
+        let (scalar, vector): (Fr, Vec<Fr>) =
+            transcript.challenge_scalar_and_vector(b"scalar", b"vector", 4);
+
+        let mut expected_transcript = ProofTranscript::new(b"test_transcript");
+        expected_transcript.append_u64(b"x", 7);
+        let expected_scalar: Fr = expected_transcript.challenge_scalar(b"scalar");
+        let expected_vector: Vec<Fr> = expected_transcript.challenge_vector(b"vector", 4);
+
+        assert_eq!(scalar, expected_scalar);
+        assert_eq!(vector, expected_vector);
+    }
+
+    #[test]
+    fn trace_serialization() {
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        transcript.append_u64(b"untraced", 1);
+        transcript.enable_trace();
+        transcript.append_u64(b"x", 7);
+        transcript.append_bytes(b"bytes", &[0xde, 0xad]);
+        let _: Fr = transcript.challenge_scalar(b"r");
+
+        let trace = transcript.trace().unwrap();
+        assert_eq!(trace.events.len(), 3);
+        assert_eq!(trace.events[2].kind, TranscriptEventKind::Challenge);
+        let serialized = trace.serialize();
+        let lines: Vec<&str> = serialized.lines().collect();
+        assert_eq!(lines[0], "append x 0700000000000000");
+        assert_eq!(lines[1], "append bytes dead");
+        assert!(lines[2].starts_with("challenge r "));
+    }
+
+    #[test]
+    fn trace_challenges_grouped_by_label() {
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        transcript.enable_trace();
+        let r: Vec<Fr> = transcript.challenge_vector(b"r", 3);
+        let s: Fr = transcript.challenge_scalar(b"s");
+        transcript.append_u64(b"x", 7);
+        let t: Fr = transcript.challenge_scalar(b"r");
+        let u: Fr = transcript.challenge_scalar(b"r");
+
+        let challenges: Vec<(&'static [u8], Vec<Fr>)> = transcript.trace().unwrap().challenges();
+        assert_eq!(
+            challenges,
+            vec![
+                (&b"r"[..], r),
+                (&b"s"[..], vec![s]),
+                (&b"r"[..], vec![t, u]),
+            ]
+        );
+    }
+
+    /// Records the trace of a small protocol that appends `first` then `second` and squeezes a
+    /// challenge after each, optionally checked against `expected`.
+    fn run_protocol(
+        first: u64,
+        second: u64,
+        expected: Option<(TranscriptTrace, TestTranscriptMode)>,
+    ) -> ProofTranscript {
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        match expected {
+            Some((expected, mode)) => transcript.compare_to(expected, mode),
+            None => transcript.enable_trace(),
+        }
+        transcript.append_u64(b"first", first);
+        let _: Fr = transcript.challenge_scalar(b"r_first");
+        transcript.append_u64(b"second", second);
+        let _: Fr = transcript.challenge_scalar(b"r_second");
+        transcript
+    }
+
+    #[test]
+    fn relaxed_transcript_reports_all_divergences() {
+        let expected = run_protocol(1, 2, None).trace().unwrap().clone();
+
+        let matching = run_protocol(1, 2, Some((expected.clone(), TestTranscriptMode::Relaxed)));
+        assert!(matching.divergences().is_empty());
+        matching.assert_end();
+
+        // Both appends differ, and so does every challenge after the first divergence
+        let diverging = run_protocol(3, 4, Some((expected, TestTranscriptMode::Relaxed)));
+        let indices: Vec<usize> = diverging
+            .divergences()
+            .iter()
+            .map(|divergence| divergence.index)
+            .collect();
+        assert_eq!(indices, vec![0, 1, 2, 3]);
+
+        let message =
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| diverging.assert_end()))
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap();
+        assert!(message.contains("at 4 event(s)"));
+        for index in 0..4 {
+            assert!(message.contains(&format!("event {}:", index)));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Transcript diverged: event 2")]
+    fn strict_transcript_panics_at_first_divergence() {
+        let expected = run_protocol(1, 2, None).trace().unwrap().clone();
+        run_protocol(1, 4, Some((expected, TestTranscriptMode::Strict)));
+    }
+
+    #[test]
+    fn vector_append_modes() {
+        let scalars: Vec<Fr> = (0..4).map(|i| Fr::from(i as u64)).collect();
+        let challenge = |mode: VectorAppendMode| -> Fr {
+            let mut transcript = ProofTranscript::new(b"test_transcript");
+            transcript.set_vector_append_mode(mode);
+            transcript.append_scalars(b"scalars", &scalars);
+            transcript.challenge_scalar(b"r")
+        };
+
+        let delimited = challenge(VectorAppendMode::Delimited);
+        let length_prefixed = challenge(VectorAppendMode::LengthPrefixed);
+        let raw = challenge(VectorAppendMode::Raw);
+        assert_ne!(delimited, length_prefixed);
+        assert_ne!(delimited, raw);
+        assert_ne!(length_prefixed, raw);
+        assert_eq!(length_prefixed, challenge(VectorAppendMode::LengthPrefixed));
+
+        // LengthPrefixed is the length, as a u64, followed by each element
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        transcript.append_u64(b"scalars", 4);
+        for scalar in scalars.iter() {
+            transcript.append_scalar(b"scalars", scalar);
+        }
+        assert_eq!(length_prefixed, transcript.challenge_scalar::<Fr>(b"r"));
+
+        // The default matches the existing delimited format
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        transcript.append_scalars(b"scalars", &scalars);
+        assert_eq!(delimited, transcript.challenge_scalar::<Fr>(b"r"));
+    }
+}
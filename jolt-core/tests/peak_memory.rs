@@ -0,0 +1,135 @@
+//! Measures the Surge prover's peak memory against `SurgeProof::peak_memory_estimate`.
+//!
+//! This lives in its own test binary because it replaces the global allocator: no other test
+//! pays for the accounting. The tests in this binary hold `MEASUREMENT` while measuring, so
+//! that they do not skew each other's measurements.
+
+use ark_bn254::{Fr, G1Projective};
+use jolt_core::{
+    jolt::instruction::{slt::SLTInstruction, xor::XORInstruction, JoltInstruction},
+    lasso::surge::{SurgePreprocessing, SurgeProof},
+    poly::commitment::{hyrax::HyraxScheme, pedersen::PedersenGenerators},
+    utils::transcript::ProofTranscript,
+};
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicIsize, Ordering};
+use std::sync::Mutex;
+
+/// Tracks the number of bytes currently allocated, and the peak since it was last reset.
+struct CountingAllocator;
+
+static ALLOCATED: AtomicIsize = AtomicIsize::new(0);
+static PEAK_ALLOCATED: AtomicIsize = AtomicIsize::new(0);
+static MEASUREMENT: Mutex<()> = Mutex::new(());
+
+impl CountingAllocator {
+    fn record(delta: isize) {
+        let allocated = ALLOCATED.fetch_add(delta, Ordering::SeqCst) + delta;
+        PEAK_ALLOCATED.fetch_max(allocated, Ordering::SeqCst);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::record(layout.size() as isize);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::record(-(layout.size() as isize));
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            Self::record(new_size as isize - layout.size() as isize);
+        }
+        new_ptr
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Returns the peak number of bytes allocated while running `f`, beyond those allocated before.
+fn measure_peak<T>(f: impl FnOnce() -> T) -> usize {
+    let _guard = MEASUREMENT.lock().unwrap_or_else(|e| e.into_inner());
+    let baseline = ALLOCATED.load(Ordering::SeqCst);
+    PEAK_ALLOCATED.store(baseline, Ordering::SeqCst);
+    drop(f());
+    (PEAK_ALLOCATED.load(Ordering::SeqCst) - baseline) as usize
+}
+
+#[test]
+fn peak_memory_estimate() {
+    const C: usize = 4;
+    const M: usize = 1 << 8;
+    type Surge = SurgeProof<Fr, HyraxScheme<G1Projective>, XORInstruction, C, M>;
+    const NUM_LOOKUPS: usize = 1 << 10;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    let ops: Vec<XORInstruction> = (0..NUM_LOOKUPS)
+        .map(|_| {
+            XORInstruction(
+                rng.next_u32() as u64 & 0xffff,
+                rng.next_u32() as u64 & 0xffff,
+            )
+        })
+        .collect();
+    let preprocessing = SurgePreprocessing::preprocess();
+    let generators = PedersenGenerators::new(Surge::num_generators(NUM_LOOKUPS), b"LassoV1");
+
+    let peak = measure_peak(|| {
+        let mut transcript = ProofTranscript::new(b"test_transcript");
+        Surge::prove(&preprocessing, &generators, ops, &mut transcript)
+    });
+
+    let estimate = Surge::peak_memory_estimate(NUM_LOOKUPS);
+    assert!(
+        estimate / 2 <= peak && peak <= 2 * estimate,
+        "estimated {} bytes, measured {} bytes",
+        estimate,
+        peak
+    );
+}
+
+#[test]
+fn mle_only_peak_memory() {
+    const C: usize = 4;
+    const M: usize = 1 << 16;
+    type Surge = SurgeProof<Fr, HyraxScheme<G1Projective>, SLTInstruction, C, M>;
+    const NUM_LOOKUPS: usize = 1 << 6;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    let ops: Vec<SLTInstruction> = (0..NUM_LOOKUPS)
+        .map(|_| SLTInstruction::default().random(&mut rng))
+        .collect();
+    let generators = PedersenGenerators::new(Surge::num_generators(NUM_LOOKUPS), b"LassoV1");
+    let prove_with = |preprocess: fn() -> SurgePreprocessing<Fr, SLTInstruction, C, M>| {
+        let ops = ops.clone();
+        measure_peak(|| {
+            let preprocessing = preprocess();
+            let mut transcript = ProofTranscript::new(b"test_transcript");
+            Surge::prove(&preprocessing, &generators, ops, &mut transcript)
+        })
+    };
+    let materialized_peak = prove_with(SurgePreprocessing::preprocess);
+    let mle_only_peak = prove_with(SurgePreprocessing::preprocess_mle_only);
+
+    // Without preprocessed subtables, at most one of them is materialized at a time
+    let num_subtables = SLTInstruction::default().subtables::<Fr>(C, M).len();
+    let subtable_size = M * std::mem::size_of::<Fr>();
+    assert!(
+        mle_only_peak + (num_subtables - 1) * subtable_size <= materialized_peak,
+        "{} subtables of {} bytes, measured {} bytes materialized and {} bytes MLE-only",
+        num_subtables,
+        subtable_size,
+        materialized_peak,
+        mle_only_peak
+    );
+}